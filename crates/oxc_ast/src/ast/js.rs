@@ -506,6 +506,18 @@ pub enum PropertyKey<'a> {
 }
 
 impl<'a> PropertyKey<'a> {
+    /// The canonical name of the property, for comparing keys written in different ways:
+    /// `a`, `"a"` and `["a"]` are all named `"a"`.
+    ///
+    /// See [`PropertyKey::static_name`].
+    pub fn property_key_name(&self) -> Option<CompactStr> {
+        self.static_name()
+    }
+
+    /// The name of the property, for identifiers and literal keys.
+    ///
+    /// Numeric keys are named by their value, so `0x10` and `16` share the name `"16"`.
+    /// Returns `None` for private identifiers and computed keys which are not literals.
     pub fn static_name(&self) -> Option<CompactStr> {
         match self {
             Self::Identifier(ident) => Some(ident.name.to_compact_str()),
@@ -613,6 +625,27 @@ pub struct TaggedTemplateExpression<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
+impl<'a> TaggedTemplateExpression<'a> {
+    /// The name of a tag which is an identifier or a chain of static member accesses,
+    /// e.g. `gql` or `String.raw`.
+    pub fn tag_static_name(&self) -> Option<CompactStr> {
+        fn static_name(expr: &Expression) -> Option<CompactStr> {
+            match expr {
+                Expression::Identifier(ident) => Some(ident.name.to_compact_str()),
+                Expression::MemberExpression(member) => match &**member {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        let object = static_name(&member.object)?;
+                        Some(format!("{object}.{}", member.property.name).into())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        static_name(&self.tag)
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -812,6 +845,16 @@ impl<'a> CallExpression<'a> {
         }
     }
 
+    /// `f(...args)`
+    pub fn has_spread_argument(&self) -> bool {
+        self.arguments.iter().any(Argument::is_spread)
+    }
+
+    /// `f?.()`, but not a call inside an optional chain such as `a?.b()`
+    pub fn is_optional_call(&self) -> bool {
+        self.optional
+    }
+
     pub fn common_js_require(&self) -> Option<&StringLiteral> {
         if !(self.callee.is_specific_id("require") && self.arguments.len() == 1) {
             return None;
@@ -1918,6 +1961,11 @@ impl<'a> FormalParameter<'a> {
     pub fn is_public(&self) -> bool {
         matches!(self.accessibility, Some(TSAccessibility::Public))
     }
+
+    /// `constructor(private foo)`, `constructor(readonly foo)` etc.
+    pub fn is_parameter_property(&self) -> bool {
+        self.accessibility.is_some() || self.readonly || self.r#override
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -2574,3 +2622,103 @@ impl<'a> ModuleExportName<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::SPAN;
+    use oxc_syntax::NumberBase;
+
+    use super::*;
+    use crate::AstBuilder;
+
+    #[test]
+    fn test_call_expression_predicates() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let ident =
+            |name| ast.identifier_reference_expression(IdentifierReference::new(SPAN, name));
+        let call = |arguments, optional| {
+            let Expression::CallExpression(call) =
+                ast.call_expression(SPAN, ident("f".into()), arguments, optional, None)
+            else {
+                unreachable!()
+            };
+            call
+        };
+
+        // f(a)
+        let expr = call(ast.new_vec_single(Argument::Expression(ident("a".into()))), false);
+        assert!(!expr.has_spread_argument());
+        assert!(!expr.is_optional_call());
+
+        // f?.(a, ...b)
+        let mut arguments = ast.new_vec_single(Argument::Expression(ident("a".into())));
+        arguments.push(Argument::SpreadElement(ast.spread_element(SPAN, ident("b".into()))));
+        let expr = call(arguments, true);
+        assert!(expr.has_spread_argument());
+        assert!(expr.is_optional_call());
+    }
+
+    #[test]
+    fn test_property_key_name() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let number = |raw, value, base| {
+            let key = ast.literal_number_expression(ast.number_literal(SPAN, value, raw, base));
+            ast.property_key_expression(key).property_key_name()
+        };
+
+        // `{ a: 1 }`
+        let key = ast.property_key_identifier(IdentifierName::new(SPAN, "a".into()));
+        assert_eq!(key.property_key_name(), Some("a".into()));
+
+        // `{ "a": 1 }`, `{ ["a"]: 1 }`
+        let key = ast.literal_string_expression(ast.string_literal(SPAN, "a"));
+        assert_eq!(ast.property_key_expression(key).property_key_name(), Some("a".into()));
+
+        // `{ 16: 1 }`, `{ 0x10: 1 }`, `{ 0o20: 1 }`, `{ 0b10000: 1 }`, `{ 16.0: 1 }`
+        assert_eq!(number("16", 16.0, NumberBase::Decimal), Some("16".into()));
+        assert_eq!(number("0x10", 16.0, NumberBase::Hex), Some("16".into()));
+        assert_eq!(number("0o20", 16.0, NumberBase::Octal), Some("16".into()));
+        assert_eq!(number("0b10000", 16.0, NumberBase::Binary), Some("16".into()));
+        assert_eq!(number("16.0", 16.0, NumberBase::Float), Some("16".into()));
+
+        // `{ [a]: 1 }`
+        let key = ast.identifier_reference_expression(IdentifierReference::new(SPAN, "a".into()));
+        assert_eq!(ast.property_key_expression(key).property_key_name(), None);
+    }
+
+    #[test]
+    fn test_tag_static_name() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let ident =
+            |name| ast.identifier_reference_expression(IdentifierReference::new(SPAN, name));
+        let tag_static_name = |tag| {
+            let quasi = ast.template_literal(SPAN, ast.new_vec(), ast.new_vec());
+            let Expression::TaggedTemplateExpression(expr) =
+                ast.tagged_template_expression(SPAN, tag, quasi, None)
+            else {
+                unreachable!()
+            };
+            expr.tag_static_name()
+        };
+
+        // gql``
+        assert_eq!(tag_static_name(ident("gql".into())), Some("gql".into()));
+
+        // String.raw``
+        let tag = ast.static_member_expression(
+            SPAN,
+            ident("String".into()),
+            IdentifierName::new(SPAN, "raw".into()),
+            false,
+        );
+        assert_eq!(tag_static_name(tag), Some("String.raw".into()));
+
+        // a[b]``
+        let tag = ast.computed_member_expression(SPAN, ident("a".into()), ident("b".into()), false);
+        assert_eq!(tag_static_name(tag), None);
+    }
+}
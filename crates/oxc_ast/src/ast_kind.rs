@@ -5,7 +5,7 @@ use crate::ast::*;
 
 macro_rules! ast_kinds {
     { $($ident:ident($type:ty),)* } => (
-        #[derive(Debug, Clone, Copy)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum AstType {
             $($ident,)*
         }
@@ -15,6 +15,15 @@ macro_rules! ast_kinds {
         pub enum AstKind<'a> {
             $($ident($type),)*
         }
+
+        impl<'a> AstKind<'a> {
+            /// The [`AstType`] of this kind, without the node it points to.
+            pub fn ty(&self) -> AstType {
+                match self {
+                    $(Self::$ident(_) => AstType::$ident,)*
+                }
+            }
+        }
     )
 }
 
@@ -198,6 +207,7 @@ ast_kinds! {
     TSTypeParameterDeclaration(&'a TSTypeParameterDeclaration<'a>),
     TSTypeParameterInstantiation(&'a TSTypeParameterInstantiation<'a>),
     TSImportType(&'a TSImportType<'a>),
+    TSClassImplements(&'a TSClassImplements<'a>),
     TSNamedTupleMember(&'a TSNamedTupleMember<'a>),
 
     TSPropertySignature(&'a TSPropertySignature<'a>),
@@ -528,6 +538,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSTypeParameterDeclaration(x) => x.span,
             Self::TSTypeParameterInstantiation(x) => x.span,
             Self::TSImportType(x) => x.span,
+            Self::TSClassImplements(x) => x.span,
             Self::TSNamedTupleMember(x) => x.span,
 
             Self::TSPropertySignature(x) => x.span,
@@ -729,6 +740,7 @@ impl<'a> AstKind<'a> {
             Self::TSTypeParameterDeclaration(_) => "TSTypeParameterDeclaration".into(),
             Self::TSTypeParameterInstantiation(_) => "TSTypeParameterInstantiation".into(),
             Self::TSImportType(_) => "TSImportType".into(),
+            Self::TSClassImplements(_) => "TSClassImplements".into(),
             Self::TSNamedTupleMember(_) => "TSNamedTupleMember".into(),
 
             Self::TSPropertySignature(_) => "TSPropertySignature".into(),
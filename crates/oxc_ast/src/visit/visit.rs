@@ -182,6 +182,10 @@ pub trait Visit<'a>: Sized {
         walk_class_heritage(self, expr);
     }
 
+    fn visit_ts_class_implements(&mut self, implements: &TSClassImplements<'a>) {
+        walk_ts_class_implements(self, implements);
+    }
+
     fn visit_class_body(&mut self, body: &ClassBody<'a>) {
         walk_class_body(self, body);
     }
@@ -1320,6 +1324,11 @@ pub mod walk {
         if let Some(super_parameters) = &class.super_type_parameters {
             visitor.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                visitor.visit_ts_class_implements(implement);
+            }
+        }
         visitor.visit_class_body(&class.body);
         visitor.leave_node(kind);
         if is_class_expr {
@@ -1334,6 +1343,19 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    pub fn walk_ts_class_implements<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        implements: &TSClassImplements<'a>,
+    ) {
+        let kind = AstKind::TSClassImplements(visitor.alloc(implements));
+        visitor.enter_node(kind);
+        visitor.visit_ts_type_name(&implements.expression);
+        if let Some(parameters) = &implements.type_parameters {
+            visitor.visit_ts_type_parameter_instantiation(parameters);
+        }
+        visitor.leave_node(kind);
+    }
+
     pub fn walk_class_body<'a, V: Visit<'a>>(visitor: &mut V, body: &ClassBody<'a>) {
         let kind = AstKind::ClassBody(visitor.alloc(body));
         visitor.enter_node(kind);
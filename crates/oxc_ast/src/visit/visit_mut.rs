@@ -173,6 +173,10 @@ pub trait VisitMut<'a>: Sized {
         walk_class_heritage_mut(self, expr);
     }
 
+    fn visit_ts_class_implements(&mut self, implements: &mut TSClassImplements<'a>) {
+        walk_ts_class_implements_mut(self, implements);
+    }
+
     fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
         walk_class_body_mut(self, body);
     }
@@ -1346,6 +1350,11 @@ pub mod walk_mut {
         if let Some(super_parameters) = &mut class.super_type_parameters {
             visitor.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &mut class.implements {
+            for implement in implements.iter_mut() {
+                visitor.visit_ts_class_implements(implement);
+            }
+        }
         visitor.visit_class_body(&mut class.body);
         visitor.leave_node(kind);
         if is_class_expr {
@@ -1363,6 +1372,19 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    pub fn walk_ts_class_implements_mut<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        implements: &mut TSClassImplements<'a>,
+    ) {
+        let kind = AstType::TSClassImplements;
+        visitor.enter_node(kind);
+        visitor.visit_ts_type_name(&mut implements.expression);
+        if let Some(parameters) = &mut implements.type_parameters {
+            visitor.visit_ts_type_parameter_instantiation(parameters);
+        }
+        visitor.leave_node(kind);
+    }
+
     pub fn walk_class_body_mut<'a, V: VisitMut<'a>>(visitor: &mut V, body: &mut ClassBody<'a>) {
         for elem in body.body.iter_mut() {
             visitor.visit_class_element(elem);
@@ -1976,7 +1976,7 @@ impl<const MINIFY: bool> Gen<MINIFY> for Super {
 
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for AwaitExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
-        p.wrap(precedence > self.precedence(), |p| {
+        p.wrap(precedence > self.precedence() || precedence == Precedence::Exponential, |p| {
             p.add_source_mapping(self.span.start);
             p.print_str(b"await ");
             self.argument.gen_expr(p, self.precedence(), ctx);
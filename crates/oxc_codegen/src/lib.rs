@@ -322,7 +322,10 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
 
     fn print_space_before_operator(&mut self, next: Operator) {
         if !MINIFY {
-            self.print_hard_space();
+            // `( -x)` and `x +  -y` only need the space that is already there
+            if !matches!(self.code.last(), Some(b' ' | b'(')) {
+                self.print_hard_space();
+            }
             return;
         }
         if self.prev_op_end != self.code.len() {
@@ -129,6 +129,14 @@ fn new_expr() {
     test("new (foo()).bar();", "new (foo()).bar();\n");
 }
 
+#[test]
+fn exponential() {
+    test("(-x) ** 2", "(-x) ** 2;\n");
+    test("(typeof x) ** 2", "(typeof x) ** 2;\n");
+    test("(await x) ** 2", "(await x) ** 2;\n");
+    test("x ** -2", "x ** -2;\n");
+}
+
 #[test]
 fn typescript() {
     test_ts("let x: string = `\\x01`;", "let x: string = `\\x01`;\n", false);
@@ -3,7 +3,7 @@ use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::Error;
 use oxc_semantic::{AstNodes, JSDocFinder, ScopeTree, Semantic, SymbolTable};
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span};
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
@@ -68,6 +68,11 @@ impl<'a> LintContext<'a> {
         self.semantic().source_text()
     }
 
+    /// The source text of `span`, or an empty string when the span is out of range.
+    pub fn source_range(&self, span: Span) -> &'a str {
+        self.source_text().get(span.start as usize..span.end as usize).unwrap_or_default()
+    }
+
     pub fn source_type(&self) -> &SourceType {
         self.semantic().source_type()
     }
@@ -154,3 +159,39 @@ impl<'a> LintContext<'a> {
         self.semantic().jsdoc()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    use super::LintContext;
+
+    #[test]
+    fn source_range() {
+        let source_text = "const a = 'foo';";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(parser_ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let ctx = LintContext::new(Box::from(Path::new("foo.js")), &Rc::new(semantic));
+
+        let literal = ctx
+            .nodes()
+            .iter()
+            .find_map(|node| match node.kind() {
+                AstKind::StringLiteral(lit) => Some(lit),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(ctx.source_range(literal.span), "'foo'");
+        assert_eq!(ctx.source_range(Span::new(10, 100)), "");
+        assert_eq!(ctx.source_range(Span::new(100, 200)), "");
+    }
+}
@@ -37,13 +37,17 @@ mod deepscan {
 
 mod eslint {
     pub mod array_callback_return;
+    pub mod consistent_return;
     pub mod constructor_super;
     pub mod default_case_last;
     pub mod default_param_last;
     pub mod eqeqeq;
     pub mod for_direction;
+    pub mod func_names;
     pub mod getter_return;
+    pub mod grouped_accessor_pairs;
     pub mod guard_for_in;
+    pub mod id_length;
     pub mod max_len;
     pub mod max_lines;
     pub mod max_params;
@@ -74,16 +78,22 @@ mod eslint {
     pub mod no_eq_null;
     pub mod no_eval;
     pub mod no_ex_assign;
+    pub mod no_extend_native;
     pub mod no_extra_boolean_cast;
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
+    pub mod no_implicit_coercion;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
     pub mod no_irregular_whitespace;
     pub mod no_iterator;
+    pub mod no_lone_blocks;
     pub mod no_loss_of_precision;
     pub mod no_mixed_operators;
+    pub mod no_mixed_requires;
+    pub mod no_multi_str;
+    pub mod no_new_func;
     pub mod no_new_symbol;
     pub mod no_new_wrappers;
     pub mod no_nonoctal_decimal_escape;
@@ -92,6 +102,7 @@ mod eslint {
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
+    pub mod no_return_assign;
     pub mod no_return_await;
     pub mod no_script_url;
     pub mod no_self_assign;
@@ -99,21 +110,30 @@ mod eslint {
     pub mod no_setter_return;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
+    pub mod no_tabs;
     pub mod no_template_curly_in_string;
     pub mod no_ternary;
     pub mod no_this_before_super;
+    pub mod no_throw_literal;
     pub mod no_undef;
+    pub mod no_unreachable;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
+    pub mod no_use_before_define;
+    pub mod no_useless_call;
     pub mod no_useless_catch;
     pub mod no_useless_escape;
     pub mod no_useless_rename;
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
+    pub mod object_shorthand;
+    pub mod prefer_destructuring;
+    pub mod prefer_exponentiation_operator;
+    pub mod require_await;
     pub mod require_yield;
     pub mod use_isnan;
     pub mod valid_typeof;
@@ -225,6 +245,7 @@ mod unicorn {
     pub mod new_for_builtins;
     pub mod no_abusive_eslint_disable;
     pub mod no_array_for_each;
+    pub mod no_array_method_this_argument;
     pub mod no_array_reduce;
     pub mod no_await_expression_member;
     pub mod no_await_in_promise_methods;
@@ -383,18 +404,24 @@ oxc_macros::declare_all_lint_rules! {
     deepscan::number_arg_out_of_range,
     deepscan::uninvoked_array_callback,
     eslint::array_callback_return,
+    eslint::consistent_return,
     eslint::constructor_super,
     eslint::default_case_last,
     eslint::default_param_last,
     eslint::eqeqeq,
     eslint::for_direction,
+    eslint::func_names,
     eslint::getter_return,
+    eslint::grouped_accessor_pairs,
     eslint::guard_for_in,
+    eslint::id_length,
     eslint::max_len,
     eslint::max_lines,
     eslint::max_params,
     eslint::no_ternary,
     eslint::no_this_before_super,
+    eslint::no_throw_literal,
+    eslint::no_tabs,
     eslint::no_template_curly_in_string,
     eslint::no_array_constructor,
     eslint::no_async_promise_executor,
@@ -422,17 +449,23 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_empty_static_block,
     eslint::no_eval,
     eslint::no_ex_assign,
+    eslint::no_extend_native,
     eslint::no_extra_boolean_cast,
     eslint::no_eq_null,
     eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
+    eslint::no_implicit_coercion,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
     eslint::no_irregular_whitespace,
     eslint::no_iterator,
+    eslint::no_lone_blocks,
     eslint::no_loss_of_precision,
     eslint::no_mixed_operators,
+    eslint::no_mixed_requires,
+    eslint::no_multi_str,
+    eslint::no_new_func,
     eslint::no_new_symbol,
     eslint::no_new_wrappers,
     eslint::no_nonoctal_decimal_escape,
@@ -441,6 +474,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_return_assign,
     eslint::no_return_await,
     eslint::no_script_url,
     eslint::no_self_assign,
@@ -449,17 +483,24 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
+    eslint::no_unreachable,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
+    eslint::no_use_before_define,
+    eslint::no_useless_call,
     eslint::no_useless_catch,
     eslint::no_useless_escape,
     eslint::no_useless_rename,
     eslint::no_var,
     eslint::no_void,
     eslint::no_with,
+    eslint::object_shorthand,
+    eslint::prefer_destructuring,
+    eslint::prefer_exponentiation_operator,
+    eslint::require_await,
     eslint::require_yield,
     eslint::use_isnan,
     eslint::valid_typeof,
@@ -532,6 +573,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::new_for_builtins,
     unicorn::no_abusive_eslint_disable,
     unicorn::no_array_for_each,
+    unicorn::no_array_method_this_argument,
     unicorn::no_array_reduce,
     unicorn::no_await_expression_member,
     unicorn::no_await_in_promise_methods,
@@ -0,0 +1,257 @@
+use oxc_ast::{
+    ast::{MethodDefinitionKind, PropertyKey, PropertyKind, ReturnStatement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ConsistentReturnDiagnostic {
+    #[error("eslint(consistent-return): Expected to return a value at the end of {0}.")]
+    #[diagnostic(severity(warning))]
+    MissingReturn(String, #[label] Span),
+    #[error("eslint(consistent-return): {0} expected a return value.")]
+    #[diagnostic(severity(warning))]
+    MissingReturnValue(String, #[label] Span),
+    #[error("eslint(consistent-return): {0} expected no return value.")]
+    #[diagnostic(severity(warning))]
+    UnexpectedReturnValue(String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentReturn {
+    /// Treat `return undefined` and `return void 0` like a bare `return;`.
+    treat_undefined_as_unspecified: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `return` statements to either always or never specify values
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function that returns a value on some paths and nothing on others is
+    /// confusing, and often a sign that a return value was forgotten.
+    ///
+    /// The first `return` statement of a function decides whether it is expected to
+    /// return a value. With `treatUndefinedAsUnspecified`, `return undefined` and
+    /// `return void 0` count as returning nothing.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return;
+    ///     }
+    /// }
+    ///
+    /// function doSomethingElse(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     }
+    /// }
+    /// ```
+    ConsistentReturn,
+    suspicious
+);
+
+impl Rule for ConsistentReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let treat_undefined_as_unspecified = value
+            .get(0)
+            .and_then(|config| config.get("treatUndefinedAsUnspecified"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { treat_undefined_as_unspecified }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+
+        let mut returns: FxHashMap<AstNodeId, Vec<&ReturnStatement>> = FxHashMap::default();
+        for node in nodes.iter() {
+            if let AstKind::ReturnStatement(stmt) = node.kind() {
+                if let Some(function_id) = nodes.enclosing_function(node.id()) {
+                    returns.entry(function_id).or_default().push(stmt);
+                }
+            }
+        }
+
+        for node in nodes.iter() {
+            if let Some(returns) = returns.get(&node.id()) {
+                self.check_function(node, returns, ctx);
+            }
+        }
+    }
+}
+
+impl ConsistentReturn {
+    fn check_function(&self, node: &AstNode, returns: &[&ReturnStatement], ctx: &LintContext) {
+        let Some((first, rest)) = returns.split_first() else { return };
+        let expects_value = self.is_specified(first);
+        let name = function_name(node, ctx);
+
+        for stmt in rest {
+            if self.is_specified(stmt) == expects_value {
+                continue;
+            }
+            let name = upper_first(&name);
+            if expects_value {
+                ctx.diagnostic(ConsistentReturnDiagnostic::MissingReturnValue(name, stmt.span));
+            } else {
+                ctx.diagnostic(ConsistentReturnDiagnostic::UnexpectedReturnValue(name, stmt.span));
+            }
+        }
+
+        if expects_value && ctx.semantic().function_exit_kinds(node.id()).fall_through {
+            let span = function_head_span(node, ctx);
+            ctx.diagnostic(ConsistentReturnDiagnostic::MissingReturn(name, span));
+        }
+    }
+
+    fn is_specified(&self, stmt: &ReturnStatement) -> bool {
+        stmt.argument.as_ref().is_some_and(|argument| {
+            !(self.treat_undefined_as_unspecified
+                && (argument.is_undefined() || argument.is_void()))
+        })
+    }
+}
+
+/// Describe the function for diagnostics, e.g. `function 'foo'` or `method 'bar'`.
+fn function_name(node: &AstNode, ctx: &LintContext) -> String {
+    let AstKind::Function(func) = node.kind() else {
+        return "arrow function".into();
+    };
+    let (kind, key) = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::MethodDefinition(def)) => match def.kind {
+            MethodDefinitionKind::Constructor => return "constructor".into(),
+            MethodDefinitionKind::Get => ("getter", Some(&def.key)),
+            MethodDefinitionKind::Set => ("setter", Some(&def.key)),
+            MethodDefinitionKind::Method => ("method", Some(&def.key)),
+        },
+        Some(AstKind::ObjectProperty(prop)) => match prop.kind {
+            PropertyKind::Get => ("getter", Some(&prop.key)),
+            PropertyKind::Set => ("setter", Some(&prop.key)),
+            PropertyKind::Init => ("method", Some(&prop.key)),
+        },
+        _ => ("function", None),
+    };
+    let name = func
+        .id
+        .as_ref()
+        .map(|id| id.name.to_string())
+        .or_else(|| key.and_then(PropertyKey::static_name).map(|name| name.to_string()));
+    match name {
+        Some(name) => format!("{kind} '{name}'"),
+        None => kind.into(),
+    }
+}
+
+/// The name of the function, or its head up to the parameters when it has none.
+fn function_head_span(node: &AstNode, ctx: &LintContext) -> Span {
+    match node.kind() {
+        AstKind::Function(func) => {
+            if let Some(id) = &func.id {
+                return id.span;
+            }
+            match ctx.nodes().parent_kind(node.id()) {
+                Some(AstKind::MethodDefinition(def)) => def.key.span(),
+                Some(AstKind::ObjectProperty(prop)) => prop.key.span(),
+                _ => Span::new(func.span.start, func.params.span.end),
+            }
+        }
+        AstKind::ArrowFunctionExpression(arrow) => {
+            Span::new(arrow.span.start, arrow.params.span.end)
+        }
+        kind => kind.span(),
+    }
+}
+
+fn upper_first(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { return; }", None),
+        ("function foo() { if (true) return; }", None),
+        ("function foo() { if (true) return; else return; }", None),
+        ("function foo() { if (true) return true; else return false; }", None),
+        ("function foo() { if (true) { return true; } return false; }", None),
+        ("f(function() { return; })", None),
+        ("f(function() { if (true) return; })", None),
+        ("f(function() { if (true) return true; else return false; })", None),
+        ("f(function() { if (true) { return true; } return false; })", None),
+        ("function foo() { function bar() { return true; } return; }", None),
+        ("function foo() { function bar() { return; } return false; }", None),
+        ("function foo() { return 1; }", None),
+        ("function foo() { if (true) return true; throw new Error(); }", None),
+        ("var x = () => { return {}; };", None),
+        ("var x = () => 1;", None),
+        ("var x = (a) => a ? 1 : undefined;", None),
+        ("class Foo { bar() { return 1; } }", None),
+        ("var obj = { get foo() { return 1; } };", None),
+        (
+            "function foo() { if (true) return; else return void 0; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return undefined; else return; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return void 0; return; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return undefined; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) return true; else return; }", None),
+        ("var foo = () => { if (true) return true; else return; }", None),
+        ("function foo() { if (true) return; else return false; }", None),
+        ("f(function() { if (true) return true; else return; })", None),
+        ("f(function() { if (true) return; else return false; })", None),
+        ("f(a => { if (true) return; else return false; })", None),
+        (
+            "function foo() { if (true) return true; return undefined; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return true; return void 0; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return undefined; return true; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        ("function foo() { if (true) return 1; }", None),
+        ("function foo() { if (true) return true; }", None),
+        ("var foo = function() { if (true) return 1; };", None),
+        ("var foo = () => { if (true) return 1; };", None),
+        ("class Foo { bar() { if (true) return 1; } }", None),
+        ("var obj = { bar() { if (true) return 1; } };", None),
+        ("function foo() { if (a) return 1; else if (b) return; else return 2; }", None),
+        ("function foo() { if (true) return undefined; else return; }", None),
+    ];
+
+    Tester::new(ConsistentReturn::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,9 +1,19 @@
+use oxc_ast::{
+    ast::{Expression, MethodDefinitionKind},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::{
+    petgraph::{stable_graph::NodeIndex, visit::EdgeRef, Direction},
+    AssignmentValue, AstNodeId, BasicBlockElement, EdgeType, Register,
+};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -12,6 +22,19 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[diagnostic(severity(warning), help("Ensure 'super()' is called from constructor"))]
 struct ConstructorSuperDiagnostic(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(constructor-super): Lacked a call of 'super()' in some code paths.")]
+#[diagnostic(
+    severity(warning),
+    help("Ensure 'super()' is called in every code path of the constructor")
+)]
+struct MissingSomeSuperDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(constructor-super): Unexpected duplicate 'super()'.")]
+#[diagnostic(severity(warning), help("'super()' can only be called once in each code path."))]
+struct DuplicateSuperDiagnostic(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Unexpected 'super()' because 'super' is not a constructor.")]
 #[diagnostic(severity(warning), help("Do not call 'super()' from constructor."))]
@@ -29,20 +52,234 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Constructors of derived classes must call `super()` before returning, calling it twice
+    /// throws a `ReferenceError`. Constructors of classes extending something that is not a
+    /// constructor (e.g. `extends null`) cannot call `super()` at all.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
     /// class A extends B {
     ///   constructor() {}
     /// }
+    /// class A extends B {
+    ///   constructor() {
+    ///     if (a) super();
+    ///   }
+    /// }
+    /// class A extends null {
+    ///   constructor() {
+    ///     super();
+    ///   }
+    /// }
+    ///
+    /// // Good
+    /// class A extends B {
+    ///   constructor() {
+    ///     if (a) super(a);
+    ///     else super();
+    ///   }
+    /// }
     /// ```
     ConstructorSuper,
-    nursery // This rule should be implemented with CFG, the current implementation has a lot of
-            // false positives.
+    nursery
 );
 
+/// Whether the result of `super_class` may be a constructor.
+fn is_possible_constructor(super_class: &Expression) -> bool {
+    match super_class.get_inner_expression() {
+        Expression::ClassExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ThisExpression(_)
+        | Expression::MemberExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::MetaProperty(_) => true,
+        Expression::Identifier(ident) => ident.name != "undefined",
+        Expression::AssignmentExpression(expr) => match expr.operator {
+            AssignmentOperator::Assign | AssignmentOperator::LogicalAnd => {
+                is_possible_constructor(&expr.right)
+            }
+            // The target itself may already be a constructor
+            AssignmentOperator::LogicalOr | AssignmentOperator::LogicalNullish => true,
+            _ => false,
+        },
+        Expression::LogicalExpression(expr) => {
+            if expr.operator == LogicalOperator::And {
+                is_possible_constructor(&expr.right)
+            } else {
+                is_possible_constructor(&expr.left) || is_possible_constructor(&expr.right)
+            }
+        }
+        Expression::ConditionalExpression(expr) => {
+            is_possible_constructor(&expr.alternate) || is_possible_constructor(&expr.consequent)
+        }
+        Expression::SequenceExpression(expr) => {
+            expr.expressions.last().is_some_and(|expr| is_possible_constructor(expr))
+        }
+        _ => false,
+    }
+}
+
+/// Spans of the `super()` calls in a function, grouped by basic block in call order.
+type SuperCalls = FxHashMap<NodeIndex, Vec<Span>>;
+
+#[derive(Debug, Default)]
+struct CodePaths {
+    /// Some code path returns after calling `super()` (or returns a value).
+    some_called: bool,
+    /// Some code path returns without calling `super()`.
+    some_missing: bool,
+    /// `super()` calls reached when `super()` was already called on the same code path.
+    duplicates: FxHashSet<Span>,
+}
+
 impl Rule for ConstructorSuper {
-    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+
+        let mut constructors = vec![];
+        let mut super_calls = FxHashMap::<AstNodeId, SuperCalls>::default();
+        for node in nodes.iter() {
+            match node.kind() {
+                AstKind::Function(func) if func.body.is_some() => {
+                    if let Some(AstKind::MethodDefinition(method)) = nodes.parent_kind(node.id()) {
+                        if method.kind == MethodDefinitionKind::Constructor {
+                            constructors.push(node);
+                        }
+                    }
+                }
+                AstKind::Super(_) => {
+                    let Some(AstKind::CallExpression(call_expr)) = nodes.parent_kind(node.id())
+                    else {
+                        continue;
+                    };
+                    let Some(function_id) = nodes.enclosing_function(node.id()) else {
+                        continue;
+                    };
+                    // The `Super` node is visited after the arguments, so it belongs to the
+                    // basic block in which the call happens.
+                    super_calls
+                        .entry(function_id)
+                        .or_default()
+                        .entry(node.cfg_ix())
+                        .or_default()
+                        .push(call_expr.span);
+                }
+                _ => {}
+            }
+        }
+
+        let no_super_calls = SuperCalls::default();
+        for node in constructors {
+            let super_calls = super_calls.get(&node.id()).unwrap_or(&no_super_calls);
+            Self::check_constructor(node, super_calls, ctx);
+        }
+    }
+}
+
+impl ConstructorSuper {
+    fn check_constructor(node: &AstNode, super_calls: &SuperCalls, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+        // Function > MethodDefinition > ClassBody > Class
+        let Some(class_id) = nodes.ancestors(node.id()).nth(3) else {
+            return;
+        };
+        let Some(super_class) = nodes.class_super_class(class_id) else {
+            return;
+        };
+        let Some(AstKind::MethodDefinition(method)) = nodes.parent_kind(node.id()) else {
+            return;
+        };
+
+        let code_paths = Self::walk_code_paths(node.cfg_ix(), super_calls, ctx);
+        if !code_paths.some_called {
+            if code_paths.some_missing {
+                ctx.diagnostic(ConstructorSuperDiagnostic(method.key.span()));
+            }
+        } else if code_paths.some_missing {
+            ctx.diagnostic(MissingSomeSuperDiagnostic(method.key.span()));
+        }
+
+        if is_possible_constructor(super_class) {
+            let mut duplicates = code_paths.duplicates.into_iter().collect::<Vec<_>>();
+            duplicates.sort_unstable_by_key(|span| span.start);
+            for span in duplicates {
+                ctx.diagnostic(DuplicateSuperDiagnostic(span));
+            }
+        } else {
+            let mut calls = super_calls.values().flatten().copied().collect::<Vec<_>>();
+            calls.sort_unstable_by_key(|span| span.start);
+            for span in calls {
+                ctx.diagnostic(SuperNotConstructorDiagnostic(span, super_class.span()));
+            }
+        }
+    }
+
+    /// Walk every code path of the function starting at `start`, tracking whether `super()`
+    /// has been called on it.
+    ///
+    /// Paths end at a `return`, or when falling off the end of the function. Paths ending
+    /// with a `throw` or in unreachable code are ignored. Each basic block is walked at most
+    /// once per state, so a `super()` call in a loop body is seen once more as a duplicate.
+    fn walk_code_paths(start: NodeIndex, super_calls: &SuperCalls, ctx: &LintContext) -> CodePaths {
+        let cfg = ctx.semantic().cfg();
+        let mut code_paths = CodePaths::default();
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![(start, false)];
+
+        while let Some((block_ix, mut called)) = stack.pop() {
+            if !visited.insert((block_ix, called)) {
+                continue;
+            }
+
+            for span in super_calls.get(&block_ix).into_iter().flatten() {
+                if called {
+                    code_paths.duplicates.insert(*span);
+                }
+                called = true;
+            }
+
+            let mut ended = None;
+            for element in cfg.basic_block_by_index(block_ix) {
+                match element {
+                    BasicBlockElement::Throw(_) | BasicBlockElement::Unreachable => {
+                        ended = Some(None);
+                        break;
+                    }
+                    // `return value;` can return an object instead of `this`
+                    BasicBlockElement::Assignment(Register::Return, value) => {
+                        let returns_value = matches!(value, AssignmentValue::NotImplicitUndefined);
+                        ended = Some(Some(called || returns_value));
+                        break;
+                    }
+                    BasicBlockElement::Assignment(..) => {}
+                }
+            }
+
+            let ended = ended.unwrap_or_else(|| {
+                let mut has_next = false;
+                for edge in cfg.graph.edges_directed(block_ix, Direction::Outgoing) {
+                    if matches!(edge.weight(), EdgeType::Normal | EdgeType::Backedge) {
+                        stack.push((edge.target(), called));
+                        has_next = true;
+                    }
+                }
+                (!has_next).then_some(called)
+            });
+
+            match ended {
+                Some(true) => code_paths.some_called = true,
+                Some(false) => code_paths.some_missing = true,
+                None => {}
+            }
+        }
+
+        code_paths
+    }
 }
 
 #[test]
@@ -65,14 +302,34 @@ fn test() {
         ("class A extends (B ??= 5) { constructor() { super(); } }", None),
         ("class A extends (B || C) { constructor() { super(); } }", None),
         ("class A extends (5 && B) { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { if (a) { super(); } else { super(); } } }", None),
+        ("class A extends B { constructor() { if (a) super(); else super(); } }", None),
+        ("class A extends B { constructor() { if (a) { super(); } else { super(); } this.c(); } }", None),
+        ("class A extends B { constructor() { if (a) throw new Error(); super(); } }", None),
+        ("class A extends B { constructor() { if (a) return {}; super(); } }", None),
+        ("class A extends B { constructor() { super(); return; } }", None),
+        ("class A extends B { constructor(a) { super(); for (const b of a) { this.a(); } } }", None),
+        ("class A extends B { constructor(a) { for (const b of a) { foo(b); } super(); } }", None),
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } super(); } }", None),
+        ("class A extends B { constructor() { const c = function () { return 1; }; super(); } }", None),
+        ("class A extends B { foo() { } }", None),
     ];
 
     let fail = vec![
-        // ("class A extends B { constructor() {} }", None),
-        // ("class A extends null { constructor() { super(); } }", None),
-        // ("class A extends null { constructor() { } }", None),
-        // ("class A extends 100 { constructor() { super(); } }", None),
-        // ("class A extends 'test' { constructor() { super(); } }", None),
+        ("class A extends B { constructor() {} }", None),
+        ("class A extends null { constructor() { } }", None),
+        ("class A extends B { constructor() { if (a) super(); } }", None),
+        ("class A extends B { constructor() { if (a) { super(); } else { foo(); } } }", None),
+        ("class A extends B { constructor() { if (a) return; super(); } }", None),
+        ("class A extends B { constructor() { a && super(); } }", None),
+        ("class A extends B { constructor() { super(); super(); } }", None),
+        ("class A extends B { constructor() { if (a) super(); super(); } }", None),
+        ("class A extends B { constructor() { for (const a of b) super(); } }", None),
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends null {\n  constructor() { super(); }\n}", None),
+        ("class A extends 100 {\n  constructor() { super(); }\n}", None),
+        ("class A extends 'test' {\n  constructor() { super(); }\n}", None),
+        ("class A extends (B += C) {\n  constructor() { super(); }\n}", None),
     ];
 
     Tester::new(ConstructorSuper::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,283 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPatternKind, Expression, Function, PropertyKind,
+        SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::outermost_paren_parent, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum FuncNamesDiagnostic {
+    #[error("eslint(func-names): Unexpected unnamed {0}.")]
+    #[diagnostic(severity(warning), help("Name the function to get better stack traces"))]
+    Unnamed(&'static str, #[label] Span),
+    #[error("eslint(func-names): Unexpected named {0} '{1}'.")]
+    #[diagnostic(severity(warning), help("Remove the name of the function"))]
+    Named(&'static str, String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FuncNames {
+    mode: FuncNamesMode,
+    /// Overrides `mode` for generator functions
+    generators: Option<FuncNamesMode>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum FuncNamesMode {
+    /// Requires function expressions to have a name
+    #[default]
+    Always,
+    /// Requires a name if it can't be inferred from the context
+    AsNeeded,
+    /// Disallows named function expressions, unless they call themselves
+    Never,
+}
+
+impl FuncNamesMode {
+    fn from_str(value: &str) -> Self {
+        match value {
+            "as-needed" => Self::AsNeeded,
+            "never" => Self::Never,
+            _ => Self::Always,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow named `function` expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Stack traces show `<anonymous>` for functions without a name, which makes errors
+    /// harder to trace back to their origin.
+    ///
+    /// ### Options
+    ///
+    /// The first option is `"always"` (default), `"as-needed"`, which allows unnamed functions
+    /// whose name is inferred from the variable or property they are assigned to, or `"never"`.
+    ///
+    /// The second option `{ "generators": "always" | "as-needed" | "never" }` overrides the
+    /// first one for generator functions.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// Foo.prototype.bar = function() {};
+    ///
+    /// // Good
+    /// Foo.prototype.bar = function bar() {};
+    /// ```
+    FuncNames,
+    style
+);
+
+impl Rule for FuncNames {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = value
+            .get(0)
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(FuncNamesMode::default, FuncNamesMode::from_str);
+        let generators = value
+            .get(1)
+            .and_then(|options| options.get("generators"))
+            .and_then(serde_json::Value::as_str)
+            .map(FuncNamesMode::from_str);
+        Self { mode, generators }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Function(func) = node.kind() else { return };
+        let parent = outermost_paren_parent(node, ctx).map(AstNode::kind);
+        // `export default function() {}` is the only function declaration without a name
+        if !func.is_expression() && !matches!(parent, Some(AstKind::ExportDefaultDeclaration(_))) {
+            return;
+        }
+
+        let func_mode = self.generators.filter(|_| func.generator).unwrap_or(self.mode);
+        let span = keyword_span(func, ctx);
+        match &func.id {
+            Some(id) if func_mode == FuncNamesMode::Never && func.is_expression() => {
+                // Recursive functions need their name
+                let is_recursive = id.symbol_id.get().is_some_and(|symbol_id| {
+                    ctx.semantic().symbol_references(symbol_id).count() > 0
+                });
+                if !is_recursive {
+                    ctx.diagnostic(FuncNamesDiagnostic::Named(
+                        function_kind(func),
+                        id.name.to_string(),
+                        span,
+                    ));
+                }
+            }
+            None if func_mode != FuncNamesMode::Never => {
+                if is_method(parent)
+                    || (func_mode == FuncNamesMode::AsNeeded && has_inferred_name(func, parent))
+                {
+                    return;
+                }
+                ctx.diagnostic(FuncNamesDiagnostic::Unnamed(function_kind(func), span));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The span of the `function` keyword.
+#[allow(clippy::cast_possible_truncation)]
+fn keyword_span(func: &Function, ctx: &LintContext) -> Span {
+    let start = ctx.source_range(func.span).find("function").map_or(0, |offset| offset as u32);
+    let start = func.span.start + start;
+    Span::new(start, start + 8) // 8 for "function".len()
+}
+
+fn function_kind(func: &Function) -> &'static str {
+    match (func.r#async, func.generator) {
+        (true, true) => "async generator function",
+        (true, false) => "async function",
+        (false, true) => "generator function",
+        (false, false) => "function",
+    }
+}
+
+/// Object and class methods are named by their key.
+fn is_method(parent: Option<AstKind>) -> bool {
+    match parent {
+        Some(AstKind::MethodDefinition(_)) => true,
+        Some(AstKind::ObjectProperty(prop)) => prop.method || prop.kind != PropertyKind::Init,
+        _ => false,
+    }
+}
+
+/// Whether the function gets its name from the binding or property it is assigned to.
+fn has_inferred_name(func: &Function, parent: Option<AstKind>) -> bool {
+    let is_func = |expr: &Expression| expr.without_parenthesized().span() == func.span;
+    match parent {
+        Some(AstKind::VariableDeclarator(decl)) => {
+            matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_))
+                && decl.init.as_ref().is_some_and(is_func)
+        }
+        Some(AstKind::ObjectProperty(prop)) => is_func(&prop.value),
+        Some(AstKind::PropertyDefinition(def)) => def.value.as_ref().is_some_and(is_func),
+        Some(AstKind::AssignmentExpression(expr)) => {
+            is_identifier_target(&expr.left) && is_func(&expr.right)
+        }
+        Some(AstKind::AssignmentTargetWithDefault(target)) => {
+            is_identifier_target(&target.binding) && is_func(&target.init)
+        }
+        // `({ foo = function() {} } = bar)`, object assignment targets have no node of their own
+        Some(AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(_))) => true,
+        Some(AstKind::AssignmentPattern(pattern)) => {
+            matches!(pattern.left.kind, BindingPatternKind::BindingIdentifier(_))
+                && is_func(&pattern.right)
+        }
+        _ => false,
+    }
+}
+
+fn is_identifier_target(target: &AssignmentTarget) -> bool {
+    matches!(
+        target,
+        AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(_)
+        )
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let always = Some(serde_json::json!(["always"]));
+    let as_needed = Some(serde_json::json!(["as-needed"]));
+    let never = Some(serde_json::json!(["never"]));
+
+    let pass = vec![
+        ("Foo.prototype.bar = function bar(){};", None),
+        ("Foo.prototype.bar = () => {}", None),
+        ("function foo(){}", None),
+        ("function test(d, e, f) {}", None),
+        ("new function bar(){}", None),
+        ("exports = { get foo() { return 1; }, set bar(val) { return val; } };", None),
+        ("({ foo() { return 1; } });", None),
+        ("class A { constructor(){} foo(){} get bar(){} set baz(value){} static qux(){}}", None),
+        ("function foo() {}", always.clone()),
+        ("var a = function foo() {};", always.clone()),
+        ("class A { constructor(){} foo(){} get bar(){} set baz(value){} }", as_needed.clone()),
+        ("({ foo() {} });", as_needed.clone()),
+        ("var foo = function(){};", as_needed.clone()),
+        ("({foo: function(){}});", as_needed.clone()),
+        ("(foo = function(){});", as_needed.clone()),
+        ("({foo = function(){}} = {});", as_needed.clone()),
+        ("({key: foo = function(){}} = {});", as_needed.clone()),
+        ("[foo = function(){}] = [];", as_needed.clone()),
+        ("function fn(foo = function(){}) {}", as_needed.clone()),
+        ("var foo = (function(){});", as_needed.clone()),
+        ("class C { foo = function() {}; }", as_needed.clone()),
+        ("function foo() {}", never.clone()),
+        ("var a = function() {};", never.clone()),
+        ("var a = function foo() { foo(); };", never.clone()),
+        ("var foo = {bar: function() {}};", never.clone()),
+        ("$('#foo').click(function() {});", never.clone()),
+        ("Foo.prototype.bar = function() {};", never.clone()),
+        ("class A { constructor(){} foo(){} get bar(){} set baz(value){} }", never.clone()),
+        ("({ foo() {} });", never.clone()),
+        ("export default function foo() {}", never.clone()),
+        ("export default function foo() {}", always.clone()),
+        ("var foo = bar(function *baz() {});", Some(serde_json::json!(["always"]))),
+        (
+            "var foo = function*() {};",
+            Some(serde_json::json!(["always", { "generators": "as-needed" }])),
+        ),
+        ("var foo = bar(function *() {});", Some(serde_json::json!(["never"]))),
+        (
+            "var foo = function*() {};",
+            Some(serde_json::json!(["never", { "generators": "as-needed" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("Foo.prototype.bar = function() {};", None),
+        ("(function(){}())", None),
+        ("f(function(){})", None),
+        ("var a = new Date(function() {});", None),
+        ("var test = function(d, e, f) {};", None),
+        ("new function() {}", None),
+        ("Foo.prototype.bar = function() {};", as_needed.clone()),
+        ("(function(){}())", as_needed.clone()),
+        ("f(function(){})", as_needed.clone()),
+        ("var {foo} = function(){};", as_needed.clone()),
+        ("({ a: obj.prop = function(){} } = foo);", as_needed.clone()),
+        ("[obj.prop = function(){}] = foo;", as_needed.clone()),
+        ("export default function() {}", as_needed.clone()),
+        ("export default (function(){});", as_needed.clone()),
+        ("var {a: [b] = function(){}} = foo;", as_needed.clone()),
+        ("function foo({ a } = function(){}) {};", as_needed.clone()),
+        ("var x = function foo() {};", never.clone()),
+        ("Foo.prototype.bar = function foo() {};", never.clone()),
+        ("({foo: function foo() {}})", never.clone()),
+        ("var foo = bar(async function() {});", always.clone()),
+        ("var foo = bar(function *() {});", always.clone()),
+        (
+            "var foo = function*() {};",
+            Some(serde_json::json!(["as-needed", { "generators": "always" }])),
+        ),
+        (
+            "var foo = bar(async function *baz() {});",
+            Some(serde_json::json!(["always", { "generators": "never" }])),
+        ),
+        ("export default function() {}", always.clone()),
+    ];
+
+    Tester::new(FuncNames::NAME, pass, fail).test_and_snapshot();
+}
@@ -10,9 +10,6 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::{
-    pg::neighbors_filtered_by_edge_weight, AssignmentValue, BasicBlockElement, EdgeType, Register,
-};
 use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
@@ -175,146 +172,27 @@ impl GetterReturn {
             return;
         }
 
-        let cfg = ctx.semantic().cfg();
-
-        let output = neighbors_filtered_by_edge_weight(
-            &cfg.graph,
-            node.cfg_ix(),
-            &|edge| match edge {
-                EdgeType::Normal => None,
-                // We don't need to handle backedges because we would have already visited
-                // them on the forward pass
-                | EdgeType::Backedge
-                // We don't need to visit NewFunction edges because it's not going to be evaluated
-                // immediately, and we are only doing a pass over things that will be immediately evaluated
-                | EdgeType::NewFunction
-                // By returning Some(X),
-                // we signal that we don't walk to this path any farther.
-                //
-                // We stop this path on a ::Yes because if it was a ::No,
-                // we would have already returned early before exploring more edges
-                => Some(DefinitelyReturnsOrThrowsOrUnreachable::Yes),
-            },
-            // We ignore the state going into this rule because we only care whether
-            // or not this path definitely returns or throws.
-            //
-            // Whether or not the path definitely returns is only has two states, Yes (the default)
-            // or No (when we see this, we immediately stop walking). Other rules that require knowing
-            // previous such as [`no_this_before_super`] we would want to observe this value.
-            &mut |basic_block_id, _state_going_into_this_rule| {
-                // The expression is the equivalent of return.
-                // Therefore, if a function is an expression, it always returns its value.
-                //
-                // Example expression:
-                // ```js
-                // const fn = () => 1;
-                // ```
-                if let AstKind::ArrowFunctionExpression(arrow_expr) = node.kind() {
-                    if arrow_expr.expression {
-                        return (DefinitelyReturnsOrThrowsOrUnreachable::Yes, false);
-                    }
-                }
-
-                // If the signature of function supports the return of the `undefined` value,
-                // you do not need to check this rule
-                if let AstKind::Function(func) = node.kind() {
-                    if let Some(ref ret) = func.return_type {
-                        if ret.type_annotation.is_maybe_undefined() {
-                            return (DefinitelyReturnsOrThrowsOrUnreachable::Yes, false);
-                        }
-                    }
-                }
-
-                // Scan through the values in this basic block.
-                for entry in cfg.basic_block_by_index(*basic_block_id) {
-                    match entry {
-                        // If the element is an assignment.
-                        //
-                        // Everything you can write in javascript that would have
-                        // the function continue are expressed as assignments in the cfg.
-                        BasicBlockElement::Assignment(to_reg, val) => {
-                            // If the assignment is to the return register.
-                            //
-                            // The return register is a special register that return statements
-                            // assign the returned value to.
-                            if matches!(to_reg, Register::Return) {
-                                // `allow_implicit` allows returning without a value to not
-                                // fail the rule. We check for this by checking if the value
-                                // being returned in the cfg this is expressed as
-                                // `AssignmentValue::ImplicitUndefined`.
-                                //
-                                // There is an assumption being made here that returning an
-                                // `undefined` will put the `undefined` directly into the
-                                // return and will not put the `undefined` into an immediate
-                                // register and return the register. However, the tests for
-                                // this rule enforce that this invariant is not broken.
-                                if !self.allow_implicit
-                                    && matches!(val, AssignmentValue::ImplicitUndefined)
-                                {
-                                    // Return false as the second argument to signify we should
-                                    // not continue walking this branch, as we know a return
-                                    // is the end of this path.
-                                    return (DefinitelyReturnsOrThrowsOrUnreachable::No, false);
-                                }
-                                // Otherwise, we definitely returned since we assigned
-                                // to the return register.
-                                //
-                                // Return false as the second argument to signify we should
-                                // not continue walking this branch, as we know a return
-                                // is the end of this path.
-                                return (DefinitelyReturnsOrThrowsOrUnreachable::Yes, false);
-                            }
-                        }
-                        // Throws are classified as returning.
-                        //
-                        // todo: test with catching...
-                        BasicBlockElement::Throw(_) |
-                        // Although the unreachable code is not returned, it will never be executed.
-                        // There is no point in checking it for return.
-                        //
-                        // An example in such cases:
-                        // ```js
-                        // switch (val) {
-                        //     default: return 1;
-                        // }
-                        // return -1;
-                        // ```
-                        // Make return useless.
-                        BasicBlockElement::Unreachable => {
-
-                            return (DefinitelyReturnsOrThrowsOrUnreachable::Yes, false);
-                        }
-                    }
+        // If the signature of function supports the return of the `undefined` value,
+        // you do not need to check this rule
+        if let AstKind::Function(func) = node.kind() {
+            if let Some(ref ret) = func.return_type {
+                if ret.type_annotation.is_maybe_undefined() {
+                    return;
                 }
+            }
+        }
 
-                // Return true as the second argument to signify we should
-                // continue walking this branch, as we haven't seen anything
-                // that will signify to us that this path of the program will
-                // definitely return or throw.
-                (DefinitelyReturnsOrThrowsOrUnreachable::No, true)
-            },
-        );
-
-        // Deciding whether we definitely return or throw in all
-        // codepaths is as simple as seeing if each individual codepath
-        // definitely returns or throws.
-        let definitely_returns_in_all_codepaths =
-            output.into_iter().all(|y| matches!(y, DefinitelyReturnsOrThrowsOrUnreachable::Yes));
+        // Throws and unreachable code end a path just like a return does, and
+        // arrow functions with an expression body always return their value.
+        let exits = ctx.semantic().function_exit_kinds(node.id());
 
-        // If not, flag it as a diagnostic.
-        if !definitely_returns_in_all_codepaths {
+        // `allow_implicit` allows returning without a value to not fail the rule.
+        if exits.fall_through || (exits.bare_return && !self.allow_implicit) {
             ctx.diagnostic(GetterReturnDiagnostic(span));
         }
     }
 }
 
-#[derive(Default, Copy, Clone, Debug)]
-enum DefinitelyReturnsOrThrowsOrUnreachable {
-    #[default]
-    No,
-    Yes,
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
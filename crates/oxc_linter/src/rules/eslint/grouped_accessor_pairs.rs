@@ -0,0 +1,267 @@
+use oxc_ast::{
+    ast::{ClassElement, MethodDefinitionKind, ObjectPropertyKind, PropertyKey, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum GroupedAccessorPairsDiagnostic {
+    #[error("eslint(grouped-accessor-pairs): Accessor pair {0} and {1} should be grouped.")]
+    #[diagnostic(severity(warning), help("Move the accessors next to each other"))]
+    NotGrouped(String, String, #[label] Span),
+    #[error("eslint(grouped-accessor-pairs): Expected {1} to be before {0}.")]
+    #[diagnostic(severity(warning), help("Swap the order of the accessors"))]
+    InvalidOrder(String, String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GroupedAccessorPairs {
+    order: PairOrder,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum PairOrder {
+    #[default]
+    AnyOrder,
+    GetBeforeSet,
+    SetBeforeGet,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require grouped accessor pairs in object literals and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A getter and a setter for the same property don't have to be defined next to each
+    /// other, but keeping them together makes the code easier to read.
+    ///
+    /// With the `getBeforeSet` or `setBeforeGet` option, the accessors of a pair must also
+    /// be defined in that order.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const foo = {
+    ///     get a() { return this.val; },
+    ///     b: 1,
+    ///     set a(value) { this.val = value; }
+    /// };
+    ///
+    /// // Good
+    /// const foo = {
+    ///     get a() { return this.val; },
+    ///     set a(value) { this.val = value; },
+    ///     b: 1
+    /// };
+    /// ```
+    GroupedAccessorPairs,
+    style
+);
+
+/// A getter or setter of an object literal or a class body.
+struct Accessor {
+    /// Position among the properties or the class members.
+    index: usize,
+    is_getter: bool,
+    description: String,
+    span: Span,
+}
+
+impl Rule for GroupedAccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let order = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("getBeforeSet") => PairOrder::GetBeforeSet,
+            Some("setBeforeGet") => PairOrder::SetBeforeGet,
+            _ => PairOrder::AnyOrder,
+        };
+        Self { order }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectExpression(object) => {
+                let accessors = object.properties.iter().enumerate().filter_map(|(index, prop)| {
+                    let ObjectPropertyKind::ObjectProperty(prop) = prop else { return None };
+                    let is_getter = match prop.kind {
+                        PropertyKind::Get => true,
+                        PropertyKind::Set => false,
+                        PropertyKind::Init => return None,
+                    };
+                    let span = head_span(prop.span, &prop.key, prop.computed, ctx);
+                    Some((index, is_getter, false, span, &prop.key))
+                });
+                self.check_accessors(accessors, ctx);
+            }
+            AstKind::ClassBody(body) => {
+                // Static and instance members are checked separately, but other members between
+                // them still break up a pair
+                for is_static in [false, true] {
+                    let accessors = body
+                        .body
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, elem)| elem.r#static() == is_static)
+                        .filter_map(|(index, elem)| {
+                            let ClassElement::MethodDefinition(def) = elem else { return None };
+                            let is_getter = match def.kind {
+                                MethodDefinitionKind::Get => true,
+                                MethodDefinitionKind::Set => false,
+                                _ => return None,
+                            };
+                            let span = head_span(def.span, &def.key, def.computed, ctx);
+                            Some((index, is_getter, is_static, span, &def.key))
+                        });
+                    self.check_accessors(accessors, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl GroupedAccessorPairs {
+    fn check_accessors<'a: 'b, 'b>(
+        &self,
+        accessors: impl Iterator<Item = (usize, bool, bool, Span, &'b PropertyKey<'a>)>,
+        ctx: &LintContext<'a>,
+    ) {
+        let mut keys = vec![];
+        let mut pairs: FxHashMap<AccessorKey, Vec<Accessor>> = FxHashMap::default();
+        for (index, is_getter, is_static, span, key) in accessors {
+            let (accessor_key, description) = describe(key, is_getter, is_static, ctx);
+            let accessor = Accessor { index, is_getter, description, span };
+            if !pairs.contains_key(&accessor_key) {
+                keys.push(accessor_key.clone());
+            }
+            pairs.entry(accessor_key).or_default().push(accessor);
+        }
+
+        for key in keys {
+            let [former, latter] = pairs[&key].as_slice() else { continue };
+            if former.is_getter == latter.is_getter {
+                continue;
+            }
+            if latter.index - former.index > 1 {
+                ctx.diagnostic(GroupedAccessorPairsDiagnostic::NotGrouped(
+                    former.description.clone(),
+                    latter.description.clone(),
+                    latter.span,
+                ));
+            } else if (self.order == PairOrder::GetBeforeSet && !former.is_getter)
+                || (self.order == PairOrder::SetBeforeGet && former.is_getter)
+            {
+                ctx.diagnostic(GroupedAccessorPairsDiagnostic::InvalidOrder(
+                    former.description.clone(),
+                    latter.description.clone(),
+                    latter.span,
+                ));
+            }
+        }
+    }
+}
+
+/// The span of an accessor up to the end of its key, including the `]` of computed keys.
+#[allow(clippy::cast_possible_truncation)]
+fn head_span(span: Span, key: &PropertyKey, computed: bool, ctx: &LintContext) -> Span {
+    let mut end = key.span().end;
+    if computed {
+        let rest = ctx.source_range(Span::new(end, span.end));
+        end += rest.find(']').map_or(0, |offset| offset as u32 + 1);
+    }
+    Span::new(span.start, end)
+}
+
+/// The name an accessor is paired by.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum AccessorKey {
+    Static(String),
+    Private(String),
+    /// Computed keys without a static name are paired by their source text
+    Computed(String),
+}
+
+/// Returns the key of the accessor and its description, such as `static getter 'a'`.
+fn describe(
+    key: &PropertyKey,
+    is_getter: bool,
+    is_static: bool,
+    ctx: &LintContext,
+) -> (AccessorKey, String) {
+    let prefix = if is_static { "static " } else { "" };
+    let kind = if is_getter { "getter" } else { "setter" };
+    if let Some(name) = key.private_name() {
+        (AccessorKey::Private(name.to_string()), format!("{prefix}private {kind} #{name}"))
+    } else if let Some(name) = key.static_name() {
+        (AccessorKey::Static(name.to_string()), format!("{prefix}{kind} '{name}'"))
+    } else {
+        let source = ctx.source_range(key.span()).to_string();
+        (AccessorKey::Computed(source), format!("{prefix}{kind}"))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("({})", None),
+        ("({ a, b })", None),
+        ("({ get a() {} })", None),
+        ("({ set a(foo) {} })", None),
+        ("({ get a() {}, set a(foo) {} })", None),
+        ("({ set a(foo) {}, get a() {} })", None),
+        ("({ get a() {}, set a(foo) {}, b: 1 })", None),
+        ("({ get a() {}, b: 1, set c(foo) {} })", None),
+        ("({ get a() {}, set a(foo) {}, get b() {}, set b(foo) {} })", None),
+        ("({ get 'a'() {}, set a(foo) {} })", None),
+        ("({ get [a]() {}, set [a](foo) {} })", None),
+        ("({ get [a]() {}, b: 1, set [c](foo) {} })", None),
+        ("({ get a() {}, b: 1, set [a](foo) {} })", None),
+        // More than one getter or setter is reported by `no-dupe-keys` instead
+        ("({ get a() {}, b: 1, get a() {}, set a(foo) {} })", None),
+        ("({ get a() {}, set a(foo) {} })", Some(serde_json::json!(["anyOrder"]))),
+        ("({ get a() {}, set a(foo) {} })", Some(serde_json::json!(["getBeforeSet"]))),
+        ("({ set a(foo) {}, get a() {} })", Some(serde_json::json!(["setBeforeGet"]))),
+        ("class A { get a() {} set a(foo) {} }", None),
+        ("class A { get a() {} b() {} static set a(foo) {} }", None),
+        ("class A { get #a() {} set #a(foo) {} }", None),
+        ("class A { get #a() {} b() {} set a(foo) {} }", None),
+        ("class A { get a() {} set a(foo) {} }", Some(serde_json::json!(["getBeforeSet"]))),
+        (
+            "class A { static set a(foo) {} static get a() {} }",
+            Some(serde_json::json!(["setBeforeGet"])),
+        ),
+    ];
+
+    let fail = vec![
+        ("({ get a() {}, b: 1, set a(foo) {} })", None),
+        ("({ set a(foo) {}, b: 1, get a() {} })", None),
+        ("({ get 'a'() {}, b: 1, set a(foo) {} })", None),
+        ("({ get [a]() {}, b: 1, set [a](foo) {} })", None),
+        ("({ get a() {}, set b(foo) {}, set a(foo) {}, get b() {} })", None),
+        ("({ set a(foo) {}, get a() {} })", Some(serde_json::json!(["getBeforeSet"]))),
+        ("({ get a() {}, set a(foo) {} })", Some(serde_json::json!(["setBeforeGet"]))),
+        ("class A { get a() {} b() {} set a(foo) {} }", None),
+        ("class A { static get a() {} static b() {} static set a(foo) {} }", None),
+        ("class A { get a() {} static foo() {} set a(foo) {} }", None),
+        ("class A { static get a() {} foo() {} static set a(foo) {} }", None),
+        ("class A { get #a() {} b() {} set #a(foo) {} }", None),
+        ("class A { set a(foo) {} get a() {} }", Some(serde_json::json!(["getBeforeSet"]))),
+        (
+            "class A { static get a() {} static set a(foo) {} }",
+            Some(serde_json::json!(["setBeforeGet"])),
+        ),
+    ];
+
+    Tester::new(GroupedAccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
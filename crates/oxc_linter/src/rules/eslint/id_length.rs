@@ -0,0 +1,293 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, MemberExpression, PropertyKey, SimpleAssignmentTarget},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum IdLengthDiagnostic {
+    #[error("eslint(id-length): Identifier name '{0}' is too short (< {1}).")]
+    #[diagnostic(severity(warning))]
+    TooShort(CompactStr, usize, #[label] Span),
+    #[error("eslint(id-length): Identifier name '{0}' is too long (> {1}).")]
+    #[diagnostic(severity(warning))]
+    TooLong(CompactStr, usize, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct IdLength(Box<IdLengthConfig>);
+
+#[derive(Debug, Clone)]
+pub struct IdLengthConfig {
+    min: usize,
+    max: usize,
+    /// `properties: "always"`, check property names as well.
+    properties: bool,
+    exceptions: Vec<CompactStr>,
+    exception_patterns: Vec<Regex>,
+}
+
+impl Default for IdLengthConfig {
+    fn default() -> Self {
+        Self {
+            min: 2,
+            max: usize::MAX,
+            properties: true,
+            exceptions: vec![],
+            exception_patterns: vec![],
+        }
+    }
+}
+
+impl std::ops::Deref for IdLength {
+    type Target = IdLengthConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce minimum and maximum identifier lengths
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Very short identifiers like `e`, `x` or `_t` and very long ones like
+    /// `hashGeneratorResultOutputContainerObject` make code harder to read.
+    ///
+    /// ### Options
+    ///
+    /// - `min` (default `2`) and `max` (default `Infinity`)
+    /// - `properties`: `"always"` (default) also checks property names, `"never"` skips them
+    /// - `exceptions`: names that are always allowed
+    /// - `exceptionPatterns`: regular expressions for names that are always allowed
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var x = 5;
+    /// obj.e = document.body;
+    /// function foo(a, b) {}
+    /// ```
+    IdLength,
+    style
+);
+
+impl Rule for IdLength {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut config = IdLengthConfig::default();
+        let Some(obj) = value.get(0) else { return Self(Box::new(config)) };
+
+        if let Some(min) = obj.get("min").and_then(serde_json::Value::as_u64) {
+            config.min = usize::try_from(min).unwrap_or(usize::MAX);
+        }
+        if let Some(max) = obj.get("max").and_then(serde_json::Value::as_u64) {
+            config.max = usize::try_from(max).unwrap_or(usize::MAX);
+        }
+        if let Some(properties) = obj.get("properties").and_then(serde_json::Value::as_str) {
+            config.properties = properties != "never";
+        }
+        if let Some(exceptions) = obj.get("exceptions").and_then(serde_json::Value::as_array) {
+            config.exceptions = exceptions
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(CompactStr::from)
+                .collect();
+        }
+        if let Some(patterns) = obj.get("exceptionPatterns").and_then(serde_json::Value::as_array) {
+            config.exception_patterns = patterns
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .filter_map(|pattern| Regex::new(pattern).ok())
+                .collect();
+        }
+
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BindingIdentifier(ident) => {
+                // Type level names are not identifiers in the sense of this rule
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(
+                        AstKind::TSTypeParameter(_)
+                            | AstKind::TSInterfaceDeclaration(_)
+                            | AstKind::TSTypeAliasDeclaration(_)
+                            | AstKind::TSEnumDeclaration(_)
+                            | AstKind::TSModuleDeclaration(_)
+                            | AstKind::TSImportEqualsDeclaration(_)
+                    )
+                ) {
+                    return;
+                }
+                self.check(&ident.name, ident.span, ctx);
+            }
+            AstKind::PropertyKey(key) if self.properties => {
+                // Keys in object patterns name properties of another object,
+                // and non-identifier keys (`'a'`, `[a]`) are not checked.
+                if !matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(
+                        AstKind::ObjectProperty(_)
+                            | AstKind::PropertyDefinition(_)
+                            | AstKind::MethodDefinition(_)
+                    )
+                ) {
+                    return;
+                }
+                match key {
+                    PropertyKey::Identifier(ident) => self.check(&ident.name, ident.span, ctx),
+                    PropertyKey::PrivateIdentifier(ident) => {
+                        self.check(&ident.name, ident.span, ctx);
+                    }
+                    PropertyKey::Expression(_) => {}
+                }
+            }
+            // `obj.x = 1`
+            AstKind::AssignmentExpression(expr) if self.properties => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &expr.left
+                else {
+                    return;
+                };
+                if let MemberExpression::StaticMemberExpression(expr) = &**member {
+                    self.check(&expr.property.name, expr.property.span, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl IdLength {
+    fn check(&self, name: &str, span: Span, ctx: &LintContext<'_>) {
+        if self.is_exception(name) {
+            return;
+        }
+        let length = name.chars().count();
+        if length < self.min {
+            ctx.diagnostic(IdLengthDiagnostic::TooShort(name.into(), self.min, span));
+        } else if length > self.max {
+            ctx.diagnostic(IdLengthDiagnostic::TooLong(name.into(), self.max, span));
+        }
+    }
+
+    fn is_exception(&self, name: &str) -> bool {
+        self.exceptions.iter().any(|exception| *exception == name)
+            || self.exception_patterns.iter().any(|pattern| pattern.is_match(name))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var xyz;", None),
+        ("var xy = 1;", None),
+        ("function xyz() {};", None),
+        ("function xyz(abc, de) {};", None),
+        ("var obj = { abc: 1, de: 2 };", None),
+        ("var obj = { 'a': 1, bc: 2 };", None),
+        ("var obj = {}; obj['a'] = 2;", None),
+        ("abc = d;", None),
+        ("try { blah(); } catch (err) { /* pass */ }", None),
+        ("var handler = function ($e) {};", None),
+        ("var _a = 2", None),
+        ("var _ad$$ = new $;", None),
+        ("var xyz = new ΣΣ();", None),
+        ("unrelatedExpressionThatNeedsToBeIgnored();", None),
+        ("var obj = { 'a': 1, bc: 2 }; obj.tk = obj.a;", None),
+        ("var query = location.query.q || '';", None),
+        ("var query = location.query.q ? location.query.q : ''", None),
+        ("let {a: foo} = bar;", None),
+        ("let foo = { [a]: 1 };", None),
+        ("let foo = { [a + b]: 1 };", None),
+        ("var x = Foo(42)", Some(serde_json::json!([{ "min": 1 }]))),
+        ("var x = Foo(42)", Some(serde_json::json!([{ "min": 0 }]))),
+        ("foo.$x = Foo(42)", Some(serde_json::json!([{ "min": 1 }]))),
+        ("var lalala = Foo(42)", Some(serde_json::json!([{ "max": 6 }]))),
+        (
+            "for (var q, h=0; h < 10; h++) { console.log(h); q++; }",
+            Some(serde_json::json!([{ "exceptions": ["h", "q"] }])),
+        ),
+        ("for (var i = 0; i < 10; i++) {}", Some(serde_json::json!([{ "exceptions": ["i"] }]))),
+        ("(num) => { num * num };", None),
+        ("function foo(num = 0) { }", None),
+        ("class MyClass { }", None),
+        ("class Foo { method() {} }", None),
+        ("function foo(...args) { }", None),
+        ("var { prop } = {};", None),
+        ("var { [(a)]: prop } = {};", None),
+        ("var { a: foo } = {};", Some(serde_json::json!([{ "min": 3 }]))),
+        ("var { prop: foo } = {};", Some(serde_json::json!([{ "max": 3 }]))),
+        ("var { longName: foo } = {};", Some(serde_json::json!([{ "min": 3, "max": 5 }]))),
+        ("var { foo: a } = {};", Some(serde_json::json!([{ "exceptions": ["a"] }]))),
+        ("var { a: { b: { c: longName } } } = {};", None),
+        ("({ a: obj.x.y.z } = {});", Some(serde_json::json!([{ "properties": "never" }]))),
+        ("import something from 'y';", None),
+        ("export var num = 0;", None),
+        ("({ prop: obj.x.y.something } = {});", None),
+        ("({ prop: obj.longName } = {});", None),
+        ("var obj = { a: 1, bc: 2 };", Some(serde_json::json!([{ "properties": "never" }]))),
+        (
+            "var obj = {}; obj.a = 1; obj.bc = 2;",
+            Some(serde_json::json!([{ "properties": "never" }])),
+        ),
+        ("({ a: obj.x } = {});", Some(serde_json::json!([{ "properties": "never" }]))),
+        ("var x = 1;", Some(serde_json::json!([{ "exceptionPatterns": ["[x-z]"] }]))),
+        ("var e = 1;", Some(serde_json::json!([{ "exceptionPatterns": ["^[a-e]$"] }]))),
+        ("class Foo { #abc = 1 }", None),
+        ("class Foo { #abc() {} }", None),
+        ("class Foo { #a = 1 }", Some(serde_json::json!([{ "properties": "never" }]))),
+        ("function foo<T>(x: T) {}", Some(serde_json::json!([{ "exceptions": ["x"] }]))),
+        ("interface I { }", None),
+        ("type T = string;", None),
+    ];
+
+    let fail = vec![
+        ("var x = 1;", None),
+        ("var x;", None),
+        ("obj.e = document.body;", None),
+        ("function x() {};", None),
+        ("function xyz(a) {};", None),
+        ("var obj = { a: 1, bc: 2 };", None),
+        ("try { blah(); } catch (e) { /* pass */ }", None),
+        ("var handler = function (e) {};", None),
+        ("for (var i=0; i < 10; i++) { console.log(i); }", None),
+        ("var j=0; while (j > -10) { console.log(--j); }", None),
+        ("var _$xt_$ = Foo(42)", Some(serde_json::json!([{ "min": 2, "max": 4 }]))),
+        ("var _$x$_t$ = Foo(42)", Some(serde_json::json!([{ "min": 2, "max": 4 }]))),
+        ("var toString;", Some(serde_json::json!([{ "max": 5 }]))),
+        ("(a) => { a * a };", None),
+        ("function foo(x = 0) { }", None),
+        ("class x { }", None),
+        ("class Foo { x() {} }", None),
+        ("function foo(...x) { }", None),
+        ("var { x} = {};", None),
+        ("var { x: a} = {};", None),
+        ("var { a: [x]} = {};", None),
+        ("import x from 'y';", None),
+        ("export var x = 0;", None),
+        ("var x = 1;", Some(serde_json::json!([{ "properties": "never" }]))),
+        ("var x = 1;", Some(serde_json::json!([{ "exceptions": ["i"] }]))),
+        ("var x = 1;", Some(serde_json::json!([{ "exceptionPatterns": ["^[a-w]$"] }]))),
+        ("class Foo { #x() {} }", None),
+        ("class Foo { #x = 1 }", None),
+        ("var myObj = { a: 1 };", Some(serde_json::json!([{ "min": 1, "max": 4 }]))),
+    ];
+
+    Tester::new(IdLength::NAME, pass, fail).test_and_snapshot();
+}
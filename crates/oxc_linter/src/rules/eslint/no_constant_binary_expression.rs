@@ -420,6 +420,10 @@ fn test() {
         ("foo ?? null ?? bar", None),
         ("a ?? (doSomething(), undefined) ?? b", None),
         ("a ?? (something = null) ?? b", None),
+        // The result depends on the left-hand side, a constant right-hand side is a default value
+        ("someArray ?? []", None),
+        ("a && true", None),
+        ("a || {}", None),
     ];
 
     let fail = vec![
@@ -660,6 +664,8 @@ fn test() {
         ("window.abc && false && anything", None),
         ("window.abc || true || anything", None),
         ("window.abc ?? 'non-nullish' ?? anything", None),
+        ("({}) || x", None),
+        ("[] ?? someArray", None),
     ];
 
     Tester::new(NoConstantBinaryExpression::NAME, pass, fail).test_and_snapshot();
@@ -25,18 +25,18 @@ declare_oxc_lint!(
     /// ### Example
     /// ```javascript
     /// var sum = 0,
-    //     i;
-    //
-    // for(i = 0; i < 10; i++) {
-    //     if(i >= 5) {
-    //         continue;
-    //     }
-    //
-    //     sum += i;
-    // }
+    ///     i;
+    ///
+    /// for(i = 0; i < 10; i++) {
+    ///     if(i >= 5) {
+    ///         continue;
+    ///     }
+    ///
+    ///     sum += i;
+    /// }
     /// ```
     NoContinue,
-    style
+    restriction
 );
 
 impl Rule for NoContinue {
@@ -57,13 +57,20 @@ fn test() {
     let pass = vec![
         "var sum = 0, i; for(i = 0; i < 10; i++){ if(i > 5) { sum += i; } }",
         "var sum = 0, i = 0; while(i < 10) { if(i > 5) { sum += i; } i++; }",
+        "for (const x of xs) { if (x) { break; } }",
+        "outer: for (const x of xs) { for (const y of ys) { if (x === y) { break outer; } } }",
+        "do { i++; } while (i < 10);",
+        "var continueLoop = true;",
     ];
 
     let fail = vec![
         "var sum = 0, i; for(i = 0; i < 10; i++){ if(i <= 5) { continue; } sum += i; }",
-"var sum = 0, i; myLabel: for(i = 0; i < 10; i++){ if(i <= 5) { continue myLabel; } sum += i; }",
-"var sum = 0, i = 0; while(i < 10) { if(i <= 5) { i++; continue; } sum += i; i++; }",
-"var sum = 0, i = 0; myLabel: while(i < 10) { if(i <= 5) { i++; continue myLabel; } sum += i; i++; }"
+        "var sum = 0, i; myLabel: for(i = 0; i < 10; i++){ if(i <= 5) { continue myLabel; } sum += i; }",
+        "var sum = 0, i = 0; while(i < 10) { if(i <= 5) { i++; continue; } sum += i; i++; }",
+        "var sum = 0, i = 0; myLabel: while(i < 10) { if(i <= 5) { i++; continue myLabel; } sum += i; i++; }",
+        "for (const key in obj) { if (!key) continue; }",
+        "do { if (i++ < 5) continue; } while (i < 10);",
+        "outer: for (const x of xs) { for (const y of ys) { if (x === y) continue outer; } }",
     ];
 
     Tester::new(NoContinue::NAME, pass, fail).test_and_snapshot();
@@ -52,7 +52,8 @@ impl Rule for NoDuplicateCase {
             map.reserve(ss.cases.len());
             for case in &ss.cases {
                 if let Some(test) = case.test.as_ref() {
-                    let hash = calculate_hash(test);
+                    // `case (a):` and `case a:` match the same value
+                    let hash = calculate_hash(test.without_parenthesized());
 
                     if let Some(prev_span) = map.insert(hash, test.span()) {
                         ctx.diagnostic(NoDuplicateCaseDiagnostic(prev_span, test.span()));
@@ -166,6 +167,7 @@ fn test() {
             "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(\na + 1 // comment\n).p1: break; case f(a+1)\n.p1: break; default: break;}",
             None,
         ),
+        ("switch (a) { case (1): break; case 1: break; }", None),
     ];
 
     Tester::new(NoDuplicateCase::NAME, pass, fail).test_and_snapshot();
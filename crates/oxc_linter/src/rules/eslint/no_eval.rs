@@ -9,7 +9,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-eval): eval can be harmful.")]
@@ -65,12 +65,27 @@ impl Rule for NoEval {
         let kind = node.kind();
 
         if let AstKind::IdentifierReference(ident) = kind {
-            if ident.name == "eval" {
+            if ident.name != "eval" || !is_global_reference(ident, ctx) {
+                return;
+            }
+            // Only `eval(...)` is a direct call, `(0, eval)(...)`, `eval?.(...)`
+            // and `const e = eval` are all indirect.
+            let is_direct_call = matches!(
+                ctx.nodes().parent_kind(node.id()),
+                Some(AstKind::CallExpression(call)) if !call.optional
+                    && matches!(&call.callee, Expression::Identifier(callee) if callee.span == ident.span)
+            );
+            if is_direct_call || !self.allow_indirect {
                 ctx.diagnostic(NoEvalDiagnostic(ident.span));
             }
             return;
         }
 
+        // Calls through the global object are always indirect
+        if self.allow_indirect {
+            return;
+        }
+
         let AstKind::MemberExpression(data) = kind else {
             return;
         };
@@ -86,7 +101,12 @@ impl Rule for NoEval {
                 Some(Expression::MemberExpression(member)) => {
                     (Some(member.object().get_inner_expression()), member.static_property_name())
                 }
-                Some(Expression::Identifier(ident)) => (None, Some(ident.name.as_str())),
+                Some(Expression::Identifier(ident)) => {
+                    if !is_global_reference(ident, ctx) {
+                        return;
+                    }
+                    (None, Some(ident.name.as_str()))
+                }
                 Some(Expression::ThisExpression(_)) => (None, Some("this")),
                 None => break,
                 _ => return,
@@ -155,65 +175,64 @@ fn test() {
         ("class A { field = this.eval(); }", None),
         ("class A { field = () => this.eval(); }", None),
         ("class A { static { this.eval(); } }", None),
-        // ("(0, eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("(0, window.eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("(0, window['eval'])('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("var EVAL = eval; EVAL('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("var EVAL = this.eval; EVAL('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // (
-        //     "(function(exe){ exe('foo') })(eval);",
-        //     Some(serde_json::json!([{ "allowIndirect": true }])),
-        // ),
-        // ("window.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("window.window.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("window.window['eval']('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("global.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("global.global.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("this.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // (
-        //     "function foo() { this.eval('foo') }",
-        //     Some(serde_json::json!([{ "allowIndirect": true }])),
-        // ),
-        // ("(0, globalThis.eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("(0, globalThis['eval'])('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // (
-        //     "var EVAL = globalThis.eval; EVAL('foo')",
-        //     Some(serde_json::json!([{ "allowIndirect": true }])),
-        // ),
-        // (
-        //     "function foo() { globalThis.eval('foo') }",
-        //     Some(serde_json::json!([{ "allowIndirect": true }])),
-        // ),
-        // (
-        //     "globalThis.globalThis.eval('foo');",
-        //     Some(serde_json::json!([{ "allowIndirect": true }])),
-        // ),
-        // ("eval?.('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("window?.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("(window?.eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        // `eval` is shadowed
+        ("function foo(eval) { eval('foo') }", None),
+        ("function foo(window) { window.eval('foo') }", None),
+        ("(0, eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("(0, window.eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("(0, window['eval'])('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("var EVAL = eval; EVAL('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("var EVAL = this.eval; EVAL('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        (
+            "(function(exe){ exe('foo') })(eval);",
+            Some(serde_json::json!([{ "allowIndirect": true }])),
+        ),
+        ("window.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("window.window.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("window.window['eval']('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("global.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("global.global.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("this.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        (
+            "function foo() { this.eval('foo') }",
+            Some(serde_json::json!([{ "allowIndirect": true }])),
+        ),
+        ("(0, globalThis.eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("(0, globalThis['eval'])('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        (
+            "var EVAL = globalThis.eval; EVAL('foo')",
+            Some(serde_json::json!([{ "allowIndirect": true }])),
+        ),
+        (
+            "function foo() { globalThis.eval('foo') }",
+            Some(serde_json::json!([{ "allowIndirect": true }])),
+        ),
+        (
+            "globalThis.globalThis.eval('foo');",
+            Some(serde_json::json!([{ "allowIndirect": true }])),
+        ),
+        ("eval?.('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("window?.eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("(window?.eval)('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
     ];
 
     let fail = vec![
         ("eval(foo)", None),
         ("eval('foo')", None),
-        ("function foo(eval) { eval('foo') }", None),
-        // ("eval(foo)", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // ("eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
-        // (
-        //     "function foo(eval) { eval('foo') }",
-        //     Some(serde_json::json!([{ "allowIndirect": true }])),
-        // ),
+        ("const e = eval;", None),
+        ("eval(foo)", Some(serde_json::json!([{ "allowIndirect": true }]))),
+        ("eval('foo')", Some(serde_json::json!([{ "allowIndirect": true }]))),
         ("(0, eval)('foo')", None),
         ("(0, window.eval)('foo')", None),
         ("(0, window['eval'])('foo')", None),
-        // ("var EVAL = eval; EVAL('foo')", None),
+        ("var EVAL = eval; EVAL('foo')", None),
         // ("var EVAL = this.eval; EVAL('foo')", None),
         // ("'use strict'; var EVAL = this.eval; EVAL('foo')", None),
         // ("() => { this.eval('foo'); }", None),
         // ("() => { 'use strict'; this.eval('foo'); }", None),
         // ("'use strict'; () => { this.eval('foo'); }", None),
         // ("() => { 'use strict'; () => { this.eval('foo'); } }", None),
-        // ("(function(exe){ exe('foo') })(eval);", None),
+        ("(function(exe){ exe('foo') })(eval);", None),
         ("window.eval('foo')", None),
         ("window.window.eval('foo')", None),
         ("window.window['eval']('foo')", None),
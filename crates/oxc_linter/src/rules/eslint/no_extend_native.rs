@@ -0,0 +1,220 @@
+use oxc_ast::{
+    ast::{Argument, AssignmentTarget, Expression, SimpleAssignmentTarget},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use phf::phf_set;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-extend-native): {0} prototype is read only, properties should not be added.")]
+#[diagnostic(severity(warning))]
+struct NoExtendNativeDiagnostic(CompactStr, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNative(Box<NoExtendNativeConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNativeConfig {
+    exceptions: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoExtendNative {
+    type Target = NoExtendNativeConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow extending native types
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Adding properties to the prototypes of built-in objects changes the behavior of
+    /// every instance, which can break other code and conflict with future additions
+    /// to the language.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// Object.prototype.a = "a";
+    /// Object.defineProperty(Array.prototype, "times", { value: 999 });
+    /// ```
+    NoExtendNative,
+    suspicious
+);
+
+impl Rule for NoExtendNative {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let exceptions = value
+            .get(0)
+            .and_then(|config| config.get("exceptions"))
+            .and_then(serde_json::Value::as_array)
+            .map(|exceptions| {
+                exceptions
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .map(CompactStr::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(NoExtendNativeConfig { exceptions }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `Array.prototype.foo = 1`
+            AstKind::AssignmentExpression(expr) => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &expr.left
+                else {
+                    return;
+                };
+                if let Some(name) = self.get_native_prototype(member.object(), ctx) {
+                    ctx.diagnostic(NoExtendNativeDiagnostic(name.into(), expr.span));
+                }
+            }
+            // `Object.defineProperty(Array.prototype, "foo", { value: 1 })`
+            AstKind::CallExpression(call) => {
+                if !(call.callee.is_specific_member_access("Object", "defineProperty")
+                    || call.callee.is_specific_member_access("Object", "defineProperties"))
+                {
+                    return;
+                }
+                let Some(Argument::Expression(target)) = call.arguments.first() else {
+                    return;
+                };
+                if let Some(name) = self.get_native_prototype(target, ctx) {
+                    ctx.diagnostic(NoExtendNativeDiagnostic(name.into(), call.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoExtendNative {
+    /// Returns the name of the native constructor if `expr` is `<Native>.prototype`.
+    fn get_native_prototype<'a>(
+        &self,
+        expr: &'a Expression<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Option<&'a str> {
+        let member = expr.get_member_expr()?;
+        if member.static_property_name() != Some("prototype") {
+            return None;
+        }
+        let Expression::Identifier(ident) = member.object().get_inner_expression() else {
+            return None;
+        };
+        let name = ident.name.as_str();
+        (NATIVE_CONSTRUCTORS.contains(name)
+            && !self.exceptions.iter().any(|exception| *exception == name)
+            && ctx.semantic().is_reference_to_global_variable(ident))
+        .then_some(name)
+    }
+}
+
+const NATIVE_CONSTRUCTORS: phf::Set<&'static str> = phf_set! {
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Map",
+    "Number",
+    "Object",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+};
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("x.prototype.p = 0", None),
+        ("x.prototype['p'] = 0", None),
+        ("Object.p = 0", None),
+        ("Object.toString.bind = 0", None),
+        ("Object['toString'].bind = 0", None),
+        ("Object.defineProperty(x, 'p', {value: 0})", None),
+        ("Object.defineProperties(x, {p: {value: 0}})", None),
+        ("global.Object.prototype.toString = 0", None),
+        ("this.Object.prototype.toString = 0", None),
+        ("o = Object; o.prototype.toString = 0", None),
+        ("eval('Object.prototype.toString = 0')", None),
+        ("parseFloat.prototype.x = 1", None),
+        ("Array.prototype.p = 0", Some(serde_json::json!([{ "exceptions": ["Array"] }]))),
+        (
+            "Object.defineProperty(Array.prototype, 'p', {value: 0})",
+            Some(serde_json::json!([{ "exceptions": ["Array"] }])),
+        ),
+        ("let Array = function () {}; Array.prototype.p = 0", None),
+        ("function f(Object) { Object.prototype.p = 0 }", None),
+        ("Array.prototype.p", None),
+    ];
+
+    let fail = vec![
+        ("String.prototype.x = 1", None),
+        ("Object.prototype.p = 0", None),
+        ("BigInt.prototype.p = 0", None),
+        ("WeakRef.prototype.p = 0", None),
+        ("FinalizationRegistry.prototype.p = 0", None),
+        ("AggregateError.prototype.p = 0", None),
+        ("Function.prototype['p'] = 0", None),
+        ("String['prototype'].p = 0", None),
+        ("Number['prototype']['p'] = 0", None),
+        ("Object.defineProperty(Array.prototype, 'p', {value: 0})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}, q: {value: 0}})", None),
+        ("Number['prototype']['p'] = 0", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
+        ("Object.prototype.p = 0; Object.prototype.q = 0", None),
+        ("function foo() { Object.prototype.p = 0 }", None),
+        ("(Object?.prototype).p = 0", None),
+        ("Array.prototype.p &&= 0", None),
+    ];
+
+    Tester::new(NoExtendNative::NAME, pass, fail).test_and_snapshot();
+}
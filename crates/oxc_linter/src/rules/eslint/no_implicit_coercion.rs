@@ -0,0 +1,451 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, Expression, MemberExpression, SimpleAssignmentTarget},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-implicit-coercion): Unexpected implicit coercion encountered.")]
+#[diagnostic(severity(warning), help("Use `{1}` instead."))]
+struct NoImplicitCoercionDiagnostic(#[label] pub Span, pub String);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoImplicitCoercion(Box<NoImplicitCoercionConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoImplicitCoercionConfig {
+    /// Check `!!foo` and `~foo.indexOf(bar)`.
+    boolean: bool,
+    /// Check `+foo`, `- -foo`, `foo - 0` and `1 * foo`.
+    number: bool,
+    /// Check `"" + foo` and `foo += ""`.
+    string: bool,
+    /// Operators which are allowed, one of `!!`, `~`, `+`, `- -`, `-` and `*`.
+    allow: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoImplicitCoercion {
+    type Target = NoImplicitCoercionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoImplicitCoercionConfig {
+    fn default() -> Self {
+        Self { boolean: true, number: true, string: true, allow: vec![] }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow shorthand type conversions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shorthands such as `!!foo` or `+foo` rely on the coercion rules of the operators and
+    /// are harder to read than calling `Boolean`, `Number` or `String` explicitly.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var b = !!foo;
+    /// var b = ~foo.indexOf(".");
+    /// var n = +foo;
+    /// var n = 1 * foo;
+    /// var s = "" + foo;
+    /// foo += "";
+    ///
+    /// // Good
+    /// var b = Boolean(foo);
+    /// var b = foo.indexOf(".") !== -1;
+    /// var n = Number(foo);
+    /// var s = String(foo);
+    /// foo = String(foo);
+    /// ```
+    NoImplicitCoercion,
+    style
+);
+
+/// `1`, `Number(foo)`, `parseInt(foo)` or `parseFloat(foo)`
+fn is_numeric(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumericLiteral(_) => true,
+        Expression::CallExpression(call_expr) => {
+            call_expr.callee.is_specific_id("Number")
+                || call_expr.callee.is_specific_id("parseInt")
+                || call_expr.callee.is_specific_id("parseFloat")
+        }
+        _ => false,
+    }
+}
+
+/// A string literal, template literal or `String(foo)`
+fn is_string_type(expr: &Expression) -> bool {
+    match expr {
+        Expression::CallExpression(call_expr) => call_expr.callee.is_specific_id("String"),
+        _ => expr.is_string_literal(),
+    }
+}
+
+fn is_empty_string(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(lit) => lit.value.is_empty(),
+        Expression::TemplateLiteral(lit) => {
+            lit.expressions.is_empty() && lit.quasis.iter().all(|quasi| quasi.value.raw.is_empty())
+        }
+        _ => false,
+    }
+}
+
+/// `foo.indexOf(bar)` or `foo.lastIndexOf(bar)`
+fn is_index_of_call(expr: &Expression) -> bool {
+    let Expression::CallExpression(call_expr) = expr else {
+        return false;
+    };
+    let Expression::MemberExpression(member_expr) = &call_expr.callee else {
+        return false;
+    };
+    !member_expr.optional()
+        && matches!(member_expr.static_property_name(), Some("indexOf" | "lastIndexOf"))
+}
+
+/// The non-numeric operand of `1 * foo` or `foo * 1`
+fn get_non_numeric_operand<'a, 'b>(
+    left: &'b Expression<'a>,
+    right: &'b Expression<'a>,
+) -> Option<&'b Expression<'a>> {
+    if !left.is_number(1.0) && !right.is_number(1.0) {
+        return None;
+    }
+    if !matches!(right, Expression::BinaryExpression(_)) && !is_numeric(right) {
+        return Some(right);
+    }
+    if !matches!(left, Expression::BinaryExpression(_)) && !is_numeric(left) {
+        return Some(left);
+    }
+    None
+}
+
+/// Whether the target of `foo += ""` can be repeated in `foo = String(foo)` without evaluating
+/// anything twice: an identifier or a member chain such as `this.foo.bar` or `foo[0]`.
+fn is_repeatable_target(target: &AssignmentTarget) -> bool {
+    match target {
+        AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(_),
+        ) => true,
+        AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::MemberAssignmentTarget(member_expr),
+        ) => is_repeatable_member(member_expr),
+        _ => false,
+    }
+}
+
+fn is_repeatable_member(member_expr: &MemberExpression) -> bool {
+    let has_static_property = match member_expr {
+        MemberExpression::ComputedMemberExpression(computed) => {
+            matches!(computed.expression, Expression::NumericLiteral(_))
+                || member_expr.static_property_name().is_some()
+        }
+        MemberExpression::StaticMemberExpression(_)
+        | MemberExpression::PrivateFieldExpression(_) => true,
+    };
+    has_static_property
+        && !member_expr.optional()
+        && match member_expr.object() {
+            Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+            Expression::MemberExpression(object) => is_repeatable_member(object),
+            _ => false,
+        }
+}
+
+/// Whether `1 * foo` is the left operand of a longer product or division, as in `1 * foo * bar`
+/// or `foo * 1 / bar`, where it is arithmetic rather than a coercion.
+fn is_product_operand(span: Span, node: &AstNode, ctx: &LintContext) -> bool {
+    matches!(
+        ctx.nodes().parent_kind(node.id()),
+        Some(AstKind::BinaryExpression(parent))
+            if matches!(parent.operator, BinaryOperator::Multiplication | BinaryOperator::Division)
+                && parent.left.span() == span
+    )
+}
+
+impl NoImplicitCoercion {
+    fn is_allowed(&self, operator: &str) -> bool {
+        self.allow.iter().any(|allowed| allowed.as_str() == operator)
+    }
+
+    /// Report `span`, fixing it to `recommendation` unless `function` is shadowed.
+    fn report<'a>(
+        span: Span,
+        recommendation: String,
+        function: &str,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let scopes = ctx.scopes();
+        if scopes.ancestors(node.scope_id()).any(|scope_id| scopes.has_binding(scope_id, function))
+        {
+            ctx.diagnostic(NoImplicitCoercionDiagnostic(span, recommendation));
+            return;
+        }
+        let fix = Fix::new(recommendation.clone(), span);
+        ctx.diagnostic_with_fix(NoImplicitCoercionDiagnostic(span, recommendation), || fix);
+    }
+
+    fn report_call<'a>(
+        span: Span,
+        function: &str,
+        operand: &Expression<'a>,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let recommendation =
+            format!("{function}({})", operand.span().source_text(ctx.source_text()));
+        Self::report(span, recommendation, function, node, ctx);
+    }
+}
+
+impl Rule for NoImplicitCoercion {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true)
+        };
+        let allow = config
+            .and_then(|config| config.get("allow"))
+            .and_then(serde_json::Value::as_array)
+            .map(|allow| {
+                allow.iter().filter_map(serde_json::Value::as_str).map(CompactStr::from).collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(NoImplicitCoercionConfig {
+            boolean: get_bool("boolean"),
+            number: get_bool("number"),
+            string: get_bool("string"),
+            allow,
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::UnaryExpression(unary_expr) => {
+                let argument = &unary_expr.argument;
+                match unary_expr.operator {
+                    // !!foo
+                    UnaryOperator::LogicalNot if self.boolean && !self.is_allowed("!!") => {
+                        if let Expression::UnaryExpression(inner) = argument {
+                            if inner.operator == UnaryOperator::LogicalNot {
+                                Self::report_call(
+                                    unary_expr.span,
+                                    "Boolean",
+                                    &inner.argument,
+                                    node,
+                                    ctx,
+                                );
+                            }
+                        }
+                    }
+                    // ~foo.indexOf(bar)
+                    UnaryOperator::BitwiseNot if self.boolean && !self.is_allowed("~") => {
+                        if is_index_of_call(argument) {
+                            let recommendation = format!(
+                                "{} !== -1",
+                                argument.span().source_text(ctx.source_text())
+                            );
+                            ctx.diagnostic(NoImplicitCoercionDiagnostic(
+                                unary_expr.span,
+                                recommendation,
+                            ));
+                        }
+                    }
+                    // +foo
+                    UnaryOperator::UnaryPlus if self.number && !self.is_allowed("+") => {
+                        if !is_numeric(argument) {
+                            Self::report_call(unary_expr.span, "Number", argument, node, ctx);
+                        }
+                    }
+                    // - -foo
+                    UnaryOperator::UnaryNegation if self.number && !self.is_allowed("- -") => {
+                        if let Expression::UnaryExpression(inner) = argument {
+                            if inner.operator == UnaryOperator::UnaryNegation
+                                && !is_numeric(&inner.argument)
+                            {
+                                Self::report_call(
+                                    unary_expr.span,
+                                    "Number",
+                                    &inner.argument,
+                                    node,
+                                    ctx,
+                                );
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            AstKind::BinaryExpression(binary_expr) => {
+                let (left, right) = (&binary_expr.left, &binary_expr.right);
+                match binary_expr.operator {
+                    // 1 * foo, foo * 1
+                    BinaryOperator::Multiplication if self.number && !self.is_allowed("*") => {
+                        if is_product_operand(binary_expr.span, node, ctx) {
+                            return;
+                        }
+                        if let Some(operand) = get_non_numeric_operand(left, right) {
+                            Self::report_call(binary_expr.span, "Number", operand, node, ctx);
+                        }
+                    }
+                    // foo - 0
+                    BinaryOperator::Subtraction if self.number && !self.is_allowed("-") => {
+                        if right.is_number_0() && !is_numeric(left) {
+                            Self::report_call(binary_expr.span, "Number", left, node, ctx);
+                        }
+                    }
+                    // "" + foo, foo + ""
+                    BinaryOperator::Addition if self.string && !self.is_allowed("+") => {
+                        if is_empty_string(left) && !is_string_type(right) {
+                            Self::report_call(binary_expr.span, "String", right, node, ctx);
+                        } else if is_empty_string(right) && !is_string_type(left) {
+                            Self::report_call(binary_expr.span, "String", left, node, ctx);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // foo += ""
+            AstKind::AssignmentExpression(assign_expr)
+                if assign_expr.operator == AssignmentOperator::Addition
+                    && self.string
+                    && !self.is_allowed("+")
+                    && is_empty_string(&assign_expr.right) =>
+            {
+                let target = assign_expr.left.span().source_text(ctx.source_text());
+                let recommendation = format!("{target} = String({target})");
+                if is_repeatable_target(&assign_expr.left) {
+                    Self::report(assign_expr.span, recommendation, "String", node, ctx);
+                } else {
+                    ctx.diagnostic(NoImplicitCoercionDiagnostic(assign_expr.span, recommendation));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("Boolean(foo)", None),
+        ("foo.indexOf(1) !== -1", None),
+        ("Number(foo)", None),
+        ("parseInt(foo)", None),
+        ("parseFloat(foo)", None),
+        ("+Number(foo)", None),
+        ("+1", None),
+        ("1 * 1", None),
+        ("1 * Number(foo)", None),
+        ("foo * 2", None),
+        ("2 * foo", None),
+        ("1 * a * b", None),
+        ("a * 1 / b", None),
+        ("- -Number(foo)", None),
+        ("-foo", None),
+        ("foo - 1", None),
+        ("foo - Number(0)", None),
+        ("String(foo)", None),
+        ("'' + 'foo'", None),
+        ("'foo' + ''", None),
+        ("`` + 'foo'", None),
+        ("'' + `${foo}`", None),
+        ("'' + String(foo)", None),
+        ("foo + 'bar'", None),
+        ("'' + foo + ''", Some(serde_json::json!([{ "string": false }]))),
+        ("foo += 'bar'", None),
+        ("!foo", None),
+        ("~foo", None),
+        ("~foo.bar()", None),
+        ("!!foo", Some(serde_json::json!([{ "boolean": false }]))),
+        ("~foo.indexOf(1)", Some(serde_json::json!([{ "boolean": false }]))),
+        ("+foo", Some(serde_json::json!([{ "number": false }]))),
+        ("1 * foo", Some(serde_json::json!([{ "number": false }]))),
+        ("'' + foo", Some(serde_json::json!([{ "string": false }]))),
+        ("foo += ''", Some(serde_json::json!([{ "string": false }]))),
+        ("!!foo", Some(serde_json::json!([{ "allow": ["!!"] }]))),
+        ("~foo.indexOf(1)", Some(serde_json::json!([{ "allow": ["~"] }]))),
+        ("+foo", Some(serde_json::json!([{ "allow": ["+"] }]))),
+        ("'' + foo", Some(serde_json::json!([{ "allow": ["+"] }]))),
+        ("foo * 1", Some(serde_json::json!([{ "allow": ["*"] }]))),
+        ("- -foo", Some(serde_json::json!([{ "allow": ["- -"] }]))),
+        ("foo - 0", Some(serde_json::json!([{ "allow": ["-"] }]))),
+    ];
+
+    let fail = vec![
+        ("!!foo", None),
+        ("!!(foo + bar)", None),
+        ("~foo.indexOf(1)", None),
+        ("~foo.bar.lastIndexOf(1)", None),
+        ("+foo", None),
+        ("+foo.bar", None),
+        ("1 * foo", None),
+        ("foo * 1", None),
+        ("1 * foo.bar", None),
+        ("- -foo", None),
+        ("foo - 0", None),
+        ("'' + foo", None),
+        ("foo + ''", None),
+        ("`` + foo", None),
+        ("foo + ``", None),
+        ("foo.bar + ''", None),
+        ("foo += ''", None),
+        ("foo.bar += ``", None),
+        ("a[i++] += ''", None),
+        ("foo().bar += ''", None),
+        ("+foo", Some(serde_json::json!([{ "allow": ["!!", "*"] }]))),
+        ("function f(Boolean) { return !!Boolean }", None),
+    ];
+
+    let fix = vec![
+        ("!!foo", "Boolean(foo)", None),
+        ("!!(foo + bar)", "Boolean((foo + bar))", None),
+        ("+foo", "Number(foo)", None),
+        ("1 * foo", "Number(foo)", None),
+        ("foo * 1", "Number(foo)", None),
+        ("- -foo", "Number(foo)", None),
+        ("foo - 0", "Number(foo)", None),
+        ("'' + foo", "String(foo)", None),
+        ("foo + ''", "String(foo)", None),
+        ("foo += ''", "foo = String(foo)", None),
+        ("foo.bar += ``", "foo.bar = String(foo.bar)", None),
+        ("var a = 1 + +foo", "var a = 1 + Number(foo)", None),
+        ("this.foo[0].#bar += ''", "this.foo[0].#bar = String(this.foo[0].#bar)", None),
+        ("foo['bar'] += ''", "foo['bar'] = String(foo['bar'])", None),
+        // not fixed
+        ("~foo.indexOf(1)", "~foo.indexOf(1)", None),
+        ("a[i++] += ''", "a[i++] += ''", None),
+        ("foo().bar += ''", "foo().bar += ''", None),
+        (
+            "function f(Boolean) { return !!Boolean }",
+            "function f(Boolean) { return !!Boolean }",
+            None,
+        ),
+    ];
+
+    Tester::new(NoImplicitCoercion::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
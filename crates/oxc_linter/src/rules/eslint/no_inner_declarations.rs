@@ -18,6 +18,7 @@ struct NoInnerDeclarationsDiagnostic(&'static str, &'static str, #[label] pub Sp
 #[derive(Debug, Default, Clone)]
 pub struct NoInnerDeclarations {
     config: NoInnerDeclarationsConfig,
+    block_scoped_functions: BlockScopedFunctions,
 }
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
@@ -29,6 +30,15 @@ enum NoInnerDeclarationsConfig {
     Both,
 }
 
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum BlockScopedFunctions {
+    /// Allows function declarations in nested blocks of strict mode code,
+    /// where they are block scoped
+    Allow,
+    #[default]
+    Disallow,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -40,6 +50,13 @@ declare_oxc_lint!(
     /// This is often undesirable due to variable hoisting, and moving declarations to the root of the program or function body can increase clarity.
     /// Note that block bindings (let, const) are not hoisted and therefore they are not affected by this rule.
     ///
+    /// ### Options
+    ///
+    /// The first option is `"functions"` (default) or `"both"`, which also disallows `var` declarations.
+    ///
+    /// The second option `{ "blockScopedFunctions": "allow" }` allows function declarations in
+    /// nested blocks of strict mode code (modules, classes and `"use strict"` code), where they are
+    /// scoped to the block like `let`.
     ///
     /// ### Example
     /// ```javascript
@@ -60,7 +77,15 @@ impl Rule for NoInnerDeclarations {
                 _ => NoInnerDeclarationsConfig::Both,
             },
         );
-        Self { config }
+        let block_scoped_functions = value
+            .get(1)
+            .and_then(|options| options.get("blockScopedFunctions"))
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(BlockScopedFunctions::default, |value| match value {
+                "allow" => BlockScopedFunctions::Allow,
+                _ => BlockScopedFunctions::Disallow,
+            });
+        Self { config, block_scoped_functions }
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -78,6 +103,13 @@ impl Rule for NoInnerDeclarations {
 
         let mut parent = ctx.nodes().parent_node(node.id());
         if let Some(parent_node) = parent {
+            if matches!(node.kind(), AstKind::Function(_))
+                && self.block_scoped_functions == BlockScopedFunctions::Allow
+                && ctx.scopes().get_flags(parent_node.scope_id()).is_strict_mode()
+            {
+                return;
+            }
+
             let parent_kind = parent_node.kind();
             if let AstKind::FunctionBody(_) = parent_kind {
                 if let Some(grandparent) = ctx.nodes().parent_node(parent_node.id()) {
@@ -163,6 +195,18 @@ fn test() {
         ("class C { method() { var x; } }", Some(serde_json::json!(["both"]))),
         ("class C { static { function foo() {} } }", Some(serde_json::json!(["both"]))),
         ("class C { static { var x; } }", Some(serde_json::json!(["both"]))),
+        (
+            "if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+        ),
+        (
+            "function foo() { if (test) { function bar() { } } }",
+            Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
+        ),
+        (
+            "class C { method() { if (test) { function foo() {} } } }",
+            Some(serde_json::json!(["functions", { "blockScopedFunctions": "allow" }])),
+        ),
     ];
 
     let fail = vec![
@@ -198,6 +242,14 @@ fn test() {
             "class C { static { if (test) { if (anotherTest) { var foo; } } } }",
             Some(serde_json::json!(["both"])),
         ),
+        (
+            "if (test) { function doSomething() { } }",
+            Some(serde_json::json!(["functions", { "blockScopedFunctions": "disallow" }])),
+        ),
+        (
+            "if (test) { var foo; }",
+            Some(serde_json::json!(["both", { "blockScopedFunctions": "allow" }])),
+        ),
     ];
 
     Tester::new(NoInnerDeclarations::NAME, pass, fail).test_and_snapshot();
@@ -38,10 +38,7 @@ impl Rule for NoIterator {
         let AstKind::MemberExpression(member_expression) = node.kind() else { return };
         if let Some(static_property_name) = member_expression.static_property_name() {
             if static_property_name == "__iterator__" {
-                ctx.diagnostic(NoIteratorDiagnostic(Span::new(
-                    member_expression.span().start,
-                    member_expression.span().end,
-                )));
+                ctx.diagnostic(NoIteratorDiagnostic(member_expression.span()));
             }
         }
     }
@@ -54,6 +51,8 @@ fn test() {
     let pass = vec![
         "var a = test[__iterator__];",
         "var __iterator__ = null;",
+        "var a = test.iterator;",
+        "var a = test['__iterator'];",
         "foo[`__iterator`] = null;",
         "foo[`__iterator__
 			`] = null;",
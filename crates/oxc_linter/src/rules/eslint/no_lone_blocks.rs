@@ -0,0 +1,143 @@
+use oxc_ast::{
+    ast::{BlockStatement, Declaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoLoneBlocksDiagnostic {
+    #[error("eslint(no-lone-blocks): Block is redundant.")]
+    #[diagnostic(severity(warning), help("Remove the braces around the statements"))]
+    Block(#[label] Span),
+    #[error("eslint(no-lone-blocks): Nested block is redundant.")]
+    #[diagnostic(severity(warning), help("Remove the braces around the statements"))]
+    Nested(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoneBlocks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary nested blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Blocks which are not the body of a function, a control flow statement or a label, and
+    /// which don't scope `let`, `const`, `class` or `function` declarations, have no effect
+    /// and only add nesting.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// {
+    ///     var foo = bar();
+    /// }
+    ///
+    /// // Good
+    /// {
+    ///     let foo = bar();
+    /// }
+    /// ```
+    NoLoneBlocks,
+    style
+);
+
+impl Rule for NoLoneBlocks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BlockStatement(block) = node.kind() else { return };
+        let Some(parent) = ctx.nodes().parent_kind(node.id()) else { return };
+
+        let is_nested = match parent {
+            AstKind::Program(_) => false,
+            AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::CatchClause(_)
+            | AstKind::FinallyClause(_) => true,
+            // A block as the only statement of a case is a common style
+            AstKind::SwitchCase(case) if case.consequent.len() > 1 => false,
+            _ => return,
+        };
+
+        if has_block_scoped_declaration(block, node.is_in_strict_mode(ctx.scopes())) {
+            return;
+        }
+
+        ctx.diagnostic(if is_nested {
+            NoLoneBlocksDiagnostic::Nested(block.span)
+        } else {
+            NoLoneBlocksDiagnostic::Block(block.span)
+        });
+    }
+}
+
+/// Function declarations are only scoped to the block in strict mode code.
+fn has_block_scoped_declaration(block: &BlockStatement, is_strict: bool) -> bool {
+    block.body.iter().any(|stmt| match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => decl.kind.is_lexical(),
+        Statement::Declaration(
+            Declaration::ClassDeclaration(_) | Declaration::UsingDeclaration(_),
+        ) => true,
+        Statement::Declaration(Declaration::FunctionDeclaration(_)) => is_strict,
+        _ => false,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (foo) { if (bar) { baz(); } }",
+        "do { bar(); } while (foo)",
+        "function foo() { while (bar) { baz() } }",
+        "{ let x = 1; }",
+        "{ const y = 1; }",
+        "{ class Bar {} }",
+        "{ function bar() {} }",
+        "{ {let y = 1;} let x = 1; }",
+        "switch (foo) { case bar: { baz; } }",
+        "switch (foo) { case bar: { baz; } case qux: { boop; } }",
+        "switch (foo) { default: { let x; } }",
+        "label: { foo(); }",
+        "try { foo(); } catch (e) { bar(); } finally { baz(); }",
+        "class C { static { foo(); } }",
+        "class C { static { { let block; } something; } }",
+        "function foo() { { const x = 4 } const x = 3 }",
+        "function foo() { { const x = 1; } }",
+        "class C { static { { let block; } } }",
+        "try {} catch (e) { { let x = e; } }",
+    ];
+
+    let fail = vec![
+        "{}",
+        "{var x = 1;}",
+        "foo(); {} bar();",
+        "if (foo) { bar(); {} baz(); }",
+        "{ { foo(); } }",
+        "function foo() { bar(); {} baz(); }",
+        "while (foo) { {} }",
+        "{ var x = 1; }",
+        "try { { foo(); } } catch (e) { { bar(); } }",
+        "try { foo(); } finally { { bar(); } }",
+        "switch (foo) { case 1: foo(); { bar; } }",
+        "switch (foo) { case 1: { bar; } foo(); }",
+        "class C { static { {} } }",
+        "class C { static { { foo; } } }",
+        "class C { static { { foo; } bar; } }",
+        "function foo() { { var x = 1; } }",
+        // Only the outer block is redundant
+        "{ { let x = 1; } }",
+    ];
+
+    Tester::new(NoLoneBlocks::NAME, pass, fail).test_and_snapshot();
+}
@@ -351,6 +351,7 @@ fn test() {
         ("var x = 0x2_0000000000001", None),
         ("var x = 0X200000_0000000_1", None),
         ("var x = 1e18_446_744_073_709_551_615", None),
+        ("var x = 0.12345678901234567890", None),
     ];
 
     Tester::new(NoLossOfPrecision::NAME, pass, fail).test_and_snapshot();
@@ -278,6 +278,7 @@ fn test() {
         ("a && b && c && d", None),
         ("a || b || c || d", None),
         ("(a || b) && c && d", None),
+        ("(a && b) || c", None),
         ("a || (b && c && d)", None),
         ("(a || b || c) && d", None),
         ("a || b || (c && d)", None),
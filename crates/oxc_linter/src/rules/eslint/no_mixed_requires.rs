@@ -0,0 +1,227 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule, utils::NODE_BUILTINS_MODULE, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoMixedRequiresDiagnostic {
+    #[error("eslint(no-mixed-requires): Do not mix 'require' and other declarations.")]
+    #[diagnostic(severity(warning), help("Move the `require` calls into their own declaration"))]
+    Mixed(#[label] Span),
+    #[error("eslint(no-mixed-requires): Do not mix core, module, file and computed requires.")]
+    #[diagnostic(severity(warning), help("Group the `require` calls by the kind of module"))]
+    Grouping(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMixedRequires {
+    /// Also report declarations requiring different kinds of modules.
+    grouping: bool,
+    /// Treat calls of a `require` call, such as `require('debug')('app')`, as requires.
+    allow_call: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `require` calls to be mixed with regular variable declarations.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Keeping module imports in their own declarations makes the dependencies of a
+    /// module easier to spot.
+    ///
+    /// With `grouping` enabled, a declaration must also not mix core, module, file and
+    /// computed requires. With `allowCall` enabled, calling the result of a `require`
+    /// call still counts as a require.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var fs = require('fs'), i = 0;
+    ///
+    /// // Good
+    /// var fs = require('fs'), path = require('path');
+    /// var i = 0;
+    /// ```
+    NoMixedRequires,
+    style
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclarationType {
+    Require,
+    Uninitialized,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ModuleType {
+    Core,
+    File,
+    Module,
+    Computed,
+}
+
+impl Rule for NoMixedRequires {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        if let Some(grouping) = config.as_bool() {
+            return Self { grouping, allow_call: false };
+        }
+        let get_bool =
+            |name: &str| config.get(name).and_then(serde_json::Value::as_bool).unwrap_or_default();
+        Self { grouping: get_bool("grouping"), allow_call: get_bool("allowCall") }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(decl) = node.kind() else {
+            return;
+        };
+
+        let mut has_require = false;
+        let mut has_other = false;
+        let mut module_types = FxHashSet::default();
+        for declarator in &decl.declarations {
+            match self.declaration_type(declarator.init.as_ref(), ctx) {
+                DeclarationType::Require => {
+                    has_require = true;
+                    if let Some(init) = &declarator.init {
+                        module_types.insert(module_type(init));
+                    }
+                }
+                DeclarationType::Uninitialized | DeclarationType::Other => has_other = true,
+            }
+        }
+
+        if has_require && has_other {
+            ctx.diagnostic(NoMixedRequiresDiagnostic::Mixed(decl.span));
+        } else if self.grouping && module_types.len() > 1 {
+            ctx.diagnostic(NoMixedRequiresDiagnostic::Grouping(decl.span));
+        }
+    }
+}
+
+impl NoMixedRequires {
+    fn declaration_type(&self, init: Option<&Expression>, ctx: &LintContext) -> DeclarationType {
+        match init {
+            None => DeclarationType::Uninitialized,
+            Some(Expression::CallExpression(call)) => match &call.callee {
+                // The global `require`, not a local binding of the same name
+                Expression::Identifier(ident)
+                    if ident.name == "require"
+                        && ident
+                            .reference_id
+                            .get()
+                            .is_some_and(|id| ctx.symbols().is_global_reference(id)) =>
+                {
+                    DeclarationType::Require
+                }
+                // `require('debug')('app')`
+                callee @ Expression::CallExpression(_) if self.allow_call => {
+                    self.declaration_type(Some(callee), ctx)
+                }
+                _ => DeclarationType::Other,
+            },
+            // `require('child_process').exec`
+            Some(Expression::MemberExpression(member)) => {
+                self.declaration_type(Some(member.object()), ctx)
+            }
+            Some(_) => DeclarationType::Other,
+        }
+    }
+}
+
+fn module_type(init: &Expression) -> ModuleType {
+    let call = match init {
+        Expression::MemberExpression(member) => return module_type(member.object()),
+        Expression::CallExpression(call) => call,
+        _ => return ModuleType::Computed,
+    };
+    let Some(Argument::Expression(Expression::StringLiteral(lit))) = call.arguments.first() else {
+        return ModuleType::Computed;
+    };
+    let name = lit.value.as_str();
+    if NODE_BUILTINS_MODULE.contains(name) {
+        ModuleType::Core
+    } else if name.starts_with('/') || name.starts_with("./") || name.starts_with("../") {
+        ModuleType::File
+    } else {
+        ModuleType::Module
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a, b = 42, c = doStuff()", Some(serde_json::json!([false]))),
+        ("function load(require) { var fs = require('fs'), i = 0; }", None),
+        (
+            "var a = require(42), b = require(), c = require('y'), d = require(doStuff())",
+            Some(serde_json::json!([false])),
+        ),
+        ("var fs = require('fs'), foo = require('foo')", Some(serde_json::json!([false]))),
+        (
+            "var exec = require('child_process').exec, foo = require('foo')",
+            Some(serde_json::json!([false])),
+        ),
+        ("var fs = require('fs'), foo = require('./foo')", Some(serde_json::json!([false]))),
+        ("var foo = require('foo'), foo2 = require('./foo')", Some(serde_json::json!([false]))),
+        (
+            "var emitter = require('events').EventEmitter, fs = require('fs')",
+            Some(serde_json::json!([false])),
+        ),
+        ("var foo = require(42), bar = require(getName())", Some(serde_json::json!([false]))),
+        ("var foo = require(42), bar = require(getName())", Some(serde_json::json!([true]))),
+        (
+            "var fs = require('fs'), foo = require('./foo')",
+            Some(serde_json::json!([{ "grouping": false }])),
+        ),
+        ("var foo = require('foo'), bar = require(getName())", Some(serde_json::json!([false]))),
+        ("var a;", Some(serde_json::json!([true]))),
+        ("var fs = require('fs'), path = require('path')", None),
+        (
+            "var async = require('async'), debug = require('diagnostics')('my-module')",
+            Some(serde_json::json!([{ "allowCall": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var fs = require('fs'), foo = 42", Some(serde_json::json!([false]))),
+        ("var fs = require('fs'), foo", Some(serde_json::json!([false]))),
+        ("const fs = require('fs'), i = 0", None),
+        (
+            "var a = require(42), b = require(), c = require('y'), d = require(doStuff())",
+            Some(serde_json::json!([true])),
+        ),
+        (
+            "var fs = require('fs'), foo = require('foo')",
+            Some(serde_json::json!([{ "grouping": true }])),
+        ),
+        (
+            "var exec = require('child_process').exec, foo = require('foo')",
+            Some(serde_json::json!([true])),
+        ),
+        ("var fs = require('fs'), foo = require('./foo')", Some(serde_json::json!([true]))),
+        ("var foo = require('foo'), foo2 = require('./foo')", Some(serde_json::json!([true]))),
+        ("var foo = require('foo'), bar = require(getName())", Some(serde_json::json!([true]))),
+        ("var async = require('async'), debug = require('diagnostics')('my-module')", None),
+        (
+            "var async = require('async'), debug = require('diagnostics').someFun('my-module')",
+            Some(serde_json::json!([{ "allowCall": true }])),
+        ),
+    ];
+
+    Tester::new(NoMixedRequires::NAME, pass, fail).test_and_snapshot();
+}
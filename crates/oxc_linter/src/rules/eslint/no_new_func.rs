@@ -0,0 +1,108 @@
+use oxc_ast::{
+    ast::{Expression, IdentifierReference},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-new-func): The Function constructor is eval.")]
+#[diagnostic(severity(warning), help("Define the function statically instead."))]
+struct NoNewFuncDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNewFunc;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `new` operators with the `Function` object
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Creating functions from strings with the `Function` constructor has the same
+    /// problems as `eval`: the code is hard to read, can't be optimized, and may
+    /// execute untrusted input.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var x = new Function("a", "b", "return a + b");
+    /// var y = Function("a", "b", "return a + b");
+    /// ```
+    NoNewFunc,
+    restriction
+);
+
+impl Rule for NoNewFunc {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, span) = match node.kind() {
+            AstKind::NewExpression(expr) => (&expr.callee, expr.span),
+            AstKind::CallExpression(expr) => (&expr.callee, expr.span),
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.without_parenthesized() else { return };
+        if is_function_constructor(ident, ctx) || is_function_constructor_alias(ident, ctx) {
+            ctx.diagnostic(NoNewFuncDiagnostic(span));
+        }
+    }
+}
+
+fn is_function_constructor(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    ident.name == "Function" && ctx.semantic().is_reference_to_global_variable(ident)
+}
+
+/// `const F = Function; new F()`
+fn is_function_constructor_alias(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(reference_id) = ident.reference_id.get() else { return false };
+    let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+        return false;
+    };
+    if !ctx.symbols().get_flag(symbol_id).is_const_variable() {
+        return false;
+    }
+    let AstKind::VariableDeclarator(decl) = ctx.semantic().symbol_declaration(symbol_id).kind()
+    else {
+        return false;
+    };
+    matches!(
+        decl.init.as_ref().map(Expression::without_parenthesized),
+        Some(Expression::Identifier(init)) if is_function_constructor(init, ctx)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = new _function(\"b\", \"c\", \"return b+c\");", None),
+        ("var a = _function(\"b\", \"c\", \"return b+c\");", None),
+        ("class Function {}; new Function()", None),
+        ("const fn = () => { class Function {}; new Function() }", None),
+        ("function Function() {}; Function()", None),
+        ("var fn = function () { function Function() {}; Function() }", None),
+        ("call(Function)", None),
+        ("new Class(Function)", None),
+        ("let F = Function; F = foo; new F()", None),
+        ("const F = function () {}; new F()", None),
+    ];
+
+    let fail = vec![
+        ("var a = new Function(\"b\", \"c\", \"return b+c\");", None),
+        ("var a = Function(\"b\", \"c\", \"return b+c\");", None),
+        ("new Function(\"a\", \"return a\")", None),
+        ("Function(\"x\")", None),
+        ("var a = (Function)(\"x\");", None),
+        ("const fn = () => { class Function {} }; new Function('', '')", None),
+        ("var fn = function () { function Function() {} }; Function('', '')", None),
+        ("const F = Function; new F(\"return 1\")", None),
+    ];
+
+    Tester::new(NoNewFunc::NAME, pass, fail).test_and_snapshot();
+}
@@ -10,7 +10,10 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-proto): The '__proto__' property is deprecated")]
-#[diagnostic(severity(warning), help("Disallow the use of the `__proto__` property."))]
+#[diagnostic(
+    severity(warning),
+    help("Use `Object.getPrototypeOf` or `Object.setPrototypeOf` instead.")
+)]
 struct NoProtoDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -44,10 +47,7 @@ impl Rule for NoProto {
         let AstKind::MemberExpression(member_expression) = node.kind() else { return };
         if let Some(static_property_name) = member_expression.static_property_name() {
             if static_property_name == "__proto__" {
-                ctx.diagnostic(NoProtoDiagnostic(Span::new(
-                    member_expression.span().start,
-                    member_expression.span().end,
-                )));
+                ctx.diagnostic(NoProtoDiagnostic(member_expression.span()));
             }
         }
     }
@@ -64,6 +64,9 @@ fn test() {
         "foo[`__proto__
 			`] = null;",
         "class C { #__proto__; foo() { this.#__proto__; } }",
+        "var a = { __proto__: b };",
+        "var a = { '__proto__': b };",
+        "var a = test.proto;",
     ];
 
     let fail = vec![
@@ -71,6 +74,8 @@ fn test() {
         "var a = test['__proto__'];",
         "var a = test[`__proto__`];",
         "test[`__proto__`] = function () {};",
+        "obj.__proto__ = b;",
+        "obj['__proto__'] = b;",
     ];
 
     Tester::new(NoProto::NAME, pass, fail).test_and_snapshot();
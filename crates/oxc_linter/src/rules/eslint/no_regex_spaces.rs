@@ -10,7 +10,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-regex-spaces): Spaces are hard to count.")]
@@ -47,19 +47,19 @@ impl Rule for NoRegexSpaces {
         match node.kind() {
             AstKind::RegExpLiteral(lit) => {
                 if let Some(span) = Self::find_literal_to_report(lit) {
-                    ctx.diagnostic(NoRegexSpacesDiagnostic(span)); // /a  b/
+                    Self::report(span, ctx); // /a  b/
                 }
             }
 
             AstKind::CallExpression(expr) if Self::is_regexp_call_expression(expr) => {
                 if let Some(span) = Self::find_expr_to_report(&expr.arguments) {
-                    ctx.diagnostic(NoRegexSpacesDiagnostic(span)); // RegExp('a  b')
+                    Self::report(span, ctx); // RegExp('a  b')
                 }
             }
 
             AstKind::NewExpression(expr) if Self::is_regexp_new_expression(expr) => {
                 if let Some(span) = Self::find_expr_to_report(&expr.arguments) {
-                    ctx.diagnostic(NoRegexSpacesDiagnostic(span)); // new RegExp('a  b')
+                    Self::report(span, ctx); // new RegExp('a  b')
                 }
             }
 
@@ -69,6 +69,28 @@ impl Rule for NoRegexSpaces {
 }
 
 impl NoRegexSpaces {
+    /// Report the spaces at `span`, fixing them to a single quantified space, e.g. ` {3}`.
+    fn report(span: Span, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let spaces = span.source_text(source_text);
+        // Escapes in a string pattern shift its value against the source text, e.g. `'\\d  '`.
+        if !spaces.bytes().all(|b| b == b' ') {
+            ctx.diagnostic(NoRegexSpacesDiagnostic(span));
+            return;
+        }
+
+        ctx.diagnostic_with_fix(NoRegexSpacesDiagnostic(span), || {
+            let count = spaces.len();
+            // A quantifier after the spaces only applies to the last one, e.g. `/a   {3}/`
+            if source_text[span.end as usize..].starts_with(|c| matches!(c, '+' | '*' | '{' | '?'))
+            {
+                Fix::new(format!(" {{{}}}", count - 1), Span::new(span.start, span.end - 1))
+            } else {
+                Fix::new(format!(" {{{count}}}"), span)
+            }
+        });
+    }
+
     fn find_literal_to_report(literal: &RegExpLiteral) -> Option<Span> {
         if Self::has_exempted_char_class(&literal.regex.pattern) {
             return None;
@@ -233,6 +255,7 @@ fn test() {
         r"var foo = /[\\q{    }]/v;",
         "var foo = new RegExp('[  ');",
         "new RegExp('[[abc]  ]', flags + 'v')",
+        "var foo = /a {2}b/;",
     ];
 
     let fail = vec![
@@ -261,7 +284,22 @@ fn test() {
         "var foo = /[[    ]    ]    /v;",
         "var foo = new RegExp('[   ]  ');",
         "var foo = new RegExp('[[    ]    ]    ', 'v');",
+        "var foo = /a  b/;",
+    ];
+
+    let fix = vec![
+        ("var foo = /a  b/;", "var foo = /a {2}b/;", None),
+        ("var foo = /bar    baz/;", "var foo = /bar {4}baz/;", None),
+        ("var foo = / a b  c d /;", "var foo = / a b {2}c d /;", None),
+        ("var foo = /(?:  )/;", "var foo = /(?: {2})/;", None),
+        ("var foo = /  foo   /;", "var foo = / {2}foo   /;", None),
+        ("var foo = RegExp('bar    baz');", "var foo = RegExp('bar {4}baz');", None),
+        ("var foo = new RegExp('bar    baz');", "var foo = new RegExp('bar {4}baz');", None),
+        // the quantifier stays on the last space
+        ("var foo = /bar   {3}baz/;", "var foo = /bar {2} {3}baz/;", None),
+        ("var foo = /bar    ?baz/;", "var foo = /bar {3} ?baz/;", None),
+        ("var foo = new RegExp('bar   *baz')", "var foo = new RegExp('bar {2} *baz')", None),
     ];
 
-    Tester::new(NoRegexSpaces::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoRegexSpaces::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
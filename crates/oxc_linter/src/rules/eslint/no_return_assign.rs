@@ -0,0 +1,158 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoReturnAssignDiagnostic {
+    #[error("eslint(no-return-assign): Return statement should not contain assignment.")]
+    #[diagnostic(severity(warning), help("Did you mean to use `===`?"))]
+    Return(#[label] Span),
+    #[error("eslint(no-return-assign): Arrow function should not return assignment.")]
+    #[diagnostic(severity(warning), help("Did you mean to use `===`?"))]
+    Arrow(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoReturnAssign {
+    /// Report assignments even when they are wrapped in parentheses (`"always"`).
+    always: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow assignment operators in `return` statements and arrow function bodies.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It is difficult to tell whether `return foo = bar + 2;` is meant to return the result
+    /// of the assignment, or whether `==`/`===` was intended.
+    ///
+    /// By default (`"except-parens"`) assignments wrapped in parentheses are allowed,
+    /// `"always"` disallows them as well.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function f() { return foo = bar + 2; }
+    /// const g = () => foo = bar;
+    ///
+    /// // Good
+    /// function f() { return foo === bar + 2; }
+    /// function f() { return (foo = bar + 2); }
+    /// const g = () => { foo = bar; };
+    /// ```
+    NoReturnAssign,
+    style
+);
+
+impl Rule for NoReturnAssign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let always = value.get(0).and_then(serde_json::Value::as_str) == Some("always");
+        Self { always }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AssignmentExpression(_) = node.kind() else {
+            return;
+        };
+
+        let nodes = ctx.nodes();
+        if !self.always
+            && matches!(nodes.parent_kind(node.id()), Some(AstKind::ParenthesizedExpression(_)))
+        {
+            return;
+        }
+
+        // Find the statement, function or class containing the assignment
+        let Some(parent) = nodes.iter_parents(node.id()).skip(1).find(|parent| {
+            let kind = parent.kind();
+            kind.is_statement() || kind.is_function_like() || matches!(kind, AstKind::Class(_))
+        }) else {
+            return;
+        };
+
+        match parent.kind() {
+            AstKind::ReturnStatement(stmt) => {
+                ctx.diagnostic(NoReturnAssignDiagnostic::Return(stmt.span));
+            }
+            // The body of `() => expr` is a function body with a single expression statement
+            AstKind::ExpressionStatement(stmt) => {
+                let mut ancestors = nodes.iter_parents(parent.id()).skip(1);
+                if !matches!(ancestors.next().map(AstNode::kind), Some(AstKind::FunctionBody(_))) {
+                    return;
+                }
+                if let Some(AstKind::ArrowFunctionExpression(arrow)) =
+                    ancestors.next().map(AstNode::kind)
+                {
+                    if arrow.expression {
+                        ctx.diagnostic(NoReturnAssignDiagnostic::Arrow(stmt.expression.span()));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("module.exports = {'a': 1};", None),
+        ("var result = a * b;", None),
+        ("function x() { var result = a * b; return result; }", None),
+        ("function x() { return (result = a * b); }", None),
+        (
+            "function x() { var result = a * b; return result; }",
+            Some(serde_json::json!(["except-parens"])),
+        ),
+        ("function x() { return (result = a * b); }", Some(serde_json::json!(["except-parens"]))),
+        (
+            "function x() { var result = a * b; return result; }",
+            Some(serde_json::json!(["always"])),
+        ),
+        (
+            "function x() { return function y() { result = a * b }; }",
+            Some(serde_json::json!(["always"])),
+        ),
+        ("() => { return (result = a * b); }", Some(serde_json::json!(["except-parens"]))),
+        ("() => (result = a * b)", Some(serde_json::json!(["except-parens"]))),
+        ("const foo = (a,b,c) => ((a = b), c)", None),
+        ("function x() { return a === b; }", None),
+        ("() => { result = a * b; }", Some(serde_json::json!(["always"]))),
+        ("() => { return () => { result = a * b; } }", Some(serde_json::json!(["always"]))),
+        ("function x() { return class { x = a = b; }; }", Some(serde_json::json!(["always"]))),
+        ("function x() { for (a = b; ;) { return a; } }", Some(serde_json::json!(["always"]))),
+    ];
+
+    let fail = vec![
+        ("function x() { return result = a * b; };", None),
+        ("function x() { return (result) = (a * b); };", None),
+        ("function x() { return result = a * b; };", Some(serde_json::json!(["except-parens"]))),
+        (
+            "function x() { return (result) = (a * b); };",
+            Some(serde_json::json!(["except-parens"])),
+        ),
+        ("() => { return result = a * b; }", None),
+        ("() => result = a * b", None),
+        ("function x() { return result = a * b; };", Some(serde_json::json!(["always"]))),
+        ("function x() { return (result = a * b); };", Some(serde_json::json!(["always"]))),
+        (
+            "function x() { return result || (result = a * b); };",
+            Some(serde_json::json!(["always"])),
+        ),
+        ("function foo(){ return a = b, c; }", None),
+        ("() => (result = a * b)", Some(serde_json::json!(["always"]))),
+        ("const foo = (a) => (b) => a = b", None),
+    ];
+
+    Tester::new(NoReturnAssign::NAME, pass, fail).test_and_snapshot();
+}
@@ -38,23 +38,14 @@ declare_oxc_lint!(
 impl Rule for NoScriptUrl {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::StringLiteral(literal)
-                if literal.value.to_lowercase().starts_with("javascript:") =>
-            {
+            AstKind::StringLiteral(literal) if is_script_url(&literal.value) => {
                 emit_diagnostic(ctx, literal.span);
             }
             AstKind::TemplateLiteral(literal)
                 if !is_tagged_template_expression(ctx, node, literal.span) =>
             {
                 if literal.quasis.len() == 1
-                    && literal
-                        .quasis
-                        .first()
-                        .unwrap()
-                        .value
-                        .raw
-                        .to_lowercase()
-                        .starts_with("javascript:")
+                    && is_script_url(&literal.quasis.first().unwrap().value.raw)
                 {
                     emit_diagnostic(ctx, literal.span);
                 }
@@ -64,6 +55,15 @@ impl Rule for NoScriptUrl {
     }
 }
 
+/// Browsers strip leading whitespace and control characters before reading the URL scheme,
+/// so `"  javascript:"` is just as much a script URL as `"javascript:"`.
+fn is_script_url(value: &str) -> bool {
+    value
+        .trim_start_matches(|c: char| c.is_whitespace() || c.is_control())
+        .get(.."javascript:".len())
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("javascript:"))
+}
+
 fn emit_diagnostic(ctx: &LintContext, span: Span) {
     ctx.diagnostic(NoScriptUrlDiagnostic(Span::new(span.start, span.end)));
 }
@@ -86,6 +86,9 @@ fn test() {
         "var url = `xjavascript:`",
         "var url = `${foo}javascript:`",
         "var a = foo`javaScript:`;",
+        "var url = 'https://example.com';",
+        "var url = 'java script:void(0)';",
+        "<a href='https://example.com'>link</a>",
     ];
 
     let fail = vec![
@@ -93,6 +96,9 @@ fn test() {
         "var a = 'javascript:';",
         "var a = `javascript:`;",
         "var a = `JavaScript:`;",
+        "var a = '  javascript:void(0)';",
+        "var a = '\\t\\nJAVASCRIPT:void(0)';",
+        "<a href='javascript:void(0)'>link</a>",
     ];
 
     Tester::new(NoScriptUrl::NAME, pass, fail).test_and_snapshot();
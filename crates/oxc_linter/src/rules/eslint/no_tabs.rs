@@ -0,0 +1,154 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-tabs): Unexpected tab character.")]
+#[diagnostic(severity(warning))]
+struct NoTabsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTabs {
+    /// Allow tabs used for indentation, at the start of a line.
+    allow_indentation_tabs: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow all tabs
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Tabs are displayed with a different width depending on the editor,
+    /// so code aligned with them looks different for everyone.
+    ///
+    /// Tabs in strings, template literals, regular expressions and comments are
+    /// not reported. With `{ "allowIndentationTabs": true }`, tabs at the start
+    /// of a line are allowed as well.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // a tab between `a` and `=`
+    /// var a	= 1;
+    /// ```
+    NoTabs,
+    style
+);
+
+impl Rule for NoTabs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_indentation_tabs = value
+            .get(0)
+            .and_then(|config| config.get("allowIndentationTabs"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { allow_indentation_tabs }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        if !source_text.contains('\t') {
+            return;
+        }
+
+        let ignored = ignored_spans(ctx);
+        let is_ignored = |offset: u32| {
+            let index = ignored.partition_point(|span| span.start <= offset);
+            index > 0 && offset < ignored[index - 1].end
+        };
+
+        let bytes = source_text.as_bytes();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            if bytes[offset] != b'\t' {
+                offset += 1;
+                continue;
+            }
+            let start = offset;
+            while offset < bytes.len() && bytes[offset] == b'\t' {
+                offset += 1;
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            let span = Span::new(start as u32, offset as u32);
+            if is_ignored(span.start) || (self.allow_indentation_tabs && is_indentation(span, ctx))
+            {
+                continue;
+            }
+            ctx.diagnostic(NoTabsDiagnostic(span));
+        }
+    }
+}
+
+/// Comments, strings, template literals and regular expressions, sorted by position.
+fn ignored_spans(ctx: &LintContext) -> Vec<Span> {
+    let mut spans = ctx.semantic().trivias().comments().map(|(_, span)| span).collect::<Vec<_>>();
+    for node in ctx.nodes().iter() {
+        match node.kind() {
+            AstKind::StringLiteral(lit) => spans.push(lit.span),
+            AstKind::RegExpLiteral(lit) => spans.push(lit.span),
+            AstKind::TemplateLiteral(lit) => spans.extend(lit.quasis.iter().map(|quasi| quasi.span)),
+            _ => {}
+        }
+    }
+    spans.sort_unstable_by_key(|span| span.start);
+    spans
+}
+
+/// Whether the tabs at `span` are only preceded by whitespace on their line.
+fn is_indentation(span: Span, ctx: &LintContext) -> bool {
+    // Scan back to the start of the line, stopping at the first character that isn't whitespace
+    ctx.source_text()[..span.start as usize]
+        .chars()
+        .rev()
+        .take_while(|c| !matches!(c, '\n' | '\r'))
+        .all(char::is_whitespace)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function test(){\n}", None),
+        ("function test(){\n  //   sss\n}", None),
+        ("var a = 'foo\tbar';", None),
+        ("var a = \"\t\";", None),
+        ("var a = `foo\tbar`;", None),
+        ("var a = `${b}\t${c}`;", None),
+        ("var a = /\t/;", None),
+        ("// \tcomment", None),
+        ("/*\t*/ var a;", None),
+        (
+            "function test(){\n\tvar a = 1;\n}",
+            Some(serde_json::json!([{ "allowIndentationTabs": true }])),
+        ),
+        (
+            "function test(){\n\t\tvar a = 1;\n\t}",
+            Some(serde_json::json!([{ "allowIndentationTabs": true }])),
+        ),
+        ("if (a) {\n  \tb();\n}", Some(serde_json::json!([{ "allowIndentationTabs": true }]))),
+    ];
+
+    let fail = vec![
+        ("function test(){\n\tvar a = 1;\n}", None),
+        ("var a\t= 1;", None),
+        ("var a = 1;\t\t// comment", None),
+        ("function test(){\n\t\tvar a\t= 1;\n}", None),
+        ("var a = `${\tb}`;", None),
+        ("var a\t= 1;", Some(serde_json::json!([{ "allowIndentationTabs": true }]))),
+        (
+            "function test(){\n\tvar a\t= 1;\n}",
+            Some(serde_json::json!([{ "allowIndentationTabs": true }])),
+        ),
+    ];
+
+    Tester::new(NoTabs::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,156 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoThrowLiteralDiagnostic {
+    #[error("eslint(no-throw-literal): Expected an error object to be thrown.")]
+    #[diagnostic(severity(warning), help("Throw an `Error` instead, it has a stack trace"))]
+    NotError(#[label] Span),
+    #[error("eslint(no-throw-literal): Do not throw undefined.")]
+    #[diagnostic(severity(warning), help("Throw an `Error` instead, it has a stack trace"))]
+    Undefined(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoThrowLiteral;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow throwing literals and other expressions which can't be an `Error` object.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Only `Error` objects carry a stack trace, so throwing anything else makes the origin of
+    /// the exception hard to find. Identifiers, calls and member expressions could be `Error`
+    /// objects and are allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// throw "error";
+    /// throw 0;
+    /// throw { message: "error" };
+    ///
+    /// // Good
+    /// throw new Error("error");
+    /// throw err;
+    /// ```
+    NoThrowLiteral,
+    pedantic
+);
+
+impl Rule for NoThrowLiteral {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ThrowStatement(stmt) = node.kind() else { return };
+
+        if !could_be_error(&stmt.argument) {
+            ctx.diagnostic(NoThrowLiteralDiagnostic::NotError(stmt.span));
+        } else if stmt.argument.is_specific_id("undefined") {
+            ctx.diagnostic(NoThrowLiteralDiagnostic::Undefined(stmt.span));
+        }
+    }
+}
+
+/// Whether the value of `expr` could be an `Error` object.
+fn could_be_error(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(_)
+        | Expression::ThisExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::MemberExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::ImportExpression(_) => true,
+        Expression::AssignmentExpression(expr) => match expr.operator {
+            AssignmentOperator::Assign | AssignmentOperator::LogicalAnd => {
+                could_be_error(&expr.right)
+            }
+            // The target keeps its value unless it is falsy or nullish
+            AssignmentOperator::LogicalOr | AssignmentOperator::LogicalNullish => true,
+            _ => false,
+        },
+        Expression::SequenceExpression(expr) => expr.expressions.last().is_some_and(could_be_error),
+        Expression::LogicalExpression(expr) => match expr.operator {
+            LogicalOperator::And => could_be_error(&expr.right),
+            LogicalOperator::Or | LogicalOperator::Coalesce => {
+                could_be_error(&expr.left) || could_be_error(&expr.right)
+            }
+        },
+        Expression::ConditionalExpression(expr) => {
+            could_be_error(&expr.consequent) || could_be_error(&expr.alternate)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "throw new Error();",
+        "throw new Error('error');",
+        "throw Error('error');",
+        "var e = new Error(); throw e;",
+        "try {throw new Error();} catch (e) {throw e;};",
+        "throw err;",
+        "throw this;",
+        "throw a.b.c;",
+        "throw foo();",
+        "throw new foo();",
+        "throw foo.bar();",
+        "throw foo[bar];",
+        "throw foo?.bar;",
+        "throw foo`bar`;",
+        "throw foo = new Error();",
+        "throw foo.bar ||= 'literal';",
+        "throw foo[bar] ??= 'literal';",
+        "throw foo &&= new Error();",
+        "throw 1, 2, new Error();",
+        "throw 'literal' && new Error();",
+        "throw new Error() || 'literal';",
+        "throw foo ? new Error() : 'literal';",
+        "throw foo ? 'literal' : new Error();",
+        "function* foo() { var index = 0; throw yield index++; }",
+        "async function foo() { throw await bar; }",
+        "throw (err as Error);",
+        "throw err!;",
+    ];
+
+    let fail = vec![
+        "throw 'error';",
+        "throw 0;",
+        "throw false;",
+        "throw null;",
+        "throw {};",
+        "throw [];",
+        "throw `${err}`;",
+        "throw 'a' + 'b';",
+        "var b = new Error(); throw 'a' + b;",
+        "throw undefined;",
+        "throw foo = 'error';",
+        "throw foo += new Error();",
+        "throw foo &&= 'literal';",
+        "throw new Error(), 1, 2, 3;",
+        "throw 'literal' && 'not an Error';",
+        "throw foo && 'literal';",
+        "throw foo ? 'not an Error' : 'literal';",
+        "throw (1 as any);",
+        "throw () => {};",
+        "throw class {};",
+    ];
+
+    Tester::new(NoThrowLiteral::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,234 @@
+use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{
+    petgraph::{stable_graph::NodeIndex, visit::EdgeRef, Direction},
+    AstNodeId, EdgeType,
+};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unreachable): Unreachable code.")]
+#[diagnostic(severity(warning), help("Remove the code that can never be executed"))]
+struct NoUnreachableDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unreachable code after `return`, `throw`, `continue`, and `break` statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Statements after a `return`, `throw`, `continue` or `break` statement can never be
+    /// executed, which is usually a mistake.
+    ///
+    /// Function declarations are hoisted and `var` declarations without an initializer have
+    /// no effect, so they are not reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     return true;
+    ///     console.log("done");
+    /// }
+    /// ```
+    NoUnreachable,
+    nursery
+);
+
+impl Rule for NoUnreachable {
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+        let reachable_blocks = Self::reachable_blocks(ctx);
+
+        // `finally` blocks are in the control flow graph once for every way of leaving the
+        // `try` statement, so a statement is reachable if any of its copies is.
+        let mut reachable_spans = FxHashSet::default();
+        // The control flow graph can put the code following a jump in the same basic block as
+        // code reachable from elsewhere, e.g. after the last `switch` case, so statements are
+        // also unreachable after a jump in the same statement list.
+        let mut jumps: FxHashMap<AstNodeId, u32> = FxHashMap::default();
+        let mut candidates = vec![];
+        for node in nodes.iter() {
+            let kind = node.kind();
+            if !is_checked_statement(kind) {
+                continue;
+            }
+            let parent_id = nodes.parent_id(node.id());
+            let after_jump = parent_id
+                .and_then(|parent_id| jumps.get(&parent_id))
+                .is_some_and(|jump_index| *jump_index < node.child_index());
+            let is_jump = matches!(
+                kind,
+                AstKind::ReturnStatement(_)
+                    | AstKind::ThrowStatement(_)
+                    | AstKind::BreakStatement(_)
+                    | AstKind::ContinueStatement(_)
+            );
+            if let Some(parent_id) = parent_id.filter(|_| is_jump) {
+                if is_statement_list(nodes.kind(parent_id)) {
+                    jumps.entry(parent_id).or_insert(node.child_index());
+                }
+            }
+            if !after_jump && reachable_blocks.contains(&node.cfg_ix()) {
+                reachable_spans.insert(kind.span());
+            } else {
+                candidates.push(node);
+            }
+        }
+
+        let mut unreachable = FxHashSet::default();
+        // (parent, child index of the last statement, span of the consecutive statements)
+        let mut ranges: Vec<(Option<AstNodeId>, u32, Span)> = vec![];
+        for node in candidates {
+            let span = node.kind().span();
+            if reachable_spans.contains(&span) {
+                continue;
+            }
+            unreachable.insert(node.id());
+            // Only the outermost unreachable statement is reported
+            if nodes.ancestors(node.id()).skip(1).any(|node_id| unreachable.contains(&node_id)) {
+                continue;
+            }
+            let parent_id = nodes.parent_id(node.id());
+            let child_index = node.child_index();
+            match ranges.last_mut() {
+                Some((last_parent_id, last_index, range))
+                    if *last_parent_id == parent_id && *last_index + 1 == child_index =>
+                {
+                    *last_index = child_index;
+                    range.end = span.end;
+                }
+                _ => ranges.push((parent_id, child_index, span)),
+            }
+        }
+
+        let mut reported = FxHashSet::default();
+        for (_, _, span) in ranges {
+            if reported.insert(span) {
+                ctx.diagnostic(NoUnreachableDiagnostic(span));
+            }
+        }
+    }
+}
+
+impl NoUnreachable {
+    /// Collect the basic blocks reachable from the start of the program or of a function.
+    fn reachable_blocks(ctx: &LintContext) -> FxHashSet<NodeIndex> {
+        let cfg = ctx.semantic().cfg();
+        let mut stack = ctx
+            .nodes()
+            .iter()
+            .filter(|node| {
+                matches!(node.kind(), AstKind::Program(_)) || node.kind().is_function_like()
+            })
+            .map(AstNode::cfg_ix)
+            .collect::<Vec<_>>();
+
+        let mut reachable = FxHashSet::default();
+        while let Some(block_ix) = stack.pop() {
+            if !reachable.insert(block_ix) {
+                continue;
+            }
+            // Nested functions are walked from their own start
+            for edge in cfg.graph.edges_directed(block_ix, Direction::Outgoing) {
+                if !matches!(edge.weight(), EdgeType::NewFunction) {
+                    stack.push(edge.target());
+                }
+            }
+        }
+        reachable
+    }
+}
+
+fn is_statement_list(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::Program(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::BlockStatement(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::SwitchCase(_)
+    )
+}
+
+/// Function declarations are hoisted, and `var` declarations without an initializer have no
+/// effect, so they are never unreachable.
+fn is_checked_statement(kind: AstKind) -> bool {
+    match kind {
+        AstKind::VariableDeclaration(decl) => {
+            decl.kind != VariableDeclarationKind::Var
+                || decl.declarations.iter().any(|declarator| declarator.init.is_some())
+        }
+        AstKind::Class(class) => class.is_declaration(),
+        _ => kind.is_statement(),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { function bar() { return 1; } return bar(); }",
+        "function foo() { return bar(); function bar() { return 1; } }",
+        "function foo() { return x; var x; }",
+        "function foo() { var x = 1; var y = 2; }",
+        "while (true) { switch (foo) { case 1: x = 1; x = 2;} }",
+        "while (true) { break; var x; }",
+        "while (true) { continue; var x, y; }",
+        "while (true) { throw 'message'; var x; }",
+        "while (true) { if (true) break; var x = 1; }",
+        "while (true) continue;",
+        "switch (foo) { case 1: break; var x; }",
+        "switch (foo) { case 1: break; case 2: foo(); }",
+        "function foo() { switch (foo) { case 1: return; case 2: foo(); } }",
+        "function foo() { if (x) return; else bar(); }",
+        "var x = 1; y = 2; throw 'uh oh'; var y;",
+        "function foo() { var x = 1; if (x) { return; } x = 2; }",
+        "function foo() { var x = 1; if (x) { } else { return; } x = 2; }",
+        "function foo() { if (x) { return 1; } else { bar(); } baz(); }",
+        "function foo() { var x = 1; while (x) { return; } x = 2; }",
+        "function foo() { var x = 1; for (x in {}) { return; } x = 2; }",
+        "function foo() { var x = 1; try { return; } finally { x = 2; } }",
+        "function foo() { try { bar(); } catch (e) { return 1; } return 2; }",
+        "function foo() { try { throw new Error(); } catch (e) { bar(); } baz(); }",
+        "try { foo(); } finally { bar(); } baz();",
+        "const foo = () => { return 1; }; foo();",
+        "var foo = function() { throw new Error(); }; foo();",
+        "label: { break label; function foo() {} }",
+    ];
+
+    let fail = vec![
+        "function foo() { return x; var x = 1; }",
+        "function foo() { return x; var x, y = 1; }",
+        "function foo() { return; x = 1; }",
+        "function foo() { return; x = 1; y = 2; }",
+        "function foo() { throw error; x = 1; }",
+        "while (true) { break; x = 1; }",
+        "while (true) { continue; x = 1; }",
+        "function foo() { switch (foo) { case 1: return; x = 1; } }",
+        "switch (foo) { case 1: throw e; x = 1; }",
+        "while (true) { switch (foo) { case 1: break; x = 1; } }",
+        "label: { break label; foo(); }",
+        "var x = 1; throw 'uh oh'; var y = 2;",
+        "function foo() { if (x) { return; } else { throw e; } x = 2; }",
+        "function foo() { return; if (x) { bar(); } baz(); }",
+        "function foo() { return; class Bar {} }",
+        "function foo() { return; let x = 1; function bar() {} x = 2; }",
+        "function foo() { try { return 1; } finally { bar(); } baz(); }",
+        "function foo() { try { bar(); } finally { return 1; } baz(); }",
+        "const foo = () => { throw e; bar(); };",
+    ];
+
+    Tester::new(NoUnreachable::NAME, pass, fail).test_and_snapshot();
+}
@@ -164,6 +164,18 @@ fn test() {
             "var foo = function() { try { return 1; } finally { class bar { constructor() {} static ehm() { return 'Hola!'; } } } };",
             None,
         ),
+        (
+            "var foo = function() { try {} finally { for (let i = 0; i < 10; i++) { if (i) { break; } else { continue; } } } }",
+            None,
+        ),
+        (
+            "var foo = function() { try {} finally { for (const x of xs) { switch (x) { case 1: break; default: continue; } } } }",
+            None,
+        ),
+        (
+            "var foo = function() { try { return 1; } finally { [1, 2].forEach(function(x) { return x; }); } }",
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -0,0 +1,234 @@
+use oxc_ast::{ast::ModuleExportName, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeId, SymbolId};
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-use-before-define): '{0}' was used before it was defined.")]
+#[diagnostic(severity(warning))]
+struct NoUseBeforeDefineDiagnostic(CompactStr, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoUseBeforeDefine {
+    /// Check function declarations. Functions are hoisted, so this is purely a style check.
+    functions: bool,
+    /// Check class declarations in upper function scopes.
+    classes: bool,
+    /// Check variable declarations in upper function scopes.
+    variables: bool,
+    /// Allow references in `export { name }` before the declaration.
+    allow_named_exports: bool,
+}
+
+impl Default for NoUseBeforeDefine {
+    fn default() -> Self {
+        Self { functions: true, classes: true, variables: true, allow_named_exports: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of variables before they are defined.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `var` and function declarations are hoisted, so using them before their declaration
+    /// in source order is confusing. `let`, `const` and `class` declarations are not initialized
+    /// until they are evaluated, so using them earlier throws a `ReferenceError` (temporal dead zone).
+    ///
+    /// ### Options
+    ///
+    /// - `functions` (default `true`): check function declarations.
+    /// - `classes` (default `true`): check class declarations used from inner functions.
+    /// - `variables` (default `true`): check variables used from inner functions.
+    /// - `allowNamedExports` (default `false`): allow `export { name }` before the declaration.
+    ///
+    /// The string option `"nofunc"` is the same as `{ "functions": false }`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// alert(a);
+    /// var a = 10;
+    ///
+    /// f();
+    /// function f() {}
+    ///
+    /// new A();
+    /// class A {}
+    /// ```
+    NoUseBeforeDefine,
+    restriction
+);
+
+impl NoUseBeforeDefine {
+    fn is_forbidden(&self, declaration: AstKind, is_outer: bool) -> bool {
+        match declaration {
+            AstKind::Function(_) => self.functions,
+            AstKind::Class(_) if is_outer => self.classes,
+            AstKind::VariableDeclarator(_) if is_outer => self.variables,
+            _ => true,
+        }
+    }
+}
+
+/// The nearest function or top level scope, which `var` declarations are hoisted to.
+fn var_scope_id(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scopes = ctx.scopes();
+    scopes.ancestors(scope_id).find(|scope_id| scopes.get_flags(*scope_id).is_var()).unwrap()
+}
+
+/// Whether a reference that appears after the declared name is still evaluated while the
+/// declaration is being initialized, e.g. `let a = a + 1` or `class A extends A {}`.
+fn is_evaluated_during_initialization(declaration: AstKind, span: Span) -> bool {
+    let contains = |outer: Span| outer.start <= span.start && span.end <= outer.end;
+    match declaration {
+        AstKind::VariableDeclarator(decl) => {
+            decl.init.as_ref().is_some_and(|init| contains(init.span()))
+        }
+        AstKind::Class(class) => {
+            class.super_class.as_ref().is_some_and(|super_class| contains(super_class.span()))
+        }
+        _ => false,
+    }
+}
+
+impl Rule for NoUseBeforeDefine {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        if config.and_then(serde_json::Value::as_str) == Some("nofunc") {
+            return Self { functions: false, ..Self::default() };
+        }
+
+        let option = |name: &str, default: bool| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+
+        Self {
+            functions: option("functions", true),
+            classes: option("classes", true),
+            variables: option("variables", true),
+            allow_named_exports: option("allowNamedExports", false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // `export { a }` does not create a reference, resolve the exported names manually.
+        if self.allow_named_exports {
+            return;
+        }
+        let AstKind::ExportNamedDeclaration(decl) = node.kind() else {
+            return;
+        };
+        if decl.source.is_some() {
+            return;
+        }
+
+        let symbols = ctx.symbols();
+        for specifier in &decl.specifiers {
+            let ModuleExportName::Identifier(ident) = &specifier.local else {
+                continue;
+            };
+            let Some(symbol_id) = ctx.scopes().get_root_binding(&ident.name) else {
+                continue;
+            };
+            let declaration = ctx.nodes().kind(symbols.get_declaration(symbol_id));
+            if ident.span.end <= symbols.get_span(symbol_id).start
+                && self.is_forbidden(declaration, false)
+            {
+                ctx.diagnostic(NoUseBeforeDefineDiagnostic(
+                    ident.name.to_compact_str(),
+                    ident.span,
+                ));
+            }
+        }
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let nodes = ctx.nodes();
+        let declaration = nodes.kind(symbols.get_declaration(symbol_id));
+        let declaration_span = symbols.get_span(symbol_id);
+        let declaration_var_scope_id = var_scope_id(symbols.get_scope_id(symbol_id), ctx);
+
+        for reference in symbols.get_resolved_references(symbol_id) {
+            if reference.is_type() {
+                continue;
+            }
+
+            let span = reference.span();
+            let reference_var_scope_id =
+                var_scope_id(nodes.get_node(reference.node_id()).scope_id(), ctx);
+            let is_outer = reference_var_scope_id != declaration_var_scope_id;
+
+            if declaration_span.end <= span.start
+                && (is_outer || !is_evaluated_during_initialization(declaration, span))
+            {
+                continue;
+            }
+
+            if self.is_forbidden(declaration, is_outer) {
+                ctx.diagnostic(NoUseBeforeDefineDiagnostic(
+                    symbols.get_name(symbol_id).into(),
+                    span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 10; alert(a);", None),
+        ("function b(a) { alert(a); }", None),
+        ("Object.hasOwnProperty.call(a);", None),
+        ("function a() { alert(arguments); }", None),
+        ("let a; a = 1;", None),
+        ("var a = function () { return a; };", None),
+        ("const f = () => f();", None),
+        ("class A { foo() { return new A(); } }", None),
+        ("function f() { return f(); }", None),
+        ("const a = 1; export { a };", None),
+        ("foo(); function foo() {}", Some(serde_json::json!([{ "functions": false }]))),
+        ("foo(); function foo() {}", Some(serde_json::json!(["nofunc"]))),
+        (
+            "function f() { return new A(); } class A {}",
+            Some(serde_json::json!([{ "classes": false }])),
+        ),
+        (
+            "function f() { return a; } const a = 1;",
+            Some(serde_json::json!([{ "variables": false }])),
+        ),
+        ("export { a }; const a = 1;", Some(serde_json::json!([{ "allowNamedExports": true }]))),
+        ("export { a } from 'a'; const a = 1;", None),
+    ];
+
+    let fail = vec![
+        ("a++; var a = 19;", None),
+        ("alert(a); var a = 10;", None),
+        ("f(); function f() {}", None),
+        ("bar; let bar;", None),
+        ("new A(); class A {}", None),
+        ("function f() { return new A(); } class A {}", None),
+        ("function f() { return a; } const a = 1;", None),
+        ("let a = a + 1;", None),
+        ("new A(); class A {}", Some(serde_json::json!([{ "classes": false }]))),
+        ("a; var a = 1;", Some(serde_json::json!([{ "variables": false }]))),
+        ("export { a }; const a = 1;", None),
+        ("export { foo }; function foo() {}", Some(serde_json::json!([{ "functions": true }]))),
+    ];
+
+    Tester::new(NoUseBeforeDefine::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,202 @@
+use oxc_ast::{
+    ast::{Argument, ArrayExpressionElement, CallExpression, Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::is_same_reference, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-call): Unnecessary `.{1}()`.")]
+#[diagnostic(severity(warning), help("Call the function directly instead."))]
+struct NoUselessCallDiagnostic(#[label] pub Span, pub &'static str);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessCall;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary `.call()` and `.apply()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When the `thisArg` passed to `Function#call()` or `Function#apply()` is the same
+    /// `this` the function would receive from a normal invocation, the call is just a slower
+    /// and harder to read version of calling the function directly.
+    ///
+    /// `.apply()` is only reported when its arguments are an array literal, calls such as
+    /// `foo.apply(null, args)` are left to `unicorn/prefer-reflect-apply` and
+    /// `unicorn/prefer-spread`. Both rules can report `foo.apply(null, [1, 2])`, the fix
+    /// provided here rewrites it to `foo(1, 2)` which satisfies both.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// foo.call(undefined, 1, 2, 3);
+    /// foo.apply(null, [1, 2, 3]);
+    /// obj.foo.call(obj, 1, 2, 3);
+    ///
+    /// // Good
+    /// foo(1, 2, 3);
+    /// obj.foo(1, 2, 3);
+    /// obj.foo.call(otherObj, 1, 2, 3);
+    /// foo.apply(undefined, args);
+    /// ```
+    NoUselessCall,
+    pedantic
+);
+
+fn is_null_or_undefined(expr: &Expression) -> bool {
+    expr.is_null() || expr.is_undefined() || expr.is_void()
+}
+
+/// Source text of the arguments the function receives, or `None` when they can't be written
+/// as a plain argument list (e.g. an array literal with holes).
+fn direct_arguments<'a>(
+    method: &str,
+    call_expr: &CallExpression<'a>,
+    source_text: &'a str,
+) -> Option<&'a str> {
+    let spans = match (method, call_expr.arguments.as_slice()) {
+        ("apply", [_, Argument::Expression(Expression::ArrayExpression(array))]) => {
+            if array.elements.iter().any(|elem| matches!(elem, ArrayExpressionElement::Elision(_)))
+            {
+                return None;
+            }
+            array.elements.first().map(GetSpan::span).zip(array.elements.last().map(GetSpan::span))
+        }
+        (_, [_, rest @ ..]) => rest.first().map(GetSpan::span).zip(rest.last().map(GetSpan::span)),
+        _ => None,
+    };
+    Some(
+        spans.map_or("", |(first, last)| Span::new(first.start, last.end).source_text(source_text)),
+    )
+}
+
+impl Rule for NoUselessCall {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+
+        let Expression::MemberExpression(member_expr) = &call_expr.callee else {
+            return;
+        };
+
+        let MemberExpression::StaticMemberExpression(callee) = &**member_expr else {
+            return;
+        };
+
+        let (method, this_arg) = match (
+            callee.property.name.as_str(),
+            call_expr.arguments.as_slice(),
+        ) {
+            ("call", [Argument::Expression(this_arg), ..]) => ("call", this_arg),
+            (
+                "apply",
+                [Argument::Expression(this_arg), Argument::Expression(Expression::ArrayExpression(_))],
+            ) => ("apply", this_arg),
+            _ => return,
+        };
+
+        let applied = callee.object.without_parenthesized();
+        let this_arg = this_arg.without_parenthesized();
+
+        let is_natural_this = match applied {
+            Expression::MemberExpression(applied_member) => {
+                is_same_reference(applied_member.object(), this_arg, ctx)
+            }
+            _ => is_null_or_undefined(this_arg),
+        };
+        if !is_natural_this {
+            return;
+        }
+
+        let diagnostic = NoUselessCallDiagnostic(call_expr.span, method);
+
+        // Rewriting an optional call or an optional chain into a plain call would change when
+        // the call is short-circuited.
+        if call_expr.optional
+            || callee.optional
+            || matches!(applied, Expression::ChainExpression(_))
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        let Some(arguments) = direct_arguments(method, call_expr, ctx.source_text()) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            Fix::new(
+                format!("{}({arguments})", callee.object.span().source_text(ctx.source_text())),
+                call_expr.span,
+            )
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // `this` binding is different
+        "foo.apply(obj, [1, 2]);",
+        "obj.foo.call(null, 1, 2);",
+        "obj.foo.apply(otherObj, [1, 2]);",
+        "a.b(x, y).c.foo.call(a.b(x, z).c, 1, 2);",
+        "foo.call(obj, 1, 2);",
+        // ignores variadic
+        "foo.apply(undefined, args);",
+        "obj.foo.apply(obj, args);",
+        // ignores computed property
+        "foo[\"call\"](undefined, 1, 2);",
+        // ignores incomplete things
+        "foo.call();",
+        "obj.foo.call();",
+        "foo.apply();",
+        "obj.foo.apply();",
+        "foo.call;",
+        "foo.call(...args);",
+    ];
+
+    let fail = vec![
+        "foo.call(undefined, 1, 2);",
+        "foo.call(void 0, 1, 2);",
+        "foo.call(null, 1, 2);",
+        "obj.foo.call(obj, 1, 2);",
+        "a.b.c.foo.call(a.b.c, 1, 2);",
+        "this.foo.call(this, 1, 2);",
+        "foo.apply(undefined, [1, 2]);",
+        "foo.apply(null, [1, 2]);",
+        "obj.foo.apply(obj, [1, 2]);",
+        "foo.call(undefined);",
+        "foo.apply(null, [1, , 2]);",
+        "foo?.call(undefined, 1);",
+        "obj?.foo.call(obj, 1);",
+    ];
+
+    let fix = vec![
+        ("foo.call(undefined, 1, 2);", "foo(1, 2);", None),
+        ("foo.call(null);", "foo();", None),
+        ("obj.foo.call(obj, a, b);", "obj.foo(a, b);", None),
+        ("this.foo.call(this, 1);", "this.foo(1);", None),
+        ("foo.apply(undefined, [1, ...rest]);", "foo(1, ...rest);", None),
+        ("obj.foo.apply(obj, []);", "obj.foo();", None),
+        ("(a || b).call(null, 1);", "(a || b)(1);", None),
+        // not fixed
+        ("foo.apply(null, [1, , 2]);", "foo.apply(null, [1, , 2]);", None),
+        ("foo?.call(undefined, 1);", "foo?.call(undefined, 1);", None),
+    ];
+
+    Tester::new(NoUselessCall::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -32,9 +32,12 @@ declare_oxc_lint!(
     /// var foo = void 0;
     ///
     /// // success
-    /// "var foo = bar()",
-    /// "foo.void()",
-    /// "foo.void = bar",
+    /// var foo = bar();
+    /// foo.void();
+    /// foo.void = bar;
+    ///
+    /// // success with `{ "allowAsStatement": true }`
+    /// void somePromise();
     /// ```
     NoVoid,
     restriction,
@@ -63,10 +66,7 @@ impl Rule for NoVoid {
         };
 
         if unary_expr.operator == UnaryOperator::Void {
-            ctx.diagnostic(NoVoidDiagnostic(Span::new(
-                unary_expr.span.start,
-                unary_expr.span.start + 4,
-            )));
+            ctx.diagnostic(NoVoidDiagnostic(unary_expr.span));
         }
     }
 }
@@ -82,6 +82,11 @@ fn test() {
         ("delete foo;", None),
         ("void 0", Some(serde_json::json!([{ "allowAsStatement": true }]))),
         ("void(0)", Some(serde_json::json!([{ "allowAsStatement": true }]))),
+        ("void foo();", Some(serde_json::json!([{ "allowAsStatement": true }]))),
+        (
+            "async function f() { void somePromise(); }",
+            Some(serde_json::json!([{ "allowAsStatement": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -91,6 +96,9 @@ fn test() {
         ("void(0)", None),
         ("var foo = void 0", None),
         ("var foo = void 0", Some(serde_json::json!([{ "allowAsStatement": true }]))),
+        ("void foo();", None),
+        ("bar(void foo());", Some(serde_json::json!([{ "allowAsStatement": true }]))),
+        ("const x = void foo();", Some(serde_json::json!([{ "allowAsStatement": true }]))),
     ];
 
     Tester::new(NoVoid::NAME, pass, fail).test_and_snapshot();
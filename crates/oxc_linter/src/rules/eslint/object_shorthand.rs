@@ -0,0 +1,484 @@
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, Expression, Function, IdentifierReference, MetaProperty,
+        ObjectProperty, PropertyDefinition, PropertyKey, PropertyKind, StaticBlock, Super,
+        ThisExpression,
+    },
+    visit::Visit,
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ObjectShorthandDiagnostic {
+    #[error("eslint(object-shorthand): Expected property shorthand.")]
+    #[diagnostic(severity(warning))]
+    PropertyShorthand(#[label] Span),
+    #[error("eslint(object-shorthand): Expected method shorthand.")]
+    #[diagnostic(severity(warning))]
+    MethodShorthand(#[label] Span),
+    #[error("eslint(object-shorthand): Expected longform property syntax.")]
+    #[diagnostic(severity(warning))]
+    PropertyLongform(#[label] Span),
+    #[error("eslint(object-shorthand): Expected longform method syntax.")]
+    #[diagnostic(severity(warning))]
+    MethodLongform(#[label] Span),
+    #[error("eslint(object-shorthand): Expected longform method syntax for string literal keys.")]
+    #[diagnostic(severity(warning))]
+    LiteralMethodLongform(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectShorthand(Box<ObjectShorthandConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectShorthandConfig {
+    mode: Mode,
+    /// Prefer longform syntax for string literal keys.
+    avoid_quotes: bool,
+    /// Allow longform methods whose name looks like a constructor, e.g. `{ Foo: function() {} }`.
+    ignore_constructors: bool,
+    /// Prefer methods over arrow functions with a block body.
+    avoid_explicit_return_arrows: bool,
+}
+
+impl std::ops::Deref for ObjectShorthand {
+    type Target = ObjectShorthandConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum Mode {
+    #[default]
+    Always,
+    Methods,
+    Properties,
+    Never,
+}
+
+impl Mode {
+    pub fn from(raw: &str) -> Self {
+        match raw {
+            "methods" => Self::Methods,
+            "properties" => Self::Properties,
+            "never" => Self::Never,
+            _ => Self::Always,
+        }
+    }
+
+    fn applies_to_methods(self) -> bool {
+        matches!(self, Self::Always | Self::Methods)
+    }
+
+    fn applies_to_properties(self) -> bool {
+        matches!(self, Self::Always | Self::Properties)
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow method and property shorthand syntax for object literals
+    ///
+    /// ### Why is this bad?
+    ///
+    /// ES2015 allows `{ foo }` for `{ foo: foo }` and `{ foo() {} }` for
+    /// `{ foo: function() {} }`. Mixing both styles makes object literals harder to scan.
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default), `"methods"`, `"properties"` or `"never"`
+    /// - `avoidQuotes`: prefer longform syntax for string literal keys
+    /// - `ignoreConstructors`: allow longform methods with a capitalized name
+    /// - `avoidExplicitReturnArrows`: prefer methods over arrow functions with a block body
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = {
+    ///     x: x,
+    ///     y: function() {},
+    /// };
+    /// ```
+    ObjectShorthand,
+    style
+);
+
+impl Rule for ObjectShorthand {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode =
+            value.get(0).and_then(serde_json::Value::as_str).map(Mode::from).unwrap_or_default();
+        let options = value.get(1);
+        let get_bool = |name: &str| {
+            options
+                .and_then(|options| options.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+
+        Self(Box::new(ObjectShorthandConfig {
+            mode,
+            avoid_quotes: get_bool("avoidQuotes"),
+            ignore_constructors: get_bool("ignoreConstructors"),
+            avoid_explicit_return_arrows: get_bool("avoidExplicitReturnArrows"),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ObjectProperty(prop) = node.kind() else { return };
+        // Getters and setters have no longform.
+        if prop.kind != PropertyKind::Init {
+            return;
+        }
+
+        if prop.shorthand || prop.method {
+            self.check_shorthand(prop, ctx);
+        } else {
+            self.check_longform(prop, ctx);
+        }
+    }
+}
+
+impl ObjectShorthand {
+    /// `{ foo }` and `{ foo() {} }`
+    fn check_shorthand(&self, prop: &ObjectProperty, ctx: &LintContext) {
+        let is_string_key =
+            matches!(prop.key, PropertyKey::Expression(Expression::StringLiteral(_)));
+        if prop.method {
+            let Expression::FunctionExpression(func) = &prop.value else { return };
+            let diagnostic = if self.mode == Mode::Never {
+                ObjectShorthandDiagnostic::MethodLongform(prop.span)
+            } else if self.avoid_quotes && is_string_key && self.mode.applies_to_methods() {
+                ObjectShorthandDiagnostic::LiteralMethodLongform(prop.span)
+            } else {
+                return;
+            };
+            ctx.diagnostic_with_fix(diagnostic, || {
+                let source_text = ctx.source_text();
+                #[allow(clippy::cast_possible_truncation)]
+                let key_start = if prop.computed {
+                    source_text[..prop.key.span().start as usize]
+                        .rfind('[')
+                        .map_or(prop.key.span().start, |start| start as u32)
+                } else {
+                    prop.key.span().start
+                };
+                let key = Span::new(key_start, func.span.start).source_text(source_text);
+                let r#async = if func.r#async { "async " } else { "" };
+                let generator = if func.generator { "*" } else { "" };
+                let rest = func.span.source_text(source_text);
+                Fix::new(format!("{}: {async}function{generator}{rest}", key.trim_end()), prop.span)
+            });
+        } else if self.mode == Mode::Never {
+            let PropertyKey::Identifier(key) = &prop.key else { return };
+            ctx.diagnostic_with_fix(ObjectShorthandDiagnostic::PropertyLongform(prop.span), || {
+                Fix::new(format!("{0}: {0}", key.name), prop.span)
+            });
+        }
+    }
+
+    /// `{ foo: foo }` and `{ foo: function() {} }`
+    fn check_longform(&self, prop: &ObjectProperty, ctx: &LintContext) {
+        let is_string_key =
+            matches!(prop.key, PropertyKey::Expression(Expression::StringLiteral(_)));
+        match &prop.value {
+            // `{ foo: function bar() {} }` binds `bar` inside the function, which a method can't.
+            Expression::FunctionExpression(func)
+                if func.id.is_none() && self.mode.applies_to_methods() =>
+            {
+                if self.is_ignored_method(prop, is_string_key) {
+                    return;
+                }
+                ctx.diagnostic_with_fix(
+                    ObjectShorthandDiagnostic::MethodShorthand(prop.span),
+                    || Fix::new(function_to_method(prop, func, ctx), prop.span),
+                );
+            }
+            Expression::ArrowFunctionExpression(arrow)
+                if !arrow.expression
+                    && self.avoid_explicit_return_arrows
+                    && self.mode.applies_to_methods() =>
+            {
+                if self.is_ignored_method(prop, is_string_key) || uses_lexical_identifiers(arrow) {
+                    return;
+                }
+                ctx.diagnostic_with_fix(
+                    ObjectShorthandDiagnostic::MethodShorthand(prop.span),
+                    || Fix::new(arrow_to_method(prop, arrow, ctx), prop.span),
+                );
+            }
+            Expression::Identifier(ident)
+                if !prop.computed && self.mode.applies_to_properties() =>
+            {
+                let key_name = match &prop.key {
+                    PropertyKey::Identifier(key) => key.name.as_str(),
+                    PropertyKey::Expression(Expression::StringLiteral(key))
+                        if !self.avoid_quotes =>
+                    {
+                        key.value.as_str()
+                    }
+                    _ => return,
+                };
+                if key_name != ident.name.as_str() {
+                    return;
+                }
+                ctx.diagnostic_with_fix(
+                    ObjectShorthandDiagnostic::PropertyShorthand(prop.span),
+                    || Fix::new(ident.name.to_string(), prop.span),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn is_ignored_method(&self, prop: &ObjectProperty, is_string_key: bool) -> bool {
+        if self.avoid_quotes && is_string_key {
+            return true;
+        }
+        self.ignore_constructors
+            && matches!(&prop.key, PropertyKey::Identifier(key) if is_constructor(&key.name))
+    }
+}
+
+/// The key of a longform property, including the brackets of a computed key.
+fn longform_key<'a>(prop: &ObjectProperty, ctx: &LintContext<'a>) -> &'a str {
+    Span::new(prop.span.start, prop.value.span().start)
+        .source_text(ctx.source_text())
+        .trim_end()
+        .trim_end_matches(':')
+        .trim_end()
+}
+
+/// `foo: async function* (a) {}` -> `async *foo(a) {}`
+fn function_to_method(prop: &ObjectProperty, func: &Function, ctx: &LintContext) -> String {
+    let key = longform_key(prop, ctx);
+    let r#async = if func.r#async { "async " } else { "" };
+    let generator = if func.generator { "*" } else { "" };
+    let start =
+        func.type_parameters.as_ref().map_or(func.params.span.start, |params| params.span.start);
+    let rest = Span::new(start, func.span.end).source_text(ctx.source_text());
+    format!("{async}{generator}{key}{rest}")
+}
+
+/// `foo: async (a) => {}` -> `async foo(a) {}`
+fn arrow_to_method(
+    prop: &ObjectProperty,
+    arrow: &ArrowFunctionExpression,
+    ctx: &LintContext,
+) -> String {
+    let source_text = ctx.source_text();
+    let key = longform_key(prop, ctx);
+    let r#async = if arrow.r#async { "async " } else { "" };
+    let type_parameters =
+        arrow.type_parameters.as_ref().map_or("", |params| params.span.source_text(source_text));
+    let params = arrow.params.span.source_text(source_text);
+    let params = if params.starts_with('(') { params.to_string() } else { format!("({params})") };
+    let return_type = arrow.return_type.as_ref().map_or("", |ty| ty.span.source_text(source_text));
+    let body = arrow.body.span.source_text(source_text);
+    format!("{async}{key}{type_parameters}{params}{return_type} {body}")
+}
+
+/// Whether the arrow function uses `this`, `super`, `arguments` or `new.target` of its
+/// surrounding scope, which would change meaning inside a method.
+fn uses_lexical_identifiers(arrow: &ArrowFunctionExpression) -> bool {
+    let mut finder = LexicalIdentifierFinder { found: false };
+    finder.visit_arrow_expression(arrow);
+    finder.found
+}
+
+struct LexicalIdentifierFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LexicalIdentifierFinder {
+    fn visit_this_expression(&mut self, _expr: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _expr: &Super) {
+        self.found = true;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.found |= ident.name == "arguments";
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        self.found |= meta.meta.name == "new" && meta.property.name == "target";
+    }
+
+    // Functions, static blocks and class fields have their own `this`
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
+
+    fn visit_property_definition(&mut self, _def: &PropertyDefinition<'a>) {}
+}
+
+/// Whether the name looks like a constructor, i.e. starts with an uppercase letter
+/// after any leading `_`, `$` and digits.
+fn is_constructor(name: &str) -> bool {
+    name.chars().find(|c| !matches!(c, '_' | '$' | '0'..='9')).is_some_and(|c| !c.is_lowercase())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = {y() {}}", None),
+        ("var x = {y}", None),
+        ("var x = {a: b}", None),
+        ("var x = {a: 'a'}", None),
+        ("var x = {'a': 'a'}", None),
+        ("var x = {'a': b}", None),
+        ("var x = {y(x) {}}", None),
+        ("var {x,y,z} = x", None),
+        ("var {x: {y}} = z", None),
+        ("var x = {*x() {}}", None),
+        ("var x = {x: y}", None),
+        ("var x = {x: y, y: z}", None),
+        ("var x = {x: y, y: z, z: 'z'}", None),
+        ("var x = {get() {}}", None),
+        ("var x = {set() {}}", None),
+        ("var x = {get y() {}}", None),
+        ("var x = {set y(z) {}}", None),
+        ("var x = {get: function() {}.bind(this)}", None),
+        ("var x = {[y]: y}", None),
+        ("var x = {[y]: 1}", None),
+        ("var x = {foo: function bar() {}}", None),
+        ("var x = {x: () => {}}", None),
+        ("var x = {x: () => 1}", None),
+        ("doSomething({x: function x() {}})", None),
+        // methods
+        ("var x = {a: a}", Some(serde_json::json!(["methods"]))),
+        ("var x = {a() {}}", Some(serde_json::json!(["methods"]))),
+        // properties
+        ("var x = {a: function() {}}", Some(serde_json::json!(["properties"]))),
+        ("var x = {a}", Some(serde_json::json!(["properties"]))),
+        // never
+        ("var x = {a: a}", Some(serde_json::json!(["never"]))),
+        ("var x = {a: function() {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {get a() {}}", Some(serde_json::json!(["never"]))),
+        // avoidQuotes
+        (
+            "var x = {'a': function() {}}",
+            Some(serde_json::json!(["always", { "avoidQuotes": true }])),
+        ),
+        (
+            "var x = {'a-b': function() {}}",
+            Some(serde_json::json!(["always", { "avoidQuotes": true }])),
+        ),
+        ("var x = {'a': a}", Some(serde_json::json!(["always", { "avoidQuotes": true }]))),
+        ("var x = {a() {}}", Some(serde_json::json!(["always", { "avoidQuotes": true }]))),
+        // ignoreConstructors
+        (
+            "var x = {ConstructorFunction: function() {}}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {_ConstructorFunction: function() {}}",
+            Some(serde_json::json!(["methods", { "ignoreConstructors": true }])),
+        ),
+        // avoidExplicitReturnArrows
+        (
+            "var x = {x: () => 1}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+        (
+            "var x = {x: () => { this.foo(); }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+        (
+            "var x = {x: () => { return arguments; }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+        (
+            "var x = {x: () => { () => this; }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+        (
+            "var x = {x: () => {}}",
+            Some(serde_json::json!(["properties", { "avoidExplicitReturnArrows": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var x = {x: x}", None),
+        ("var x = {'x': x}", None),
+        ("var x = {y: y, x: x}", None),
+        ("var x = {x: function() {}}", None),
+        ("var x = {'x': function() {}}", None),
+        ("var x = {[y]: function() {}}", None),
+        ("var x = {x: function*() {}}", None),
+        ("var x = {x: async function(a, b) { return a; }}", None),
+        ("var x = {a: a}", Some(serde_json::json!(["properties"]))),
+        ("var x = {a: function() {}}", Some(serde_json::json!(["methods"]))),
+        ("var x = {a}", Some(serde_json::json!(["never"]))),
+        ("var x = {a() {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {async *[a](b) {}}", Some(serde_json::json!(["never"]))),
+        ("var x = {'a'() {}}", Some(serde_json::json!(["always", { "avoidQuotes": true }]))),
+        (
+            "var x = {constructorFunction: function() {}}",
+            Some(serde_json::json!(["always", { "ignoreConstructors": true }])),
+        ),
+        (
+            "var x = {x: () => { return 1; }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+        (
+            "var x = {x: async a => { function f() { this; } }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("var x = {x: x}", "var x = {x}", None),
+        ("var x = {'x': x}", "var x = {x}", None),
+        ("var x = {y: y, x: x}", "var x = {y, x}", None),
+        ("var x = {x: function() {}}", "var x = {x() {}}", None),
+        ("var x = {'x': function() {}}", "var x = {'x'() {}}", None),
+        ("var x = {[y]: function() {}}", "var x = {[y]() {}}", None),
+        ("var x = {x: function*() {}}", "var x = {*x() {}}", None),
+        (
+            "var x = {x: async function(a, b) { return a; }}",
+            "var x = {async x(a, b) { return a; }}",
+            None,
+        ),
+        ("var x = {a}", "var x = {a: a}", Some(serde_json::json!(["never"]))),
+        ("var x = {a() {}}", "var x = {a: function() {}}", Some(serde_json::json!(["never"]))),
+        (
+            "var x = {async *[a](b) {}}",
+            "var x = {[a]: async function*(b) {}}",
+            Some(serde_json::json!(["never"])),
+        ),
+        (
+            "var x = {'a'() {}}",
+            "var x = {'a': function() {}}",
+            Some(serde_json::json!(["always", { "avoidQuotes": true }])),
+        ),
+        (
+            "var x = {x: () => { return 1; }}",
+            "var x = {x() { return 1; }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+        (
+            "var x = {x: async a => { function f() { this; } }}",
+            "var x = {async x(a) { function f() { this; } }}",
+            Some(serde_json::json!(["always", { "avoidExplicitReturnArrows": true }])),
+        ),
+    ];
+
+    Tester::new(ObjectShorthand::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
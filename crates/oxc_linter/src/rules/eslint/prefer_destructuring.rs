@@ -0,0 +1,331 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPatternKind, Expression, MemberExpression,
+        SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-destructuring): Use {0} destructuring.")]
+#[diagnostic(severity(warning))]
+struct PreferDestructuringDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferDestructuring(Box<PreferDestructuringConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferDestructuringConfig {
+    variable_declarator: EnabledTypes,
+    assignment_expression: EnabledTypes,
+    /// Also report `const foo = obj.bar` and `const foo = obj[key]`.
+    enforce_for_renamed_properties: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct EnabledTypes {
+    array: bool,
+    object: bool,
+}
+
+impl Default for EnabledTypes {
+    fn default() -> Self {
+        Self { array: true, object: true }
+    }
+}
+
+impl EnabledTypes {
+    /// Types left out of a configured `{ array, object }` object are disabled.
+    fn from_value(value: Option<&serde_json::Value>) -> Self {
+        let enabled = |key| {
+            value
+                .and_then(|value| value.get(key))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false)
+        };
+        Self { array: enabled("array"), object: enabled("object") }
+    }
+}
+
+impl std::ops::Deref for PreferDestructuring {
+    type Target = PreferDestructuringConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require destructuring from arrays and/or objects
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Reading a property into a variable of the same name, or the first
+    /// element of an array into a variable, repeats what destructuring
+    /// expresses more concisely.
+    ///
+    /// ### Options
+    ///
+    /// The first option is either `{ array, object }`, or the same object keyed by
+    /// `VariableDeclarator` and `AssignmentExpression` to configure them separately.
+    /// Both are enabled by default.
+    ///
+    /// The second option `{ enforceForRenamedProperties: true }` also reports
+    /// properties read into a variable of a different name, e.g. `const foo = obj.bar`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const foo = object.foo;
+    /// const first = array[0];
+    ///
+    /// // Good
+    /// const { foo } = object;
+    /// const [first] = array;
+    /// ```
+    PreferDestructuring,
+    style
+);
+
+impl Rule for PreferDestructuring {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut config = PreferDestructuringConfig::default();
+
+        if let Some(types) = value.get(0) {
+            if types.get("VariableDeclarator").is_some()
+                || types.get("AssignmentExpression").is_some()
+            {
+                config.variable_declarator =
+                    EnabledTypes::from_value(types.get("VariableDeclarator"));
+                config.assignment_expression =
+                    EnabledTypes::from_value(types.get("AssignmentExpression"));
+            } else {
+                let enabled = EnabledTypes::from_value(Some(types));
+                config.variable_declarator = enabled;
+                config.assignment_expression = enabled;
+            }
+        }
+
+        config.enforce_for_renamed_properties = value
+            .get(1)
+            .and_then(|options| options.get("enforceForRenamedProperties"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
+                let Some(Expression::MemberExpression(member)) = &decl.init else { return };
+                let Some(kind) =
+                    self.destructuring_kind(&ident.name, member, self.variable_declarator)
+                else {
+                    return;
+                };
+
+                let diagnostic = PreferDestructuringDiagnostic(kind.as_str(), decl.span);
+                // `const foo: T = obj.foo` would lose its annotation.
+                if decl.id.type_annotation.is_some() {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let object_text = member.object().span().source_text(ctx.source_text());
+                let pattern = match &**member {
+                    MemberExpression::StaticMemberExpression(expr)
+                        if expr.property.name == ident.name =>
+                    {
+                        format!("{{ {} }}", ident.name)
+                    }
+                    MemberExpression::ComputedMemberExpression(expr)
+                        if expr.expression.is_number_0() =>
+                    {
+                        format!("[{}]", ident.name)
+                    }
+                    _ => {
+                        ctx.diagnostic(diagnostic);
+                        return;
+                    }
+                };
+                ctx.diagnostic_with_fix(diagnostic, || {
+                    Fix::new(format!("{pattern} = {object_text}"), decl.span)
+                });
+            }
+            AstKind::AssignmentExpression(expr) => {
+                if expr.operator != AssignmentOperator::Assign {
+                    return;
+                }
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+                ) = &expr.left
+                else {
+                    return;
+                };
+                let Expression::MemberExpression(member) = &expr.right else { return };
+                if let Some(kind) =
+                    self.destructuring_kind(&ident.name, member, self.assignment_expression)
+                {
+                    ctx.diagnostic(PreferDestructuringDiagnostic(kind.as_str(), expr.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DestructuringKind {
+    Array,
+    Object,
+}
+
+impl DestructuringKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+}
+
+impl PreferDestructuring {
+    /// Which destructuring `name = member` should use instead, if any.
+    fn destructuring_kind(
+        &self,
+        name: &str,
+        member: &MemberExpression,
+        enabled: EnabledTypes,
+    ) -> Option<DestructuringKind> {
+        if member.optional() || matches!(member.object(), Expression::Super(_)) {
+            return None;
+        }
+        match member {
+            MemberExpression::ComputedMemberExpression(expr) => match &expr.expression {
+                Expression::NumericLiteral(lit) if lit.value.fract() == 0.0 => {
+                    enabled.array.then_some(DestructuringKind::Array)
+                }
+                Expression::StringLiteral(lit) => (enabled.object
+                    && (self.enforce_for_renamed_properties || lit.value.as_str() == name))
+                    .then_some(DestructuringKind::Object),
+                // `{ [key]: foo } = obj` evaluates the key only once, skip keys with side effects.
+                Expression::Identifier(_) | Expression::NumericLiteral(_) => (enabled.object
+                    && self.enforce_for_renamed_properties)
+                    .then_some(DestructuringKind::Object),
+                _ => None,
+            },
+            MemberExpression::StaticMemberExpression(expr) => (enabled.object
+                && (self.enforce_for_renamed_properties || expr.property.name.as_str() == name))
+                .then_some(DestructuringKind::Object),
+            MemberExpression::PrivateFieldExpression(_) => None,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var [foo] = array;", None),
+        ("var { foo } = object;", None),
+        ("var foo;", None),
+        ("var foo = object.bar;", None),
+        ("var foo = object['bar'];", None),
+        ("var foo = object[bar];", None),
+        ("var foo = object?.foo;", None),
+        ("var foo = array[0];", Some(serde_json::json!([{ "array": false }]))),
+        ("var foo = object.foo;", Some(serde_json::json!([{ "object": false, "array": true }]))),
+        (
+            "var foo = array[0];",
+            Some(serde_json::json!([{ "VariableDeclarator": { "array": false, "object": true } }])),
+        ),
+        (
+            "foo = object.foo;",
+            Some(serde_json::json!([{ "VariableDeclarator": { "array": true, "object": true } }])),
+        ),
+        (
+            "var foo = object.bar;",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": false }])),
+        ),
+        (
+            "var foo = object[getKey()];",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": true }])),
+        ),
+        ("var foo = array[index];", None),
+        ("var foo = array[1.5];", None),
+        ("class Foo extends Bar { baz() { var foo = super.foo; } }", None),
+        ("class Foo { #foo; bar() { var foo = this.#foo; } }", None),
+        ("foo += object.foo;", None),
+        ("foo.bar = object.bar;", None),
+        ("({ foo } = object);", None),
+    ];
+
+    let fail = vec![
+        ("var foo = object.foo;", None),
+        ("var foo = object['foo'];", None),
+        ("var foo = array[0];", None),
+        ("var foo = array[1];", None),
+        ("let foo = object.foo;", None),
+        ("const first = arr[0];", None),
+        ("var foo = object.foo.foo;", None),
+        ("var foo: number = object.foo;", None),
+        ("foo = object.foo;", None),
+        ("foo = array[0];", None),
+        ("var foo = array[0];", Some(serde_json::json!([{ "array": true }]))),
+        ("var foo = object.foo;", Some(serde_json::json!([{ "object": true }]))),
+        (
+            "foo = object.foo;",
+            Some(serde_json::json!([{ "AssignmentExpression": { "object": true } }])),
+        ),
+        (
+            "var foo = object.bar;",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": true }])),
+        ),
+        (
+            "var foo = object[bar];",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": true }])),
+        ),
+        (
+            "var foo = object['bar'];",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": true }])),
+        ),
+        (
+            "foo = object.bar;",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("var foo = object.foo;", "var { foo } = object;", None),
+        ("const a = obj.a;", "const { a } = obj;", None),
+        ("var foo = object.bar.foo;", "var { foo } = object.bar;", None),
+        ("var foo = getObject().foo;", "var { foo } = getObject();", None),
+        ("const first = arr[0];", "const [first] = arr;", None),
+        ("let a = 1, b = obj.b;", "let a = 1, { b } = obj;", None),
+        // not fixed
+        ("var foo = object['foo'];", "var foo = object['foo'];", None),
+        ("var foo = array[1];", "var foo = array[1];", None),
+        ("var foo: number = object.foo;", "var foo: number = object.foo;", None),
+        ("foo = object.foo;", "foo = object.foo;", None),
+        (
+            "var foo = object.bar;",
+            "var foo = object.bar;",
+            Some(serde_json::json!([{ "object": true }, { "enforceForRenamedProperties": true }])),
+        ),
+    ];
+
+    Tester::new(PreferDestructuring::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,231 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    operator::BinaryOperator,
+    precedence::{GetPrecedence, Precedence},
+};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-exponentiation-operator): Use the '**' operator instead of 'Math.pow'.")]
+#[diagnostic(severity(warning))]
+struct PreferExponentiationOperatorDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferExponentiationOperator;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of `Math.pow` in favor of the `**` operator
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `**` operator was introduced in ES2016 as a shorter and more readable
+    /// alternative to `Math.pow`, with the same semantics.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const foo = Math.pow(2, 8);
+    /// const bar = Math.pow(a + 1, b);
+    ///
+    /// // Good
+    /// const foo = 2 ** 8;
+    /// const bar = (a + 1) ** b;
+    /// ```
+    PreferExponentiationOperator,
+    style
+);
+
+impl Rule for PreferExponentiationOperator {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+
+        let Expression::MemberExpression(member_expr) = &call_expr.callee else { return };
+        if !member_expr.is_specific_member_access("Math", "pow") {
+            return;
+        }
+        let Expression::Identifier(ident) = member_expr.object().get_inner_expression() else {
+            return;
+        };
+        if !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        let [Argument::Expression(base), Argument::Expression(exponent)] =
+            call_expr.arguments.as_slice()
+        else {
+            return;
+        };
+
+        let diagnostic = PreferExponentiationOperatorDiagnostic(call_expr.span);
+
+        // `Math.pow?.(a, b)` short-circuits when `Math.pow` is missing, `a ** b` doesn't.
+        if call_expr.optional || member_expr.optional() {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let source_text = ctx.source_text();
+            let base_text = base.span().source_text(source_text);
+            let exponent_text = exponent.span().source_text(source_text);
+
+            // `-a ** b` is a syntax error and `a ** b ** c` groups to the right.
+            let base_text = if precedence(base) <= Precedence::Exponential
+                || matches!(base, Expression::UnaryExpression(_) | Expression::AwaitExpression(_))
+            {
+                format!("({base_text})")
+            } else {
+                base_text.to_string()
+            };
+            let exponent_text = if precedence(exponent) < Precedence::Exponential {
+                format!("({exponent_text})")
+            } else {
+                exponent_text.to_string()
+            };
+
+            let replacement = format!("{base_text} ** {exponent_text}");
+            let replacement = if needs_parens(node, call_expr.span, ctx) {
+                format!("({replacement})")
+            } else {
+                replacement
+            };
+            Fix::new(replacement, call_expr.span)
+        });
+    }
+}
+
+/// Precedence of the operator an expression is built from, parenthesized
+/// and primary expressions never need to be wrapped.
+fn precedence(expr: &Expression) -> Precedence {
+    match expr {
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::LogicalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::MemberExpression(_) => expr.precedence(),
+        Expression::TSAsExpression(_) | Expression::TSSatisfiesExpression(_) => {
+            Precedence::Relational
+        }
+        _ => Precedence::Grouping,
+    }
+}
+
+/// Whether `a ** b` has to be wrapped in parentheses where the `Math.pow` call was.
+fn needs_parens(node: &AstNode, span: Span, ctx: &LintContext) -> bool {
+    match ctx.nodes().parent_kind(node.id()) {
+        Some(
+            AstKind::UnaryExpression(_)
+            | AstKind::AwaitExpression(_)
+            | AstKind::TaggedTemplateExpression(_)
+            | AstKind::TSNonNullExpression(_),
+        ) => true,
+        Some(AstKind::BinaryExpression(expr)) => {
+            expr.operator == BinaryOperator::Exponential && expr.left.span() == span
+        }
+        Some(AstKind::MemberExpression(expr)) => expr.object().span() == span,
+        Some(AstKind::CallExpression(expr)) => expr.callee.span() == span,
+        Some(AstKind::NewExpression(expr)) => expr.callee.span() == span,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("Object.pow(a, b)", None),
+        ("Math.max(a, b)", None),
+        ("Math", None),
+        ("Math(a, b)", None),
+        ("pow", None),
+        ("pow(a, b)", None),
+        ("Math.pow", None),
+        ("Math.Pow(a, b)", None),
+        ("math.pow(a, b)", None),
+        ("foo.Math.pow(a, b)", None),
+        ("new Math.pow(a, b)", None),
+        ("Math[pow](a, b)", None),
+        ("globalThis.Object.pow(a, b)", None),
+        ("Math.pow(a)", None),
+        ("Math.pow(a, b, c)", None),
+        ("Math.pow(...args)", None),
+        ("let Math; Math.pow(a, b);", None),
+        ("if (foo) { const Math = 1; Math.pow(a, b); }", None),
+        ("var x = function Math() { Math.pow(a, b); }", None),
+        ("function foo(Math) { Math.pow(a, b); }", None),
+        ("function foo() { Math.pow(a, b); var Math; }", None),
+        ("a ** b", None),
+    ];
+
+    let fail = vec![
+        ("Math.pow(a, b)", None),
+        ("(Math).pow(a, b)", None),
+        ("Math['pow'](a, b)", None),
+        ("Math.pow(a + 1, 2)", None),
+        ("Math.pow(-a, b)", None),
+        ("Math.pow(a, b + c)", None),
+        ("Math.pow(a, b) + c", None),
+        ("-Math.pow(a, b)", None),
+        ("Math.pow(a, b).toString()", None),
+        ("Math.pow?.(a, b)", None),
+        ("foo(Math.pow(a, b))", None),
+        ("function foo(a, b) { return Math.pow(a, b); }", None),
+    ];
+
+    let fix = vec![
+        ("Math.pow(a, b)", "a ** b", None),
+        ("Math.pow(2, 8)", "2 ** 8", None),
+        ("Math['pow'](a, b)", "a ** b", None),
+        ("(Math).pow(a, b)", "a ** b", None),
+        ("Math.pow(a.foo, b[0])", "a.foo ** b[0]", None),
+        ("Math.pow(foo(), bar())", "foo() ** bar()", None),
+        // base needs parens
+        ("Math.pow(a + 1, 2)", "(a + 1) ** 2", None),
+        ("Math.pow(a * b, c)", "(a * b) ** c", None),
+        ("Math.pow(-a, b)", "(-a) ** b", None),
+        ("Math.pow(-1, b)", "(-1) ** b", None),
+        ("Math.pow(typeof a, b)", "(typeof a) ** b", None),
+        ("Math.pow(a ** b, c)", "(a ** b) ** c", None),
+        ("Math.pow(a ? b : c, d)", "(a ? b : c) ** d", None),
+        ("Math.pow((a + 1), 2)", "(a + 1) ** 2", None),
+        // exponent needs parens
+        ("Math.pow(a, b + c)", "a ** (b + c)", None),
+        ("Math.pow(a, b ** c)", "a ** b ** c", None),
+        ("Math.pow(a, -b)", "a ** -b", None),
+        ("Math.pow(a, (b, c))", "a ** (b, c)", None),
+        // result needs parens
+        ("Math.pow(a, b) + c", "a ** b + c", None),
+        ("c * Math.pow(a, b)", "c * a ** b", None),
+        ("-Math.pow(a, b)", "-(a ** b)", None),
+        ("Math.pow(a, b) ** c", "(a ** b) ** c", None),
+        ("c ** Math.pow(a, b)", "c ** a ** b", None),
+        ("Math.pow(a, b).toString()", "(a ** b).toString()", None),
+        ("Math.pow(a, b)()", "(a ** b)()", None),
+        ("foo(Math.pow(a, b))", "foo(a ** b)", None),
+        // not fixed
+        ("Math.pow?.(a, b)", "Math.pow?.(a, b)", None),
+    ];
+
+    Tester::new(PreferExponentiationOperator::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,91 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(require-await): Async function has no 'await' expression.")]
+#[diagnostic(severity(warning), help("Consider removing the 'async' keyword."))]
+struct RequireAwaitDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireAwait;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow async functions which have no `await` expression.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Asynchronous functions that don’t use `await` might not need to be asynchronous
+    /// functions and could be the unintentional result of refactoring.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// async function foo() {
+    ///   doSomething();
+    /// }
+    /// ```
+    RequireAwait,
+    pedantic
+);
+
+impl Rule for RequireAwait {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let nodes = ctx.nodes();
+        // `HasAwait` is set on functions which directly contain an `await` or `for await...of`
+        if !nodes.is_async(node.id()) || nodes.is_generator(node.id()) || node.flags().has_await() {
+            return;
+        }
+        let span = match node.kind() {
+            AstKind::Function(func) => {
+                func.id.as_ref().map_or_else(|| func.span, |ident| ident.span)
+            }
+            AstKind::ArrowFunctionExpression(arrow) => arrow.span,
+            _ => return,
+        };
+        ctx.diagnostic(RequireAwaitDiagnostic(span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function foo() { await doSomething(); }",
+        "(async function() { await doSomething(); })",
+        "async () => { await doSomething(); }",
+        "async () => await doSomething()",
+        "({ async foo() { await doSomething(); } })",
+        "class A { async foo() { await doSomething(); } }",
+        "async function foo() { if (bar) { await doSomething(); } }",
+        "async function foo() { for await (const x of xs) { doSomething(x); } }",
+        "async function foo() { function bar() {} await doSomething(); }",
+        "async function* foo() { yield 1; }",
+        "function foo() { doSomething(); }",
+        "await doSomething();",
+    ];
+
+    let fail = vec![
+        "async function foo() { doSomething(); }",
+        "(async function() { doSomething(); })",
+        "async () => { doSomething(); }",
+        "async () => doSomething()",
+        "({ async foo() { doSomething(); } })",
+        "class A { async foo() { doSomething(); } }",
+        "async function foo() {}",
+        "async function foo() { return fetch(url); }",
+        "async function foo() { async function bar() { await doSomething(); } }",
+        "async function foo() { const bar = async () => { await doSomething(); }; }",
+        "async function foo() { for (const x of xs) { doSomething(x); } }",
+    ];
+
+    Tester::new(RequireAwait::NAME, pass, fail).test_and_snapshot();
+}
@@ -20,6 +20,7 @@ declare_oxc_lint!(
     /// ### What it does
     ///
     /// This rule generates warnings for generator functions that do not have the yield keyword.
+    /// Empty generator functions are reported as well.
     ///
     /// ### Why is this bad?
     ///
@@ -38,10 +39,7 @@ declare_oxc_lint!(
 impl Rule for RequireYield {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::Function(func) = node.kind() {
-            if !node.flags().has_yield()
-                && func.generator
-                && func.body.as_ref().is_some_and(|body| !body.statements.is_empty())
-            {
+            if func.generator && func.body.is_some() && !node.flags().has_yield() {
                 let span = func.id.as_ref().map_or_else(|| func.span, |ident| ident.span);
                 ctx.diagnostic(RequireYieldDiagnostic(span));
             }
@@ -56,14 +54,12 @@ fn test() {
     let pass = vec![
         "function foo() { return 0; }",
         "function* foo() { yield 0; }",
-        "function* foo() { }",
+        "function* foo() { yield* bar(); }",
         "(function* foo() { yield 0; })();",
-        "(function* foo() { })();",
         "function* foo() { while (true) { yield 0; } }",
+        "function* foo() { if (bar) { yield; } }",
         "var obj = { *foo() { yield 0; } };",
-        "var obj = { *foo() { } };",
         "class A { *foo() { yield 0; } };",
-        "class A { *foo() { } };",
         "() => {}",
     ];
 
@@ -74,6 +70,11 @@ fn test() {
         "class A { *foo() { return 0; } }",
         "function* foo() { function* bar() { yield 0; } }",
         "function* foo() { function* bar() { return 0; } yield 0; }",
+        "function* foo() { const bar = function* () { yield 0; }; }",
+        "function* foo() { }",
+        "(function* foo() { })();",
+        "var obj = { *foo() { } };",
+        "class A { *foo() { } };",
     ];
 
     Tester::new(RequireYield::NAME, pass, fail).test_and_snapshot();
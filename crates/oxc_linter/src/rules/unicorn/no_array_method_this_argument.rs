@@ -0,0 +1,198 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode, Fix};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-array-method-this-argument): Do not use the `this` argument in `{1}()`.")]
+#[diagnostic(
+    severity(warning),
+    help("Arrow functions ignore the `this` argument, remove it or use a regular function.")
+)]
+struct NoArrayMethodThisArgumentDiagnostic(#[label] pub Span, String);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayMethodThisArgument;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows passing a `thisArg` to array iteration methods when the callback is an arrow
+    /// function.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Arrow functions don't have their own `this`, so the `thisArg` is ignored. It is dead
+    /// code, and usually means the callback was expected to see a different `this`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// array.map((x) => x * this.factor, this);
+    ///
+    /// // Good
+    /// array.map((x) => x * this.factor);
+    /// array.map(function (x) { return x * this.factor; }, this);
+    /// ```
+    NoArrayMethodThisArgument,
+    correctness
+);
+
+/// Methods taking a callback as first argument and `thisArg` as second argument.
+///
+/// `reduce` and `reduceRight` are not included, their second argument is the initial value.
+const ITERATION_METHODS: [&str; 10] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "forEach",
+    "map",
+    "some",
+];
+
+/// Libraries with methods of the same name that don't take a `thisArg`.
+const IGNORED_OBJECTS: [&str; 7] = ["_", "lodash", "underscore", "$", "jQuery", "Vue", "Children"];
+
+impl Rule for NoArrayMethodThisArgument {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+
+        let (callback_index, separator) = if is_method_call(
+            call_expr,
+            Some(&["Array"]),
+            Some(&["from", "fromAsync"]),
+            Some(3),
+            Some(3),
+        ) {
+            // `Array.from(iterable, mapFn, thisArg)`
+            (1, '.')
+        } else if is_method_call(call_expr, None, Some(&ITERATION_METHODS), Some(2), Some(2))
+            && !is_ignored_object(call_expr)
+        {
+            (0, '#')
+        } else {
+            return;
+        };
+
+        let Some(Argument::Expression(callback)) = call_expr.arguments.get(callback_index) else {
+            return;
+        };
+        if !matches!(callback.without_parenthesized(), Expression::ArrowFunctionExpression(_)) {
+            return;
+        }
+        let Some(Argument::Expression(this_arg)) = call_expr.arguments.get(callback_index + 1)
+        else {
+            return;
+        };
+
+        let Expression::MemberExpression(member_expr) = call_expr.callee.without_parenthesized()
+        else {
+            return;
+        };
+        let Some(method_name) = member_expr.static_property_name() else { return };
+
+        let diagnostic = NoArrayMethodThisArgumentDiagnostic(
+            this_arg.span(),
+            format!("Array{separator}{method_name}"),
+        );
+        // Removing an argument with side effects, as in `array.map(x => x, init())`, changes
+        // what the code does.
+        if has_side_effects(this_arg) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, || {
+            Fix::delete(Span::new(callback.span().end, this_arg.span().end))
+        });
+    }
+}
+
+/// Everything but identifiers, `this` and literals
+fn has_side_effects(expr: &Expression) -> bool {
+    let expr = expr.without_parenthesized();
+    !(expr.is_literal()
+        || matches!(expr, Expression::Identifier(_) | Expression::ThisExpression(_)))
+}
+
+fn is_ignored_object(call_expr: &CallExpression) -> bool {
+    let Expression::MemberExpression(member_expr) = call_expr.callee.without_parenthesized() else {
+        return false;
+    };
+    match member_expr.object().without_parenthesized() {
+        Expression::Identifier(ident) => IGNORED_OBJECTS.contains(&ident.name.as_str()),
+        Expression::MemberExpression(object) => object.static_property_name() == Some("Children"),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "array.map(x => x)",
+        "array.map(function (x) { return x; }, this)",
+        "array.map(callback, this)",
+        "array.forEach(x => x, ...args)",
+        "array.map(...callbacks, this)",
+        "array.reduce(x => x, 0)",
+        "array.reduce(fn, 0)",
+        "array.reduceRight((total, x) => total + x, 0)",
+        "array.includes(x => x, 1)",
+        "array[map](x => x, this)",
+        "map(x => x, this)",
+        "array.map(x => x, this, extra)",
+        "_.map(array, x => x)",
+        "lodash.forEach(x => x, this)",
+        "$.map(x => x, this)",
+        "React.Children.forEach(x => x, this)",
+        "Array.from(iterable, x => x)",
+        "Array.from(iterable, function (x) { return x; }, this)",
+        "NotArray.from(iterable, x => x, this)",
+    ];
+
+    let fail = vec![
+        "array.map(x => x, this)",
+        "array.forEach((x) => { x(); }, thisArg)",
+        "array.filter(async x => x, this)",
+        "array.every(x => x, this)",
+        "array.find(x => x, this)",
+        "array.findIndex(x => x, this)",
+        "array.findLast(x => x, this)",
+        "array.findLastIndex(x => x, this)",
+        "array.flatMap(x => x, this)",
+        "array.some(x => x, this)",
+        "array?.map((x => x), this)",
+        "array.map(x => x, this,)",
+        "Array.from(iterable, x => x, this)",
+        "Array.fromAsync(iterable, x => x, this)",
+        "array.map(x => x, init())",
+        "array.map(x => x, { value: 1 })",
+    ];
+
+    let fix = vec![
+        ("array.map(x => x, this)", "array.map(x => x)", None),
+        ("array.forEach((x) => { x(); }, thisArg)", "array.forEach((x) => { x(); })", None),
+        ("array.map(x => x, this,)", "array.map(x => x,)", None),
+        ("array.map((x => x), this)", "array.map((x => x))", None),
+        ("Array.from(iterable, x => x, this)", "Array.from(iterable, x => x)", None),
+        ("array.map(x => x, null)", "array.map(x => x)", None),
+        // not fixed
+        ("array.map(x => x, init())", "array.map(x => x, init())", None),
+        ("array.map(x => x, { value: 1 })", "array.map(x => x, { value: 1 })", None),
+    ];
+
+    Tester::new(NoArrayMethodThisArgument::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
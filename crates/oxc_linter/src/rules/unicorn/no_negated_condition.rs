@@ -140,6 +140,8 @@ fn test() {
         r"if(!a) {b()} else {c()}",
         r"if(!!a) b(); else c();",
         r"(!!a) ? b() : c();",
+        r"if (!x) {} else {}",
+        r"x !== y ? a : b",
     ];
 
     Tester::new(NoNegatedCondition::NAME, pass, fail).test_and_snapshot();
@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{Argument, ArrayExpression, ArrayExpressionElement, CallExpression, Expression},
+    ast::{
+        Argument, ArrayExpression, ArrayExpressionElement, CallExpression, Expression,
+        ObjectExpression, ObjectPropertyKind, PropertyKind,
+    },
     AstKind,
 };
 use oxc_diagnostics::{
@@ -15,7 +18,7 @@ use crate::{
     },
     context::LintContext,
     rule::Rule,
-    AstNode,
+    AstNode, Fix,
 };
 
 #[derive(Debug, Error, Diagnostic)]
@@ -145,21 +148,37 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
 
             let span = Span::new(spread_elem.span.start, spread_elem.span.start + 3);
 
+            let report = |diagnostic, elements: Option<&'a str>| match elements {
+                Some(elements) => {
+                    ctx.diagnostic_with_fix(diagnostic, || Fix::new(elements, spread_elem.span));
+                }
+                None => ctx.diagnostic(diagnostic),
+            };
+
             match node.kind() {
-                AstKind::ObjectExpression(_) => {
+                AstKind::ObjectExpression(object_expr) => {
                     // { ...{ } }
                     if matches!(parent_parent.kind(), AstKind::ObjectExpression(_)) {
-                        ctx.diagnostic(NoUselessSpreadDiagnostic::SpreadInList(span, "object"));
+                        report(
+                            NoUselessSpreadDiagnostic::SpreadInList(span, "object"),
+                            object_properties_text(object_expr, ctx),
+                        );
                     }
                 }
-                AstKind::ArrayExpression(_) => match parent_parent.kind() {
+                AstKind::ArrayExpression(array_expr) => match parent_parent.kind() {
                     // ...[ ]
                     AstKind::ArrayExpressionElement(_) => {
-                        ctx.diagnostic(NoUselessSpreadDiagnostic::SpreadInList(span, "array"));
+                        report(
+                            NoUselessSpreadDiagnostic::SpreadInList(span, "array"),
+                            array_elements_text(array_expr, ctx),
+                        );
                     }
                     // foo(...[ ])
                     AstKind::Argument(_) => {
-                        ctx.diagnostic(NoUselessSpreadDiagnostic::SpreadInArguments(span));
+                        report(
+                            NoUselessSpreadDiagnostic::SpreadInArguments(span),
+                            array_elements_text(array_expr, ctx),
+                        );
                     }
                     _ => {}
                 },
@@ -171,6 +190,37 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
     }
 }
 
+/// The source of the elements of `[a, b]`, which replaces `...[a, b]`. Empty arrays and arrays
+/// with holes are not flattened, spreading `[a, , b]` passes `undefined` for the hole.
+fn array_elements_text<'a>(
+    array_expr: &ArrayExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a str> {
+    if array_expr.elements.iter().any(|elem| matches!(elem, ArrayExpressionElement::Elision(_))) {
+        return None;
+    }
+    let first = array_expr.elements.first()?.span();
+    let last = array_expr.elements.last()?.span();
+    Some(ctx.source_range(Span::new(first.start, last.end)))
+}
+
+/// The source of the properties of `{ a, b }`, which replaces `...{ a, b }`. Spreading invokes
+/// getters and doesn't set the prototype, so objects with accessors or `__proto__` are kept.
+fn object_properties_text<'a>(
+    object_expr: &ObjectExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a str> {
+    let has_accessor = object_expr.properties.iter().any(|prop| {
+        matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.kind != PropertyKind::Init)
+    });
+    if has_accessor || object_expr.has_proto() {
+        return None;
+    }
+    let first = object_expr.properties.first()?.span();
+    let last = object_expr.properties.last()?.span();
+    Some(ctx.source_range(Span::new(first.start, last.end)))
+}
+
 fn check_useless_iterable_to_array<'a>(
     node: &AstNode<'a>,
     array_expr: &ArrayExpression<'a>,
@@ -458,6 +508,8 @@ fn test() {
         r"[...Promise.allSettled(foo)]",
         r"[...await Promise.all(foo, extraArgument)]",
         r"[...new Array(3)]",
+        r"[...new Set(x)]",
+        r"const array = [a, ...new Set(b), c]",
     ];
 
     let fail = vec![
@@ -561,7 +613,25 @@ fn test() {
             availableAggregates.push(...['p50', 'p75', 'p95', 'p99']);
         }
         ",
+        r"({...{get a() { return 1; }}})",
+    ];
+
+    let fix = vec![
+        (r"const array = [...[a]]", r"const array = [a]", None),
+        (r"const object = {...{a}}", r"const object = {a}", None),
+        (r"foo(...[a, b])", r"foo(a, b)", None),
+        (r"new Foo(a, ...[b, c], d)", r"new Foo(a, b, c, d)", None),
+        (r"const array = [...[a,],]", r"const array = [a,]", None),
+        (r"const array = [...(( [a] ))]", r"const array = [a]", None),
+        (r"const array = [a, ...[b, (( c ))], d]", r"const array = [a, b, (( c )), d]", None),
+        (r"({a:1, ...{a: 2}})", r"({a:1, a: 2})", None),
+        // not fixed
+        (r"const array = [...[]]", r"const array = [...[]]", None),
+        (r"const object = {...{}}", r"const object = {...{}}", None),
+        (r"foo(...[a, , b])", r"foo(...[a, , b])", None),
+        (r"({...{get a() { return 1; }}})", r"({...{get a() { return 1; }}})", None),
+        (r"const map = new Map([...iterable])", r"const map = new Map([...iterable])", None),
     ];
 
-    Tester::new(NoUselessSpread::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoUselessSpread::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -7,9 +7,9 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -53,6 +53,29 @@ fn is_apply_signature(first_arg: &Argument, second_arg: &Argument) -> bool {
     }
 }
 
+/// Whether the expression can be moved into `Reflect.apply()` without changing its behavior.
+fn is_side_effect_free(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::Identifier(_) | Expression::ThisExpression(_) => true,
+        Expression::MemberExpression(member_expr) => {
+            !member_expr.optional()
+                && !member_expr.is_computed()
+                && is_side_effect_free(member_expr.object())
+        }
+        _ => false,
+    }
+}
+
+/// `Reflect.apply(target, thisArg, args)` with the source text of the given parts.
+fn reflect_apply(ctx: &LintContext, target: Span, this_arg: Span, args: Span) -> String {
+    format!(
+        "Reflect.apply({}, {}, {})",
+        ctx.source_range(target),
+        ctx.source_range(this_arg),
+        ctx.source_range(args)
+    )
+}
+
 fn is_static_property_name_equal(expr: &MemberExpression, value: &str) -> bool {
     expr.static_property_name().is_some_and(|name| name == value)
 }
@@ -80,7 +103,19 @@ impl Rule for PreferReflectApply {
         if is_static_property_name_equal(member_expr, "apply")
             && matches!(call_expr.arguments.as_slice(), [first, second] if is_apply_signature(first, second))
         {
-            ctx.diagnostic(PreferReflectApplyDiagnostic(call_expr.span));
+            let diagnostic = PreferReflectApplyDiagnostic(call_expr.span);
+            let target = member_expr.object();
+            if is_side_effect_free(target) {
+                let fixed = reflect_apply(
+                    ctx,
+                    target.span(),
+                    call_expr.arguments[0].span(),
+                    call_expr.arguments[1].span(),
+                );
+                ctx.diagnostic_with_fix(diagnostic, || Fix::new(fixed, call_expr.span));
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
             return;
         }
 
@@ -101,7 +136,14 @@ impl Rule for PreferReflectApply {
                     if iden.name == "Function"
                         && matches!(call_expr.arguments.as_slice(), [_, second, third] if is_apply_signature(second, third))
                     {
-                        ctx.diagnostic(PreferReflectApplyDiagnostic(call_expr.span));
+                        let [target, this_arg, args] = call_expr.arguments.as_slice() else {
+                            return;
+                        };
+                        let fixed = reflect_apply(ctx, target.span(), this_arg.span(), args.span());
+                        ctx.diagnostic_with_fix(
+                            PreferReflectApplyDiagnostic(call_expr.span),
+                            || Fix::new(fixed, call_expr.span),
+                        );
                     }
                 }
             }
@@ -144,5 +186,24 @@ fn test() {
         ("foo[\"apply\"](null, [42]);", None),
     ];
 
-    Tester::new(PreferReflectApply::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.apply(null, [42]);", "Reflect.apply(foo, null, [42]);", None),
+        ("foo.bar.apply(this, arguments);", "Reflect.apply(foo.bar, this, arguments);", None),
+        ("(foo).apply(null, [a, b]);", "Reflect.apply((foo), null, [a, b]);", None),
+        (
+            "Function.prototype.apply.call(foo, null, [42]);",
+            "Reflect.apply(foo, null, [42]);",
+            None,
+        ),
+        (
+            "Function.prototype.apply.call(foo.bar(), this, arguments);",
+            "Reflect.apply(foo.bar(), this, arguments);",
+            None,
+        ),
+        // The target could have side effects, report only
+        ("foo().apply(null, [42]);", "foo().apply(null, [42]);", None),
+        ("foo[bar].apply(null, [42]);", "foo[bar].apply(null, [42]);", None),
+    ];
+
+    Tester::new(PreferReflectApply::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -52,6 +52,8 @@ impl<'a> Binder for VariableDeclarator<'a> {
             }
         }
 
+        let hoisted_scope_id = var_scope_ids.last().copied().unwrap_or(current_scope_id);
+
         self.id.bound_names(&mut |ident| {
             let span = ident.span;
             let name = &ident.name;
@@ -72,6 +74,7 @@ impl<'a> Binder for VariableDeclarator<'a> {
             for scope_id in &var_scope_ids {
                 builder.scope.add_binding(*scope_id, name.to_compact_str(), symbol_id);
             }
+            builder.scope.add_hoisted_var(hoisted_scope_id, symbol_id);
         });
     }
 }
@@ -140,6 +143,7 @@ impl<'a> Binder for Function<'a> {
                     excludes,
                 );
                 ident.symbol_id.set(Some(symbol_id));
+                builder.scope.add_hoisted_function(parent_scope_id, symbol_id);
             } else if self.r#type == FunctionType::FunctionExpression {
                 // https://tc39.es/ecma262/#sec-runtime-semantics-instantiateordinaryfunctionexpression
                 // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
@@ -0,0 +1,74 @@
+use oxc_ast::{
+    ast::{BindingPattern, BindingPatternKind},
+    AstKind,
+};
+
+use crate::{AstNodeId, Semantic, SymbolId};
+
+/// A name bound by a declaration and its place in the destructuring pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternBinding {
+    pub symbol_id: SymbolId,
+    /// Bound by a rest element, e.g. `rest` in `const { a, ...rest } = obj`.
+    pub is_rest: bool,
+    /// Has a default value, e.g. `b` in `const { b = 1 } = obj`.
+    pub has_default: bool,
+    /// Number of object and array patterns the name is nested in,
+    /// `0` for `const a = 1` and `1` for `const { a } = obj`.
+    pub depth: u32,
+}
+
+impl<'a> Semantic<'a> {
+    /// The names bound by a variable declarator, formal parameter or catch clause,
+    /// in source order. Other nodes bind no names through patterns.
+    pub fn pattern_bindings(&self, node_id: AstNodeId) -> Vec<PatternBinding> {
+        let pattern = match self.nodes.kind(node_id) {
+            AstKind::VariableDeclarator(decl) => &decl.id,
+            AstKind::FormalParameter(param) => &param.pattern,
+            AstKind::CatchClause(clause) => match &clause.param {
+                Some(param) => param,
+                None => return vec![],
+            },
+            _ => return vec![],
+        };
+        let mut bindings = vec![];
+        collect_pattern_bindings(pattern, false, false, 0, &mut bindings);
+        bindings
+    }
+}
+
+fn collect_pattern_bindings(
+    pattern: &BindingPattern,
+    is_rest: bool,
+    has_default: bool,
+    depth: u32,
+    bindings: &mut Vec<PatternBinding>,
+) {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                bindings.push(PatternBinding { symbol_id, is_rest, has_default, depth });
+            }
+        }
+        BindingPatternKind::ObjectPattern(pat) => {
+            for prop in &pat.properties {
+                collect_pattern_bindings(&prop.value, is_rest, false, depth + 1, bindings);
+            }
+            if let Some(rest) = &pat.rest {
+                collect_pattern_bindings(&rest.argument, true, false, depth + 1, bindings);
+            }
+        }
+        BindingPatternKind::ArrayPattern(pat) => {
+            for element in pat.elements.iter().flatten() {
+                collect_pattern_bindings(element, is_rest, false, depth + 1, bindings);
+            }
+            if let Some(rest) = &pat.rest {
+                collect_pattern_bindings(&rest.argument, true, false, depth + 1, bindings);
+            }
+        }
+        // The default belongs to the pattern on its left, e.g. `b` in `{ b = 1 }`.
+        BindingPatternKind::AssignmentPattern(pat) => {
+            collect_pattern_bindings(&pat.left, is_rest, true, depth, bindings);
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, Visit};
 use oxc_diagnostics::Error;
-use oxc_span::{CompactStr, SourceType, Span};
+use oxc_span::{CompactStr, GetSpan, SourceType, Span};
 use oxc_syntax::{
     identifier::is_identifier_name,
     module_record::{ExportImportName, ExportLocalName, ModuleRecord},
@@ -45,6 +45,8 @@ pub struct SemanticBuilder<'a> {
     pub current_node_flags: NodeFlags,
     pub current_symbol_flags: SymbolFlags,
     pub current_scope_id: ScopeId,
+    /// Number of children added so far for each node on the path to the current node
+    child_counts: Vec<u32>,
     /// Stores current `AstKind::Function` and `AstKind::ArrowFunctionExpression` during AST visit
     pub function_stack: Vec<AstNodeId>,
     // To make a namespace/module value like
@@ -96,6 +98,7 @@ impl<'a> SemanticBuilder<'a> {
             in_type_definition: false,
             current_reference_flag: ReferenceFlag::empty(),
             current_scope_id,
+            child_counts: vec![],
             function_stack: vec![],
             namespace_stack: vec![],
             nodes: AstNodes::default(),
@@ -197,8 +200,13 @@ impl<'a> SemanticBuilder<'a> {
         }
 
         let ast_node = AstNode::new(kind, self.current_scope_id, self.cfg.current_node_ix, flags);
+        let child_index = self.child_counts.last_mut().map_or(0, |count| {
+            *count += 1;
+            *count - 1
+        });
+        self.child_counts.push(0);
         self.current_node_id = if matches!(kind, AstKind::Program(_)) {
-            let id = self.nodes.add_node(ast_node, None);
+            let id = self.nodes.add_node(ast_node, None, child_index);
             #[allow(unsafe_code)]
             // SAFETY: `ast_node` is a `Program` and hence the root of the tree.
             unsafe {
@@ -206,11 +214,12 @@ impl<'a> SemanticBuilder<'a> {
             }
             id
         } else {
-            self.nodes.add_node(ast_node, Some(self.current_node_id))
+            self.nodes.add_node(ast_node, Some(self.current_node_id), child_index)
         };
     }
 
     fn pop_ast_node(&mut self) {
+        self.child_counts.pop();
         if let Some(parent_id) = self.nodes.parent_id(self.current_node_id) {
             self.current_node_id = parent_id;
         }
@@ -222,7 +231,6 @@ impl<'a> SemanticBuilder<'a> {
 
     pub fn strict_mode(&self) -> bool {
         self.current_scope_flags().is_strict_mode()
-            || self.current_node_flags.contains(NodeFlags::Class)
     }
 
     pub fn set_function_node_flag(&mut self, flag: NodeFlags) {
@@ -231,6 +239,36 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Set `NodeFlags::HasThis` on the functions a `this` expression at `span` belongs to:
+    /// the closest non-arrow function and the arrow functions in between.
+    ///
+    /// Class field initializers and static blocks have their own `this`.
+    fn set_this_node_flags(&mut self, span: Span) {
+        let mut function_ids = vec![];
+        for node_id in self.nodes.ancestors(self.current_node_id) {
+            match self.nodes.kind(node_id) {
+                AstKind::ArrowFunctionExpression(_) => function_ids.push(node_id),
+                AstKind::Function(_) => {
+                    function_ids.push(node_id);
+                    break;
+                }
+                AstKind::StaticBlock(_) => break,
+                AstKind::PropertyDefinition(def) => {
+                    let value_span = def.value.as_ref().map(GetSpan::span);
+                    if value_span
+                        .is_some_and(|value| value.start <= span.start && span.end <= value.end)
+                    {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        for function_id in function_ids {
+            *self.nodes.get_node_mut(function_id).flags_mut() |= NodeFlags::HasThis;
+        }
+    }
+
     /// Declares a `Symbol` for the node, adds it to symbol table, and binds it to the scope.
     ///
     /// includes: the `SymbolFlags` that node has in addition to its declaration type (eg: export, ambient, etc.)
@@ -370,18 +408,12 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             if flags.contains(ScopeFlags::Top) { None } else { Some(self.current_scope_id) };
 
         let mut flags = flags;
-        // Inherit strict mode for functions
-        // https://tc39.es/ecma262/#sec-strict-mode-code
         if let Some(parent_scope_id) = parent_scope_id {
-            let mut strict_mode = self.scope.root_flags().is_strict_mode();
+            // Code nested in strict mode code is strict, e.g. in a class body or a function
+            // with a `"use strict"` directive.
+            // https://tc39.es/ecma262/#sec-strict-mode-code
             let parent_scope_flags = self.scope.get_flags(parent_scope_id);
-
-            if !strict_mode
-                && parent_scope_flags.is_function()
-                && parent_scope_flags.is_strict_mode()
-            {
-                strict_mode = true;
-            }
+            let strict_mode = parent_scope_flags.is_strict_mode();
 
             // inherit flags for non-function scopes
             if !flags.contains(ScopeFlags::Function) {
@@ -530,6 +562,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         let statement_state = self
             .cfg
             .before_statement(self.current_node_id, StatementControlFlowType::DoesNotUseContinue);
+        // a labeled continue jumps to the loop owning the label instead of the innermost loop
+        let mut continues_labeled_loop = false;
         /* cfg */
 
         if let Some(continue_target) = &stmt.label {
@@ -541,7 +575,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
                 let (_, _, continue_) = self.cfg.ast_node_to_break_continue.iter().rev().find(|x| x.0 == label_found.1)
                                         .expect("expected a corresponding break/continue array for a found label owning ast node");
                 if let Some(continue_) = continue_ {
-                    self.cfg.basic_blocks_with_breaks[*continue_].push(self.cfg.current_node_ix);
+                    self.cfg.basic_blocks_with_continues[*continue_].push(self.cfg.current_node_ix);
+                    continues_labeled_loop = true;
                 } else {
                     self.cfg
                     .basic_blocks_with_breaks
@@ -577,7 +612,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         let current_node_ix = self.cfg.current_node_ix;
         // todo: assert on this instead when continues which
         // aren't in iterations are nonrecoverable errors
-        if let Some(continues) = self.cfg.basic_blocks_with_continues.last_mut() {
+        if let Some(continues) =
+            self.cfg.basic_blocks_with_continues.last_mut().filter(|_| !continues_labeled_loop)
+        {
             continues.push(current_node_ix);
         }
         self.cfg.put_unreachable();
@@ -1000,10 +1037,6 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
 
         /* cfg - bb after if statement joins consequent and alternate */
         let after_if_graph_ix = self.cfg.new_basic_block();
-
-        if stmt.alternate.is_some() {
-            self.cfg.put_unreachable();
-        }
         //  else {
         self.cfg.add_edge(after_consequent_stmt_graph_ix, after_if_graph_ix, EdgeType::Normal);
         // }
@@ -1217,10 +1250,14 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         /* cfg */
 
         self.visit_expression(&stmt.argument);
-        // todo - put unreachable after throw statement
 
         /* cfg */
         self.cfg.put_throw(throw_expr);
+
+        /* cfg - put unreachable after throw */
+        let _ = self.cfg.new_basic_block();
+        self.cfg.put_unreachable();
+
         self.cfg.after_statement(
             &statement_state,
             self.current_node_id,
@@ -1419,6 +1456,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             after_try_statement_block_ix,
             EdgeType::Normal,
         );
+        // In a try-catch, the catch block is skipped when the try block completes successfully
+        if stmt.finalizer.is_none() {
+            self.cfg.add_edge(end_of_try_block_ix, after_try_statement_block_ix, EdgeType::Normal);
+        }
 
         self.cfg.after_statement(
             &statement_state,
@@ -1571,12 +1612,13 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         }
         let kind = AstKind::Class(self.alloc(class));
 
-        // FIXME(don): Should we enter a scope when visiting class declarations?
+        // All parts of a class are strict mode code, its scope is strict for every nested scope to
+        // inherit it. https://tc39.es/ecma262/#sec-strict-mode-code
         let is_class_expr = class.r#type == ClassType::ClassExpression;
         if is_class_expr {
             // Class expressions create a temporary scope with the class name as its only variable
             // E.g., `let c = class A { foo() { console.log(A) } }`
-            self.enter_scope(ScopeFlags::empty());
+            self.enter_scope(ScopeFlags::StrictMode);
         }
 
         self.enter_node(kind);
@@ -1589,6 +1631,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if let Some(id) = &class.id {
             self.visit_binding_identifier(id);
         }
+        // The name of a class declaration is bound in the enclosing scope
+        if !is_class_expr {
+            self.enter_scope(ScopeFlags::StrictMode);
+        }
         if let Some(parameters) = &class.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
@@ -1599,6 +1645,11 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if let Some(super_parameters) = &class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                self.visit_ts_class_implements(implement);
+            }
+        }
         self.visit_class_body(&class.body);
 
         /* cfg */
@@ -1610,6 +1661,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         // self.cfg.put_collection_in_register(self.current_node_id, CollectionType::Class, elements);
         /* cfg */
 
+        if !is_class_expr {
+            self.leave_scope();
+        }
         self.leave_node(kind);
         if is_class_expr {
             self.leave_scope();
@@ -1741,7 +1795,9 @@ impl<'a> SemanticBuilder<'a> {
                 self.make_all_namespaces_valuelike();
                 self.in_type_definition = true;
             }
-            AstKind::TSTypeParameterInstantiation(_) | AstKind::TSTypeAnnotation(_) => {
+            AstKind::TSTypeParameterInstantiation(_)
+            | AstKind::TSTypeAnnotation(_)
+            | AstKind::TSClassImplements(_) => {
                 self.in_type_definition = true;
             }
             AstKind::TSEnumMember(enum_member) => {
@@ -1787,6 +1843,15 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::YieldExpression(_) => {
                 self.set_function_node_flag(NodeFlags::HasYield);
             }
+            AstKind::AwaitExpression(_) => {
+                self.set_function_node_flag(NodeFlags::HasAwait);
+            }
+            AstKind::ForOfStatement(stmt) if stmt.r#await => {
+                self.set_function_node_flag(NodeFlags::HasAwait);
+            }
+            AstKind::ThisExpression(expr) => {
+                self.set_this_node_flags(expr.span);
+            }
             _ => {}
         }
     }
@@ -1798,8 +1863,11 @@ impl<'a> SemanticBuilder<'a> {
                 self.add_export_flag_for_export_identifier();
             }
             AstKind::Class(_) => {
-                self.current_node_flags -= NodeFlags::Class;
                 self.class_table_builder.pop_class();
+                // Code after a nested class is still in the body of the enclosing class
+                if self.class_table_builder.current_class_id.is_none() {
+                    self.current_node_flags -= NodeFlags::Class;
+                }
             }
             AstKind::ExportDefaultDeclaration(_) | AstKind::ExportNamedDeclaration(_) => {
                 self.current_symbol_flags -= SymbolFlags::Export;
@@ -1823,7 +1891,8 @@ impl<'a> SemanticBuilder<'a> {
             | AstKind::TSInterfaceDeclaration(_)
             | AstKind::TSModuleDeclaration(_)
             | AstKind::TSTypeParameterInstantiation(_)
-            | AstKind::TSTypeAnnotation(_) => {
+            | AstKind::TSTypeAnnotation(_)
+            | AstKind::TSClassImplements(_) => {
                 self.in_type_definition = false;
             }
             AstKind::UpdateExpression(_) => {
@@ -0,0 +1,477 @@
+//! Constant folding of expressions
+
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression, IdentifierReference},
+    AstKind,
+};
+use oxc_syntax::{
+    identifier::{is_irregular_whitespace, is_line_terminator, TAB},
+    operator::{BinaryOperator, LogicalOperator, UnaryOperator},
+};
+
+use crate::Semantic;
+
+/// The value of an expression that can be computed statically.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    /// Source text of a `BigInt` literal without the `n` suffix
+    BigInt(String),
+}
+
+impl ConstantValue {
+    /// <https://tc39.es/ecma262/#sec-toboolean>
+    pub fn to_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Number(n) => Some(*n != 0.0 && !n.is_nan()),
+            Self::String(s) => Some(!s.is_empty()),
+            Self::Boolean(b) => Some(*b),
+            Self::Null => Some(false),
+            Self::BigInt(raw) => Some(!is_zero_bigint(raw)),
+        }
+    }
+
+    /// <https://tc39.es/ecma262/#sec-tonumber>
+    ///
+    /// Returns `None` for `BigInt`s, which throw when mixed with numbers.
+    pub fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::String(s) => Some(string_to_number(s)),
+            Self::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+            Self::Null => Some(0.0),
+            Self::BigInt(_) => None,
+        }
+    }
+
+    /// <https://tc39.es/ecma262/#sec-tostring>
+    pub fn to_js_string(&self) -> Option<String> {
+        match self {
+            Self::Number(n) => Some(number_to_string(*n)),
+            Self::String(s) => Some(s.clone()),
+            Self::Boolean(b) => Some(b.to_string()),
+            Self::Null => Some("null".into()),
+            Self::BigInt(raw) => raw.bytes().all(|b| b.is_ascii_digit()).then(|| raw.clone()),
+        }
+    }
+
+    /// The result of the `typeof` operator.
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::String(_) => "string",
+            Self::Boolean(_) => "boolean",
+            Self::Null => "object",
+            Self::BigInt(_) => "bigint",
+        }
+    }
+
+    // Strict equality of numbers is exact
+    #[allow(clippy::float_cmp)]
+    fn strict_equals(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => Some(a == b),
+            (Self::String(a), Self::String(b)) => Some(a == b),
+            (Self::Boolean(a), Self::Boolean(b)) => Some(a == b),
+            (Self::Null, Self::Null) => Some(true),
+            // Different notations of the same `BigInt` are equal
+            (Self::BigInt(_), Self::BigInt(_)) => None,
+            _ => Some(false),
+        }
+    }
+}
+
+/// <https://tc39.es/ecma262/#sec-numeric-types-number-tostring>
+fn number_to_string(n: f64) -> String {
+    ryu_js::Buffer::new().format(n).to_string()
+}
+
+/// <https://tc39.es/ecma262/#sec-stringtonumber>
+fn string_to_number(s: &str) -> f64 {
+    let s = s.trim_matches(is_str_white_space);
+    if s.is_empty() {
+        return 0.0;
+    }
+
+    // `0x`, `0o` and `0b` literals, which can't have a sign
+    let radix = match s.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        let digits = &s[2..];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return f64::NAN;
+        }
+        return digits
+            .chars()
+            .filter_map(|c| c.to_digit(radix))
+            .fold(0.0, |value, digit| value * f64::from(radix) + f64::from(digit));
+    }
+
+    let (sign, unsigned) = match s.as_bytes()[0] {
+        b'+' => (1.0, &s[1..]),
+        b'-' => (-1.0, &s[1..]),
+        _ => (1.0, s),
+    };
+    if unsigned == "Infinity" {
+        return sign * f64::INFINITY;
+    }
+    // Rust also parses spellings like `inf` and `NaN`, which are not numbers in JavaScript
+    if !is_str_unsigned_decimal_literal(unsigned) {
+        return f64::NAN;
+    }
+    unsigned.parse::<f64>().map_or(f64::NAN, |n| sign * n)
+}
+
+/// <https://tc39.es/ecma262/#prod-StrWhiteSpaceChar>
+fn is_str_white_space(c: char) -> bool {
+    c == ' ' || c == TAB || is_line_terminator(c) || (c != '\u{85}' && is_irregular_whitespace(c))
+}
+
+/// <https://tc39.es/ecma262/#prod-StrUnsignedDecimalLiteral>, without `Infinity`
+fn is_str_unsigned_decimal_literal(s: &str) -> bool {
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let (mantissa, exponent) = match s.split_once(|c| c == 'e' || c == 'E') {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (s, None),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exponent_is_valid = exponent.map_or(true, |exponent| {
+        let digits = exponent.strip_prefix(|c| c == '+' || c == '-').unwrap_or(exponent);
+        !digits.is_empty() && is_digits(digits)
+    });
+    (!integer.is_empty() || !fraction.is_empty())
+        && is_digits(integer)
+        && is_digits(fraction)
+        && exponent_is_valid
+}
+
+/// Whether the source text of a `BigInt` literal, without the `n` suffix, is zero.
+fn is_zero_bigint(raw: &str) -> bool {
+    let digits = match raw.get(..2) {
+        Some("0x" | "0X" | "0o" | "0O" | "0b" | "0B") => &raw[2..],
+        _ => raw,
+    };
+    digits.bytes().all(|b| b == b'0' || b == b'_')
+}
+
+/// <https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate>
+#[allow(clippy::float_cmp)]
+fn exponentiate(base: f64, exponent: f64) -> f64 {
+    // `powf` returns 1 for these, but they are `NaN` in JavaScript
+    if exponent.is_nan() || (exponent.is_infinite() && base.abs() == 1.0) {
+        f64::NAN
+    } else {
+        base.powf(exponent)
+    }
+}
+
+/// Compute the value of an expression which consists only of literals, e.g. `1 + 2` or
+/// `` `a${1}` ``. Use [`Semantic::get_constant_value`] to also resolve `const` variables.
+pub fn get_constant_value(expr: &Expression) -> Option<ConstantValue> {
+    evaluate(expr, &|_| None)
+}
+
+impl<'a> Semantic<'a> {
+    /// Compute the value of an expression if it is statically known, resolving references to
+    /// `const` variables declared before the expression with a constant initializer.
+    pub fn get_constant_value(&self, expr: &Expression<'a>) -> Option<ConstantValue> {
+        evaluate(expr, &|ident| self.get_constant_binding_value(ident))
+    }
+
+    /// Whether an expression is always truthy (`Some(true)`) or always falsy (`Some(false)`)
+    /// when converted to a boolean, `None` when it is not known statically.
+    ///
+    /// Object, array, function and class expressions are always truthy, `undefined`, `NaN` and
+    /// `void` expressions are always falsy, and everything else falls back to
+    /// [`Semantic::get_constant_value`].
+    pub fn static_truthiness(&self, expr: &Expression<'a>) -> Option<bool> {
+        match expr {
+            Expression::ObjectExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::RegExpLiteral(_) => Some(true),
+            // `${x}a` is a non-empty string whatever `x` is
+            Expression::TemplateLiteral(lit)
+                if lit.quasis.iter().any(|quasi| !quasi.value.raw.is_empty()) =>
+            {
+                Some(true)
+            }
+            Expression::Identifier(ident) if self.is_reference_to_global_variable(ident) => {
+                match ident.name.as_str() {
+                    "undefined" | "NaN" => Some(false),
+                    "Infinity" => Some(true),
+                    _ => None,
+                }
+            }
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::Void => Some(false),
+                // `typeof x` is never an empty string
+                UnaryOperator::Typeof => Some(true),
+                UnaryOperator::LogicalNot => self.static_truthiness(&unary.argument).map(|b| !b),
+                _ => self.get_constant_value(expr)?.to_boolean(),
+            },
+            Expression::ParenthesizedExpression(paren) => self.static_truthiness(&paren.expression),
+            Expression::SequenceExpression(seq) => self.static_truthiness(seq.expressions.last()?),
+            _ => self.get_constant_value(expr)?.to_boolean(),
+        }
+    }
+
+    fn get_constant_binding_value(&self, ident: &IdentifierReference) -> Option<ConstantValue> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.symbols.get_reference(reference_id).symbol_id()?;
+        if !self.symbols.get_flag(symbol_id).is_const_variable() {
+            return None;
+        }
+        let AstKind::VariableDeclarator(decl) = self.symbol_declaration(symbol_id).kind() else {
+            return None;
+        };
+        // Only declarations which end before the reference are resolved,
+        // which also guarantees termination for `const a = a`.
+        if decl.span.end > ident.span.start
+            || !matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_))
+        {
+            return None;
+        }
+        self.get_constant_value(decl.init.as_ref()?)
+    }
+}
+
+fn evaluate(
+    expr: &Expression,
+    resolve: &dyn Fn(&IdentifierReference) -> Option<ConstantValue>,
+) -> Option<ConstantValue> {
+    match expr {
+        Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+        Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
+        Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+        Expression::NullLiteral(_) => Some(ConstantValue::Null),
+        Expression::BigintLiteral(lit) => {
+            Some(ConstantValue::BigInt(lit.raw.trim_end_matches('n').to_string()))
+        }
+        Expression::TemplateLiteral(lit) => {
+            let mut value = String::new();
+            for (i, quasi) in lit.quasis.iter().enumerate() {
+                value.push_str(quasi.value.cooked.as_ref()?);
+                if let Some(expr) = lit.expressions.get(i) {
+                    value.push_str(&evaluate(expr, resolve)?.to_js_string()?);
+                }
+            }
+            Some(ConstantValue::String(value))
+        }
+        Expression::Identifier(ident) => resolve(ident),
+        Expression::ParenthesizedExpression(expr) => evaluate(&expr.expression, resolve),
+        Expression::UnaryExpression(expr) => {
+            let value = evaluate(&expr.argument, resolve)?;
+            match expr.operator {
+                UnaryOperator::UnaryNegation => Some(ConstantValue::Number(-value.to_number()?)),
+                UnaryOperator::UnaryPlus => Some(ConstantValue::Number(value.to_number()?)),
+                UnaryOperator::LogicalNot => Some(ConstantValue::Boolean(!value.to_boolean()?)),
+                UnaryOperator::Typeof => Some(ConstantValue::String(value.type_of().into())),
+                _ => None,
+            }
+        }
+        Expression::BinaryExpression(expr) => {
+            let left = evaluate(&expr.left, resolve)?;
+            let right = evaluate(&expr.right, resolve)?;
+            let number = |op: fn(f64, f64) -> f64| {
+                Some(ConstantValue::Number(op(left.to_number()?, right.to_number()?)))
+            };
+            match expr.operator {
+                BinaryOperator::Addition => {
+                    if matches!(left, ConstantValue::String(_))
+                        || matches!(right, ConstantValue::String(_))
+                    {
+                        Some(ConstantValue::String(left.to_js_string()? + &right.to_js_string()?))
+                    } else {
+                        number(|a, b| a + b)
+                    }
+                }
+                BinaryOperator::Subtraction => number(|a, b| a - b),
+                BinaryOperator::Multiplication => number(|a, b| a * b),
+                BinaryOperator::Division => number(|a, b| a / b),
+                BinaryOperator::Remainder => number(|a, b| a % b),
+                BinaryOperator::Exponential => number(exponentiate),
+                BinaryOperator::StrictEquality => {
+                    Some(ConstantValue::Boolean(left.strict_equals(&right)?))
+                }
+                BinaryOperator::StrictInequality => {
+                    Some(ConstantValue::Boolean(!left.strict_equals(&right)?))
+                }
+                _ => None,
+            }
+        }
+        Expression::LogicalExpression(expr) => {
+            let left = evaluate(&expr.left, resolve)?;
+            let use_left = match expr.operator {
+                LogicalOperator::And => !left.to_boolean()?,
+                LogicalOperator::Or => left.to_boolean()?,
+                LogicalOperator::Coalesce => left != ConstantValue::Null,
+            };
+            if use_left {
+                Some(left)
+            } else {
+                evaluate(&expr.right, resolve)
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::ConstantValue;
+    use crate::SemanticBuilder;
+
+    /// Evaluate the expression of the last statement in `source_text`
+    fn evaluate(source_text: &str) -> Option<ConstantValue> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            panic!("expected an expression statement");
+        };
+        semantic.get_constant_value(&stmt.expression)
+    }
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(evaluate("1 + 2"), Some(ConstantValue::Number(3.0)));
+        assert_eq!(evaluate("'a' + 'b'"), Some(ConstantValue::String("ab".into())));
+        assert_eq!(evaluate("!true"), Some(ConstantValue::Boolean(false)));
+        assert_eq!(evaluate("-(2 ** 3)"), Some(ConstantValue::Number(-8.0)));
+        assert_eq!(evaluate("'a' + 1"), Some(ConstantValue::String("a1".into())));
+        assert_eq!(evaluate("typeof null"), Some(ConstantValue::String("object".into())));
+        assert_eq!(evaluate("`a${1 + 1}b${true}`"), Some(ConstantValue::String("a2btrue".into())));
+        assert_eq!(evaluate("null ?? 'b'"), Some(ConstantValue::String("b".into())));
+        assert_eq!(evaluate("0 || 1"), Some(ConstantValue::Number(1.0)));
+        assert_eq!(evaluate("1 === 1"), Some(ConstantValue::Boolean(true)));
+        assert_eq!(evaluate("10n"), Some(ConstantValue::BigInt("10".into())));
+        assert_eq!(evaluate("1n + 1"), None);
+    }
+
+    #[test]
+    fn test_number_conversions() {
+        let string = |s: &str| Some(ConstantValue::String(s.into()));
+        assert_eq!(evaluate("'' + 1e21"), string("1e+21"));
+        assert_eq!(evaluate("'' + 1e-7"), string("1e-7"));
+        assert_eq!(evaluate("'' + 0.1"), string("0.1"));
+        assert_eq!(evaluate("'' + -0"), string("0"));
+        assert_eq!(evaluate("'' + 1 / 0"), string("Infinity"));
+
+        let number = |n: f64| Some(ConstantValue::Number(n));
+        assert_eq!(evaluate("+'0x10'"), number(16.0));
+        assert_eq!(evaluate("+'0B11'"), number(3.0));
+        assert_eq!(evaluate("+'0o17'"), number(15.0));
+        assert_eq!(evaluate("+' 12 '"), number(12.0));
+        assert_eq!(evaluate("+'\\n1.5e3\\t'"), number(1500.0));
+        assert_eq!(evaluate("+'.5'"), number(0.5));
+        assert_eq!(evaluate("+'-Infinity'"), number(f64::NEG_INFINITY));
+        assert_eq!(evaluate("+''"), number(0.0));
+
+        let is_nan =
+            |source: &str| matches!(evaluate(source), Some(ConstantValue::Number(n)) if n.is_nan());
+        assert!(is_nan("+'inf'"));
+        assert!(is_nan("+'NaN'"));
+        assert!(is_nan("+'-0x10'"));
+        assert!(is_nan("+'1_000'"));
+        assert!(is_nan("+'1e'"));
+        assert!(is_nan("+'.'"));
+        assert!(is_nan("1 ** (0 / 0)"));
+        assert!(is_nan("1 ** (1 / 0)"));
+        assert_eq!(evaluate("(0 / 0) ** 0"), number(1.0));
+    }
+
+    #[test]
+    fn test_non_constant() {
+        assert_eq!(evaluate("x + 1"), None);
+        assert_eq!(evaluate("foo()"), None);
+        assert_eq!(evaluate("`a${x}`"), None);
+        assert_eq!(evaluate("let x = 1; x + 1"), None);
+    }
+
+    #[test]
+    fn test_const_variables() {
+        assert_eq!(
+            evaluate("const x = 1; const y = x + 1; y * 2"),
+            Some(ConstantValue::Number(4.0))
+        );
+        assert_eq!(evaluate("const s = 'a'; `${s}b`"), Some(ConstantValue::String("ab".into())));
+        assert_eq!(evaluate("const { x } = { x: 1 }; x"), None);
+    }
+
+    /// Truthiness of the expression of the last statement in `source_text`
+    fn truthiness(source_text: &str) -> Option<bool> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            panic!("expected an expression statement");
+        };
+        semantic.static_truthiness(&stmt.expression)
+    }
+
+    #[test]
+    fn test_truthy() {
+        assert_eq!(truthiness("({})"), Some(true));
+        assert_eq!(truthiness("({ a: 1 })"), Some(true));
+        assert_eq!(truthiness("[]"), Some(true));
+        assert_eq!(truthiness("[0]"), Some(true));
+        assert_eq!(truthiness("1"), Some(true));
+        assert_eq!(truthiness("-1"), Some(true));
+        assert_eq!(truthiness("('a')"), Some(true));
+        assert_eq!(truthiness("`a${x}`"), Some(true));
+        assert_eq!(truthiness("(function () {})"), Some(true));
+        assert_eq!(truthiness("() => {}"), Some(true));
+        assert_eq!(truthiness("(class {})"), Some(true));
+        assert_eq!(truthiness("/a/"), Some(true));
+        assert_eq!(truthiness("typeof x"), Some(true));
+        assert_eq!(truthiness("Infinity"), Some(true));
+        assert_eq!(truthiness("!0"), Some(true));
+        assert_eq!(truthiness("(x, 1)"), Some(true));
+        assert_eq!(truthiness("0x10n"), Some(true));
+    }
+
+    #[test]
+    fn test_falsy() {
+        assert_eq!(truthiness("0"), Some(false));
+        assert_eq!(truthiness("-0"), Some(false));
+        assert_eq!(truthiness("('')"), Some(false));
+        assert_eq!(truthiness("``"), Some(false));
+        assert_eq!(truthiness("null"), Some(false));
+        assert_eq!(truthiness("undefined"), Some(false));
+        assert_eq!(truthiness("NaN"), Some(false));
+        assert_eq!(truthiness("void foo()"), Some(false));
+        assert_eq!(truthiness("![]"), Some(false));
+        assert_eq!(truthiness("0n"), Some(false));
+        assert_eq!(truthiness("0x0n"), Some(false));
+        assert_eq!(truthiness("0b00n"), Some(false));
+        assert_eq!(truthiness("const x = 0; x"), Some(false));
+    }
+
+    #[test]
+    fn test_unknown_truthiness() {
+        assert_eq!(truthiness("x"), None);
+        assert_eq!(truthiness("let x = 0; x"), None);
+        assert_eq!(truthiness("`${x}`"), None);
+        assert_eq!(truthiness("foo()"), None);
+        assert_eq!(truthiness("-x"), None);
+    }
+}
@@ -115,6 +115,24 @@ pub enum EdgeType {
     NewFunction,
 }
 
+/// The ways control can leave a function, see [`crate::Semantic::function_exit_kinds`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExitAnalysis {
+    /// Some path ends in `return value`.
+    pub value_return: bool,
+    /// Some path ends in a bare `return;`.
+    pub bare_return: bool,
+    /// Some path reaches the end of the function body without returning or throwing.
+    pub fall_through: bool,
+}
+
+impl ExitAnalysis {
+    /// Whether every path that leaves the function normally returns a value.
+    pub fn always_returns_value(&self) -> bool {
+        !self.bare_return && !self.fall_through
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ControlFlowGraph {
     pub basic_blocks: Vec<Vec<BasicBlockElement>>,
@@ -303,7 +321,7 @@ impl ControlFlowGraph {
                     self.ast_node_to_break_continue.push((
                         id,
                         self.basic_blocks_with_breaks.len() - 1,
-                        None,
+                        Some(self.basic_blocks_with_continues.len() - 1),
                     ));
                 }
             }
@@ -1,7 +1,9 @@
 mod binder;
+mod binding;
 mod builder;
 mod checker;
 mod class;
+mod constant;
 mod control_flow;
 mod diagnostics;
 mod jsdoc;
@@ -19,22 +21,29 @@ pub use petgraph;
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 use class::ClassTable;
+pub use constant::{get_constant_value, ConstantValue};
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
-use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
-use oxc_span::SourceType;
+use oxc_ast::{
+    ast::{Argument, AssignmentTarget, Expression, IdentifierReference, SimpleAssignmentTarget},
+    AstKind, Trivias,
+};
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::operator::UnaryOperator;
 pub use oxc_syntax::{
-    module_record::ModuleRecord,
+    module_record::{ImportEntry, ModuleRecord},
     scope::{ScopeFlags, ScopeId},
     symbol::{SymbolFlags, SymbolId},
 };
+use petgraph::visit::EdgeRef;
 use rustc_hash::FxHashSet;
 
 pub use crate::{
+    binding::PatternBinding,
     control_flow::{
         print_basic_block, AssignmentValue, BasicBlockElement, BinaryAssignmentValue, BinaryOp,
         CallType, CalleeWithArgumentsAssignmentValue, CollectionAssignmentValue, ControlFlowGraph,
-        EdgeType, ObjectPropertyAccessAssignmentValue, Register, UnaryExpressioneAssignmentValue,
-        UpdateAssignmentValue,
+        EdgeType, ExitAnalysis, ObjectPropertyAccessAssignmentValue, Register,
+        UnaryExpressioneAssignmentValue, UpdateAssignmentValue,
     },
     node::{AstNode, AstNodeId, AstNodes},
     reference::{Reference, ReferenceFlag, ReferenceId},
@@ -119,6 +128,56 @@ impl<'a> Semantic<'a> {
         &self.cfg
     }
 
+    /// Summarize how the code paths of a function or arrow function end.
+    ///
+    /// Throws and unreachable code are not exits, so a function whose every path
+    /// throws reports none of them.
+    pub fn function_exit_kinds(&self, node_id: AstNodeId) -> ExitAnalysis {
+        let node = self.nodes.get_node(node_id);
+        let mut analysis = ExitAnalysis::default();
+
+        // `() => value`
+        if matches!(node.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression) {
+            analysis.value_return = true;
+            return analysis;
+        }
+
+        let graph = &self.cfg.graph;
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![node.cfg_ix()];
+        'blocks: while let Some(ix) = stack.pop() {
+            if !visited.insert(ix) {
+                continue;
+            }
+            for element in self.cfg.basic_block_by_index(ix) {
+                match element {
+                    BasicBlockElement::Assignment(Register::Return, value) => {
+                        match value {
+                            AssignmentValue::ImplicitUndefined => analysis.bare_return = true,
+                            AssignmentValue::NotImplicitUndefined => analysis.value_return = true,
+                        }
+                        continue 'blocks;
+                    }
+                    BasicBlockElement::Throw(_) | BasicBlockElement::Unreachable => {
+                        continue 'blocks;
+                    }
+                    BasicBlockElement::Assignment(..) => {}
+                }
+            }
+            // Nested functions are not evaluated as part of this one.
+            let mut successors = graph
+                .edges_directed(ix, petgraph::Direction::Outgoing)
+                .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction))
+                .map(|edge| edge.target())
+                .peekable();
+            if successors.peek().is_none() {
+                analysis.fall_through = true;
+            }
+            stack.extend(successors);
+        }
+        analysis
+    }
+
     pub fn is_unresolved_reference(&self, node_id: AstNodeId) -> bool {
         let reference_node = self.nodes.get_node(node_id);
         let AstKind::IdentifierReference(id) = reference_node.kind() else {
@@ -137,6 +196,42 @@ impl<'a> Semantic<'a> {
         self.symbols.get_resolved_references(symbol_id)
     }
 
+    /// Get all resolved references to the local binding of an import, as
+    /// `(span, flag, is_type_context)` tuples.
+    pub fn import_binding_references(
+        &self,
+        entry: &ImportEntry,
+    ) -> impl Iterator<Item = (Span, ReferenceFlag, bool)> + '_ {
+        let symbols = &self.symbols;
+        self.scopes
+            .get_root_binding(entry.local_name.name())
+            .into_iter()
+            .flat_map(|symbol_id| symbols.get_resolved_references(symbol_id))
+            .map(|reference| (reference.span(), reference.flag(), reference.is_type()))
+    }
+
+    /// CommonJS `require(...)` calls in the program, as `(span, specifier)` tuples.
+    ///
+    /// Only calls to the global `require` with a single argument are listed. The specifier is
+    /// the value of a string literal argument, or `None` when the argument is dynamic.
+    pub fn require_calls(&self) -> impl Iterator<Item = (Span, Option<&'a str>)> + '_ {
+        self.nodes.iter().filter_map(|node| {
+            let AstKind::CallExpression(call) = node.kind() else { return None };
+            let Expression::Identifier(ident) = &call.callee else { return None };
+            if ident.name != "require"
+                || call.arguments.len() != 1
+                || !ident.reference_id.get().is_some_and(|id| self.symbols.is_global_reference(id))
+            {
+                return None;
+            }
+            let specifier = match &call.arguments[0] {
+                Argument::Expression(Expression::StringLiteral(lit)) => Some(lit.value.as_str()),
+                _ => None,
+            };
+            Some((call.span, specifier))
+        })
+    }
+
     pub fn symbol_declaration(&self, symbol_id: SymbolId) -> &AstNode<'a> {
         self.nodes.get_node(self.symbols.get_declaration(symbol_id))
     }
@@ -144,6 +239,89 @@ impl<'a> Semantic<'a> {
     pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
         self.scopes().root_unresolved_references().contains_key(ident.name.as_str())
     }
+
+    /// Check if evaluating the module's top level code has observable side effects.
+    ///
+    /// See [`Semantic::top_level_side_effects`] for what is considered a side effect.
+    pub fn has_top_level_side_effects(&self) -> bool {
+        self.top_level_side_effects().next().is_some()
+    }
+
+    /// Spans of the expressions evaluated with the module that may have observable side effects.
+    ///
+    /// Calls, `new` expressions, tagged templates, `delete`, and assignments to members or
+    /// global variables are side effects, unless the call or `new` is annotated with
+    /// `/* @__PURE__ */` or `/* #__PURE__ */`. Function bodies and instance field initializers
+    /// are not evaluated with the module and are skipped.
+    pub fn top_level_side_effects(&self) -> Box<dyn Iterator<Item = Span> + '_> {
+        // Boxed, an `impl Iterator` can't capture `'a` without naming it in its bounds
+        Box::new(self.nodes.iter().filter_map(|node| {
+            let span = match node.kind() {
+                AstKind::CallExpression(expr) if !self.has_pure_annotation(expr.span) => expr.span,
+                AstKind::NewExpression(expr) if !self.has_pure_annotation(expr.span) => expr.span,
+                AstKind::TaggedTemplateExpression(expr) => expr.span,
+                AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::Delete => {
+                    expr.span
+                }
+                AstKind::AssignmentExpression(expr) => match &expr.left {
+                    AssignmentTarget::SimpleAssignmentTarget(target)
+                        if !self.is_observable_assignment_target(target) =>
+                    {
+                        return None
+                    }
+                    _ => expr.span,
+                },
+                AstKind::UpdateExpression(expr)
+                    if self.is_observable_assignment_target(&expr.argument) =>
+                {
+                    expr.span
+                }
+                _ => return None,
+            };
+            self.is_evaluated_with_module(node.id()).then_some(span)
+        }))
+    }
+
+    fn is_evaluated_with_module(&self, node_id: AstNodeId) -> bool {
+        let span = self.nodes.kind(node_id).span();
+        !self.nodes.iter_parents(node_id).skip(1).any(|parent| match parent.kind() {
+            kind if kind.is_function_like() => true,
+            AstKind::PropertyDefinition(prop) => {
+                !prop.r#static
+                    && prop.value.as_ref().is_some_and(|value| {
+                        let value_span = value.span();
+                        value_span.start <= span.start && span.end <= value_span.end
+                    })
+            }
+            _ => false,
+        })
+    }
+
+    /// Assignments to local variables are not observable outside of the module.
+    fn is_observable_assignment_target(&self, target: &SimpleAssignmentTarget) -> bool {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                ident.reference_id.get().map_or(true, |id| !self.symbols.has_binding(id))
+            }
+            _ => true,
+        }
+    }
+
+    /// `/* @__PURE__ */ foo()`, only whitespace and parentheses may be between the comment and
+    /// the call.
+    fn has_pure_annotation(&self, span: Span) -> bool {
+        let Some((start, comment)) = self.trivias.comments_range(..span.start).next_back() else {
+            return false;
+        };
+        let between = Span::new(comment.end, span.start).source_text(self.source_text);
+        // Multi line comments end before the closing `*/`
+        let between = between.strip_prefix("*/").unwrap_or(between);
+        if !between.chars().all(|c| c.is_whitespace() || c == '(') {
+            return false;
+        }
+        let raw = Span::new(*start, comment.end).source_text(self.source_text);
+        raw.contains("@__PURE__") || raw.contains("#__PURE__")
+    }
 }
 
 #[cfg(test)]
@@ -163,7 +341,8 @@ mod tests {
         let parse = oxc_parser::Parser::new(allocator, source, source_type).parse();
         assert!(parse.errors.is_empty());
         let program = allocator.alloc(parse.program);
-        let semantic = SemanticBuilder::new(source, source_type).build(program);
+        let semantic =
+            SemanticBuilder::new(source, source_type).with_trivias(parse.trivias).build(program);
         assert!(semantic.errors.is_empty(), "Parse error: {}", semantic.errors[0]);
         semantic.semantic
     }
@@ -370,4 +549,36 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_top_level_side_effects() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+
+        let source = "
+            import { a } from 'a';
+            const b = 1;
+            let c = b;
+            c = 2;
+            function foo() { console.log(a); }
+            class Foo { bar = foo(); static baz() { foo(); } }
+            export const d = /* @__PURE__ */ foo();
+            export const e = /* #__PURE__ */ new Foo();
+            export default foo;";
+        let semantic = get_semantic(&allocator, source, source_type);
+        assert!(!semantic.has_top_level_side_effects());
+
+        let source = "
+            const b = 1;
+            console.log(b);
+            window.foo = b;
+            /* @__PURE__ */ b; foo();";
+        let semantic = get_semantic(&allocator, source, source_type);
+        let side_effects = semantic
+            .top_level_side_effects()
+            .map(|span| span.source_text(source))
+            .collect::<Vec<_>>();
+        assert_eq!(side_effects, vec!["console.log(b)", "window.foo = b", "foo()"]);
+        assert!(semantic.has_top_level_side_effects());
+    }
 }
@@ -1,9 +1,10 @@
 use petgraph::stable_graph::NodeIndex;
 
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_index::IndexVec;
+use oxc_span::GetSpan;
 
-use crate::scope::ScopeId;
+use crate::scope::{ScopeId, ScopeTree};
 
 pub use oxc_syntax::node::{AstNodeId, NodeFlags};
 
@@ -21,11 +22,14 @@ pub struct AstNode<'a> {
     cfg_ix: NodeIndex,
 
     flags: NodeFlags,
+
+    /// Position among the children of the parent node (initialized by `AstNodes::add_node`)
+    child_index: u32,
 }
 
 impl<'a> AstNode<'a> {
     pub fn new(kind: AstKind<'a>, scope_id: ScopeId, cfg_ix: NodeIndex, flags: NodeFlags) -> Self {
-        Self { id: AstNodeId::new(0), kind, cfg_ix, scope_id, flags }
+        Self { id: AstNodeId::new(0), kind, cfg_ix, scope_id, flags, child_index: 0 }
     }
 
     pub fn id(&self) -> AstNodeId {
@@ -51,6 +55,21 @@ impl<'a> AstNode<'a> {
     pub fn flags_mut(&mut self) -> &mut NodeFlags {
         &mut self.flags
     }
+
+    /// Check if the node is strict mode code.
+    ///
+    /// Module code, classes and code inside a `"use strict"` directive are strict, which is
+    /// marked by `ScopeFlags::StrictMode` on their scope or one of its ancestors.
+    pub fn is_in_strict_mode(&self, scopes: &ScopeTree) -> bool {
+        scopes.ancestors(self.scope_id).any(|scope_id| scopes.get_flags(scope_id).is_strict_mode())
+    }
+
+    /// Get the position of the node among the children of its parent, in visit order.
+    ///
+    /// The root node has a child index of `0`.
+    pub fn child_index(&self) -> u32 {
+        self.child_index
+    }
 }
 
 /// Untyped AST nodes flattened into an vec
@@ -101,6 +120,144 @@ impl<'a> AstNodes<'a> {
         self.parent_id(ast_node_id).map(|node_id| self.get_node(node_id))
     }
 
+    /// Check if the node is an `async` function or arrow function.
+    ///
+    /// Returns `false` for nodes that are not function-like.
+    pub fn is_async(&self, ast_node_id: AstNodeId) -> bool {
+        match self.kind(ast_node_id) {
+            AstKind::Function(func) => func.r#async,
+            AstKind::ArrowFunctionExpression(arrow) => arrow.r#async,
+            _ => false,
+        }
+    }
+
+    /// Check if the node is a generator function.
+    ///
+    /// Arrow functions can never be generators.
+    pub fn is_generator(&self, ast_node_id: AstNodeId) -> bool {
+        matches!(self.kind(ast_node_id), AstKind::Function(func) if func.generator)
+    }
+
+    /// Get the innermost `Function` or `ArrowFunctionExpression` enclosing the node,
+    /// not including the node itself.
+    pub fn enclosing_function(&self, ast_node_id: AstNodeId) -> Option<AstNodeId> {
+        self.ancestors(ast_node_id).skip(1).find(|node_id| self.kind(*node_id).is_function_like())
+    }
+
+    /// Check if the innermost function enclosing the node is `async`.
+    pub fn is_in_async_function(&self, ast_node_id: AstNodeId) -> bool {
+        self.enclosing_function(ast_node_id).is_some_and(|node_id| self.is_async(node_id))
+    }
+
+    /// Check if the innermost function enclosing the node is a generator.
+    pub fn is_in_generator_function(&self, ast_node_id: AstNodeId) -> bool {
+        self.enclosing_function(ast_node_id).is_some_and(|node_id| self.is_generator(node_id))
+    }
+
+    /// Check if the node is TypeScript type syntax or inside of it, such as type annotations,
+    /// type aliases, interfaces, type arguments and type parameters with their constraints.
+    ///
+    /// Expressions of `as`, `satisfies` and non-null assertions are not in a type context.
+    pub fn is_in_type_context(&self, ast_node_id: AstNodeId) -> bool {
+        self.ancestors(ast_node_id).any(|node_id| {
+            let kind = self.kind(node_id);
+            kind.is_type()
+                || matches!(
+                    kind,
+                    AstKind::TSTypeAnnotation(_)
+                        | AstKind::TSTypeAliasDeclaration(_)
+                        | AstKind::TSInterfaceDeclaration(_)
+                        | AstKind::TSTypeParameter(_)
+                        | AstKind::TSTypeParameterDeclaration(_)
+                        | AstKind::TSTypeParameterInstantiation(_)
+                        | AstKind::TSTypeQuery(_)
+                        | AstKind::TSImportType(_)
+                        | AstKind::TSNamedTupleMember(_)
+                        | AstKind::TSClassImplements(_)
+                )
+        })
+    }
+
+    /// Get the innermost loop enclosing the node, not including the node itself.
+    ///
+    /// Loops outside of the innermost function are not considered.
+    pub fn nearest_loop(&self, ast_node_id: AstNodeId) -> Option<AstNodeId> {
+        self.ancestors(ast_node_id)
+            .skip(1)
+            .take_while(|node_id| !self.kind(*node_id).is_function_like())
+            .find(|node_id| self.kind(*node_id).is_iteration_statement())
+    }
+
+    /// Get the statement a `break` or `continue` statement jumps out of.
+    ///
+    /// Without a label this is the innermost loop, or for `break` also the innermost `switch`.
+    /// With a label this is the body of the matching labeled statement.
+    /// Returns `None` for other nodes and for jumps without a valid target.
+    pub fn breakable_target(&self, ast_node_id: AstNodeId) -> Option<AstNodeId> {
+        let (label, is_break) = match self.kind(ast_node_id) {
+            AstKind::BreakStatement(stmt) => (stmt.label.as_ref(), true),
+            AstKind::ContinueStatement(stmt) => (stmt.label.as_ref(), false),
+            _ => return None,
+        };
+        let mut ancestors = self
+            .ancestors(ast_node_id)
+            .skip(1)
+            .take_while(|node_id| !self.kind(*node_id).is_function_like());
+
+        let Some(label) = label else {
+            return ancestors.find(|node_id| {
+                let kind = self.kind(*node_id);
+                kind.is_iteration_statement()
+                    || (is_break && matches!(kind, AstKind::SwitchStatement(_)))
+            });
+        };
+        // The body of `a: b: while (x) {}` is the loop for both labels
+        let mut body = None;
+        for node_id in ancestors {
+            match self.kind(node_id) {
+                AstKind::LabeledStatement(stmt) if stmt.label.name == label.name => return body,
+                AstKind::LabeledStatement(_) => {}
+                _ => body = Some(node_id),
+            }
+        }
+        None
+    }
+
+    /// Get the `extends` expression of a class.
+    ///
+    /// Returns `None` for classes without `extends` and nodes that are not classes.
+    pub fn class_super_class(&self, ast_node_id: AstNodeId) -> Option<&'a Expression<'a>> {
+        match self.kind(ast_node_id) {
+            AstKind::Class(class) => class.super_class.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Check if the node is a class with an `extends` clause.
+    pub fn has_super_class(&self, ast_node_id: AstNodeId) -> bool {
+        self.class_super_class(ast_node_id).is_some()
+    }
+
+    /// Check if the node is the callee of a `CallExpression`, `NewExpression` or the tag of a
+    /// `TaggedTemplateExpression`, ignoring parentheses around it.
+    pub fn is_callee(&self, ast_node_id: AstNodeId) -> bool {
+        let mut node_id = ast_node_id;
+        loop {
+            let Some(parent_id) = self.parent_id(node_id) else { return false };
+            let span = self.kind(node_id).span();
+            return match self.kind(parent_id) {
+                AstKind::ParenthesizedExpression(_) => {
+                    node_id = parent_id;
+                    continue;
+                }
+                AstKind::CallExpression(expr) => expr.callee.span() == span,
+                AstKind::NewExpression(expr) => expr.callee.span() == span,
+                AstKind::TaggedTemplateExpression(expr) => expr.tag.span() == span,
+                _ => false,
+            };
+        }
+    }
+
     pub fn get_node(&self, ast_node_id: AstNodeId) -> &AstNode<'a> {
         &self.nodes[ast_node_id]
     }
@@ -148,10 +305,48 @@ impl<'a> AstNodes<'a> {
         std::iter::successors(Some(ast_node_id), |node_id| parent_ids[*node_id])
     }
 
-    pub fn add_node(&mut self, node: AstNode<'a>, parent_id: Option<AstNodeId>) -> AstNodeId {
+    /// Find the closest ancestor of the node for which `predicate` returns `true`,
+    /// not including the node itself.
+    ///
+    /// Stops walking up the AST at the first match.
+    pub fn find_ancestor<F>(&self, ast_node_id: AstNodeId, predicate: F) -> Option<&AstNode<'a>>
+    where
+        F: Fn(&AstNode<'a>) -> bool,
+    {
+        self.ancestors(ast_node_id)
+            .skip(1)
+            .map(|node_id| self.get_node(node_id))
+            .find(|node| predicate(node))
+    }
+
+    /// Find the closest ancestor of the node with the given [`AstType`],
+    /// not including the node itself.
+    pub fn find_ancestor_kind(&self, ast_node_id: AstNodeId, ty: AstType) -> Option<&AstNode<'a>> {
+        self.find_ancestor(ast_node_id, |node| node.kind().ty() == ty)
+    }
+
+    /// Walk down the AST, iterating over every node nested in the node pointed to by `ast_node_id`.
+    ///
+    /// The nodes are produced in pre-order: a node comes before its children, and the
+    /// children of a node come in the order they were visited. The node itself is not included.
+    pub fn descendants(&self, ast_node_id: AstNodeId) -> impl Iterator<Item = &AstNode<'a>> + '_ {
+        // Nodes are added in pre-order, so the descendants of a node are the nodes right after
+        // it, up to the first node whose parent was added before the node itself.
+        self.nodes.iter().skip(ast_node_id.index() + 1).take_while(move |node| {
+            self.parent_ids[node.id()].is_some_and(|parent_id| parent_id >= ast_node_id)
+        })
+    }
+
+    pub fn add_node(
+        &mut self,
+        node: AstNode<'a>,
+        parent_id: Option<AstNodeId>,
+        child_index: u32,
+    ) -> AstNodeId {
         let mut node = node;
         let ast_node_id = self.parent_ids.push(parent_id);
         node.id = ast_node_id;
+        node.child_index = child_index;
         self.nodes.push(node);
         ast_node_id
     }
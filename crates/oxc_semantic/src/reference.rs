@@ -46,6 +46,10 @@ impl Reference {
         self.symbol_id
     }
 
+    pub fn flag(&self) -> ReferenceFlag {
+        self.flag
+    }
+
     pub(crate) fn set_symbol_id(&mut self, symbol_id: SymbolId) {
         self.symbol_id = Some(symbol_id);
     }
@@ -1,6 +1,6 @@
 use std::hash::BuildHasherDefault;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use oxc_ast::{ast::Expression, syntax_directed_operations::GatherNodeParts};
 use oxc_index::IndexVec;
 use oxc_span::CompactStr;
@@ -10,6 +10,7 @@ use rustc_hash::{FxHashMap, FxHasher};
 use crate::{reference::ReferenceId, symbol::SymbolId, AstNodeId};
 
 type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
+type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
 
 type Bindings = FxIndexMap<CompactStr, SymbolId>;
 type UnresolvedReferences = FxHashMap<CompactStr, Vec<ReferenceId>>;
@@ -29,6 +30,10 @@ pub struct ScopeTree {
     flags: IndexVec<ScopeId, ScopeFlags>,
     bindings: IndexVec<ScopeId, Bindings>,
     unresolved_references: IndexVec<ScopeId, UnresolvedReferences>,
+    /// Maps a scope to the `var` declarations hoisted to it
+    hoisted_vars: FxHashMap<ScopeId, FxIndexSet<SymbolId>>,
+    /// Maps a scope to the function declarations hoisted to it
+    hoisted_functions: FxHashMap<ScopeId, FxIndexSet<SymbolId>>,
 }
 
 impl ScopeTree {
@@ -120,6 +125,21 @@ impl ScopeTree {
         self.node_ids[&scope_id]
     }
 
+    /// Get the `var` declarations hoisted to the scope, including those declared in nested blocks.
+    ///
+    /// Only function scopes and the top level scope have hoisted `var`s.
+    pub fn hoisted_vars(&self, scope_id: ScopeId) -> impl Iterator<Item = SymbolId> + '_ {
+        self.hoisted_vars.get(&scope_id).into_iter().flatten().copied()
+    }
+
+    /// Get the function declarations hoisted to the scope.
+    ///
+    /// Unlike `var`s, these are initialized before any code in the scope runs,
+    /// so they are never in a temporal dead zone.
+    pub fn hoisted_functions(&self, scope_id: ScopeId) -> impl Iterator<Item = SymbolId> + '_ {
+        self.hoisted_functions.get(&scope_id).into_iter().flatten().copied()
+    }
+
     pub fn iter_bindings(&self) -> impl Iterator<Item = (ScopeId, SymbolId, &'_ CompactStr)> + '_ {
         self.bindings.iter_enumerated().flat_map(|(scope_id, bindings)| {
             bindings.iter().map(move |(name, symbol_id)| (scope_id, *symbol_id, name))
@@ -151,6 +171,14 @@ impl ScopeTree {
         self.bindings[scope_id].insert(name, symbol_id);
     }
 
+    pub(crate) fn add_hoisted_var(&mut self, scope_id: ScopeId, symbol_id: SymbolId) {
+        self.hoisted_vars.entry(scope_id).or_default().insert(symbol_id);
+    }
+
+    pub(crate) fn add_hoisted_function(&mut self, scope_id: ScopeId, symbol_id: SymbolId) {
+        self.hoisted_functions.entry(scope_id).or_default().insert(symbol_id);
+    }
+
     pub(crate) fn add_unresolved_reference(
         &mut self,
         scope_id: ScopeId,
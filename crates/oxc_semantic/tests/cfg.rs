@@ -2,6 +2,8 @@ mod util;
 
 use std::fs;
 
+use oxc_ast::{AstKind, AstType};
+use oxc_semantic::{petgraph::stable_graph::NodeIndex, BasicBlockElement, Semantic};
 use oxc_span::SourceType;
 pub use util::SemanticTester;
 
@@ -15,6 +17,51 @@ fn test_cfg_files() {
     });
 }
 
+fn cfg_ix_of(semantic: &Semantic, predicate: impl Fn(&AstKind) -> bool) -> NodeIndex {
+    semantic.nodes().iter().find(|node| predicate(&node.kind())).unwrap().cfg_ix()
+}
+
+fn cfg_ix_of_identifier(semantic: &Semantic, name: &str) -> NodeIndex {
+    cfg_ix_of(
+        semantic,
+        |kind| matches!(kind, AstKind::IdentifierReference(ident) if ident.name == name),
+    )
+}
+
+#[test]
+fn test_labeled_jumps_from_inner_loop() {
+    // `a` is in the condition of the outer loop, `after` follows it
+    let tester =
+        SemanticTester::js("outer: while (a) { while (b) { break outer; } c(); } after();");
+    let semantic = tester.build();
+    let graph = &semantic.cfg().graph;
+    let break_ix = cfg_ix_of(&semantic, |kind| kind.ty() == AstType::BreakStatement);
+    assert!(graph.contains_edge(break_ix, cfg_ix_of_identifier(&semantic, "after")));
+
+    let tester =
+        SemanticTester::js("outer: while (a) { while (b) { continue outer; } c(); } after();");
+    let semantic = tester.build();
+    let graph = &semantic.cfg().graph;
+    let continue_ix = cfg_ix_of(&semantic, |kind| kind.ty() == AstType::ContinueStatement);
+    assert!(graph.contains_edge(continue_ix, cfg_ix_of_identifier(&semantic, "a")));
+}
+
+#[test]
+fn test_if_else_joins_branches() {
+    // Neither branch leaves the function, so the code after the statement is reachable from both
+    let tester = SemanticTester::js("if (a) { b(); } else { c(); } after();");
+    let semantic = tester.build();
+    let cfg = semantic.cfg();
+    let after_ix = cfg_ix_of_identifier(&semantic, "after");
+    for branch in ["b", "c"] {
+        assert!(cfg.graph.contains_edge(cfg_ix_of_identifier(&semantic, branch), after_ix));
+    }
+    assert!(!cfg
+        .basic_block_by_index(after_ix)
+        .iter()
+        .any(|elem| matches!(elem, BasicBlockElement::Unreachable)));
+}
+
 // todo: private identifier from class function name should not be in cfg
 
 // #[test]
@@ -1,5 +1,6 @@
 mod util;
 
+use oxc_ast::{ast::Expression, AstKind};
 pub use util::SemanticTester;
 
 #[test]
@@ -41,3 +42,28 @@ fn test_class_with_ts() {
     .has_accessor("ap")
     .has_accessor("pap");
 }
+
+#[test]
+fn test_class_super_class() {
+    let tester = SemanticTester::js(
+        "
+      class Foo {}
+      class Bar extends Foo {}
+      const Baz = class extends (cond ? Foo : Bar) {};
+    ",
+    );
+    tester.has_class("Foo").has_no_super_class();
+    tester.has_class("Bar").has_super_class("Foo");
+
+    let semantic = tester.build();
+    let class_expr = semantic
+        .nodes()
+        .iter()
+        .find(|node| matches!(node.kind(), AstKind::Class(class) if class.id.is_none()))
+        .unwrap();
+    let super_class = semantic.nodes().class_super_class(class_expr.id()).unwrap();
+    assert!(matches!(super_class.without_parenthesized(), Expression::ConditionalExpression(_)));
+
+    // Not a class
+    assert!(!semantic.nodes().has_super_class(semantic.nodes().root()));
+}
@@ -0,0 +1,278 @@
+mod util;
+
+use oxc_ast::{AstKind, AstType};
+use oxc_semantic::{AstNode, AstNodeId, ExitAnalysis, Semantic};
+use oxc_span::GetSpan;
+use util::first_node_id;
+pub use util::SemanticTester;
+
+fn function_named(semantic: &Semantic, name: &str) -> AstNodeId {
+    first_node_id(
+        semantic,
+        |kind| matches!(kind, AstKind::Function(func) if func.id.as_ref().is_some_and(|id| id.name == name)),
+    )
+}
+
+#[test]
+fn test_async_arrow() {
+    let tester = SemanticTester::js("const f = async () => { await g(); };");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let arrow =
+        first_node_id(&semantic, |kind| matches!(kind, AstKind::ArrowFunctionExpression(_)));
+    assert!(nodes.is_async(arrow));
+    assert!(!nodes.is_generator(arrow));
+
+    let await_expr = first_node_id(&semantic, |kind| matches!(kind, AstKind::AwaitExpression(_)));
+    assert_eq!(nodes.enclosing_function(await_expr), Some(arrow));
+    assert!(nodes.is_in_async_function(await_expr));
+    assert!(!nodes.is_in_generator_function(await_expr));
+}
+
+#[test]
+fn test_generator_function() {
+    let tester = SemanticTester::js("function* gen() { yield 1; }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let gen = function_named(&semantic, "gen");
+    assert!(nodes.is_generator(gen));
+    assert!(!nodes.is_async(gen));
+    // A function is not its own enclosing function
+    assert_eq!(nodes.enclosing_function(gen), None);
+
+    let yield_expr = first_node_id(&semantic, |kind| matches!(kind, AstKind::YieldExpression(_)));
+    assert!(nodes.is_in_generator_function(yield_expr));
+    assert!(!nodes.is_in_async_function(yield_expr));
+}
+
+#[test]
+fn test_async_generator_function() {
+    let tester =
+        SemanticTester::js("async function* gen() { for await (const x of xs) { yield x; } }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let gen = function_named(&semantic, "gen");
+    assert!(nodes.is_async(gen));
+    assert!(nodes.is_generator(gen));
+
+    let yield_expr = first_node_id(&semantic, |kind| matches!(kind, AstKind::YieldExpression(_)));
+    assert!(nodes.is_in_async_function(yield_expr));
+    assert!(nodes.is_in_generator_function(yield_expr));
+}
+
+#[test]
+fn test_nested_function_boundary() {
+    let tester = SemanticTester::js("async function outer() { function inner() { return 1; } }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let return_stmt = first_node_id(&semantic, |kind| matches!(kind, AstKind::ReturnStatement(_)));
+    assert_eq!(nodes.enclosing_function(return_stmt), Some(function_named(&semantic, "inner")));
+    assert!(!nodes.is_in_async_function(return_stmt));
+}
+
+#[test]
+fn test_descendants() {
+    let tester = SemanticTester::js(
+        "function outer(a) { function inner(b) { return a + b; } return 1; } const after = 1;",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let outer = function_named(&semantic, "outer");
+    let inner = function_named(&semantic, "inner");
+    for (node_id, ancestor) in [(outer, "outer"), (inner, "inner")] {
+        let descendants = nodes.descendants(node_id).map(AstNode::id).collect::<Vec<_>>();
+        let expected = nodes
+            .iter()
+            .filter(|node| nodes.ancestors(node.id()).skip(1).any(|id| id == node_id))
+            .count();
+        assert_eq!(descendants.len(), expected, "descendants of {ancestor}");
+
+        // Pre-order: the parent of each node was produced before it
+        for (i, id) in descendants.iter().enumerate() {
+            let parent_id = nodes.parent_id(*id).unwrap();
+            assert!(parent_id == node_id || descendants[..i].contains(&parent_id));
+        }
+    }
+
+    assert!(nodes
+        .descendants(inner)
+        .any(|node| matches!(node.kind(), AstKind::BinaryExpression(_))));
+    assert!(!nodes
+        .descendants(inner)
+        .any(|node| matches!(node.kind(), AstKind::NumericLiteral(_))));
+    assert!(nodes.descendants(outer).any(|node| node.id() == inner));
+    assert!(!nodes
+        .descendants(outer)
+        .any(|node| matches!(node.kind(), AstKind::VariableDeclaration(_))));
+}
+
+#[test]
+fn test_find_ancestor() {
+    let tester = SemanticTester::js(
+        "function outer() {
+            if (a) { for (;;) { while (b) { const f = () => { return 1; }; return 2; } } }
+        }",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+    let outer = function_named(&semantic, "outer");
+
+    let returns = nodes
+        .iter()
+        .filter(|node| matches!(node.kind(), AstKind::ReturnStatement(_)))
+        .map(AstNode::id)
+        .collect::<Vec<_>>();
+    assert_eq!(returns.len(), 2);
+
+    for return_stmt in returns {
+        let function = nodes.find_ancestor_kind(return_stmt, AstType::Function);
+        assert_eq!(function.map(AstNode::id), Some(outer));
+    }
+
+    let arrow_return = first_node_id(&semantic, |kind| matches!(kind, AstKind::ReturnStatement(_)));
+    let enclosing = nodes.find_ancestor(arrow_return, |node| node.kind().is_function_like());
+    assert!(matches!(enclosing.map(AstNode::kind), Some(AstKind::ArrowFunctionExpression(_))));
+    let loop_stmt = nodes.find_ancestor(arrow_return, |node| node.kind().is_iteration_statement());
+    assert!(matches!(loop_stmt.map(AstNode::kind), Some(AstKind::WhileStatement(_))));
+
+    // The node itself is not an ancestor
+    assert!(nodes.find_ancestor_kind(outer, AstType::Function).is_none());
+}
+
+#[test]
+fn test_child_index() {
+    let tester = SemanticTester::js("function f() { a(); b(); c(); }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let statements = nodes
+        .iter()
+        .filter(|node| matches!(node.kind(), AstKind::ExpressionStatement(_)))
+        .collect::<Vec<_>>();
+    assert_eq!(statements.iter().map(|node| node.child_index()).collect::<Vec<_>>(), [0, 1, 2]);
+
+    let body = nodes.parent_id(statements[0].id());
+    assert!(matches!(body.map(|id| nodes.kind(id)), Some(AstKind::FunctionBody(_))));
+    assert!(statements.iter().all(|node| nodes.parent_id(node.id()) == body));
+
+    // The count starts over for the children of each node
+    assert!(nodes
+        .iter()
+        .filter(|node| matches!(node.kind(), AstKind::IdentifierReference(_)))
+        .all(|node| node.child_index() == 0));
+    assert_eq!(nodes.root_node().child_index(), 0);
+}
+
+#[test]
+fn test_body_flags() {
+    let tester = SemanticTester::js(
+        "async function outer() {
+            const arrow = async () => { await a; this.b; };
+            async function inner() { for await (const x of xs) {} }
+            function* gen() { yield 1; }
+            class A { field = this; static { this; } [this.key] = 1; }
+        }",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+    let flags = |id: AstNodeId| nodes.get_node(id).flags();
+
+    let outer = flags(function_named(&semantic, "outer"));
+    // `await` and `yield` belong to the closest function, arrow functions included
+    assert!(!outer.has_await());
+    assert!(!outer.has_yield());
+    // `this` in arrow functions and computed class keys is the `this` of `outer`,
+    // but class field initializers and static blocks have their own
+    assert!(outer.has_this());
+
+    let arrow =
+        first_node_id(&semantic, |kind| matches!(kind, AstKind::ArrowFunctionExpression(_)));
+    assert!(flags(arrow).has_await());
+    assert!(flags(arrow).has_this());
+
+    let inner = flags(function_named(&semantic, "inner"));
+    assert!(inner.has_await());
+    assert!(!inner.has_this());
+
+    let gen = flags(function_named(&semantic, "gen"));
+    assert!(gen.has_yield());
+    assert!(!gen.has_await());
+    assert!(!gen.has_this());
+
+    let tester = SemanticTester::js(
+        "function f() { class A { field = this; static { this; } } return () => 1; }",
+    );
+    let semantic = tester.build();
+    assert!(!semantic.nodes().get_node(function_named(&semantic, "f")).flags().has_this());
+}
+
+#[test]
+fn test_exit_kinds_mixed_returns() {
+    let tester = SemanticTester::js(
+        "function mixed(x) { if (x) { return x; } return; }
+        function values(x) { if (x) { return 1; } else { return 2; } }",
+    );
+    let semantic = tester.build();
+
+    let mixed = semantic.function_exit_kinds(function_named(&semantic, "mixed"));
+    assert!(mixed.value_return);
+    assert!(mixed.bare_return);
+    assert!(!mixed.fall_through);
+    assert!(!mixed.always_returns_value());
+
+    let values = semantic.function_exit_kinds(function_named(&semantic, "values"));
+    assert_eq!(
+        values,
+        ExitAnalysis { value_return: true, bare_return: false, fall_through: false }
+    );
+    assert!(values.always_returns_value());
+}
+
+#[test]
+fn test_exit_kinds_fall_through() {
+    let tester = SemanticTester::js(
+        "function falls(x) { if (x) { return 1; } }
+        function empty() { function inner() { return 1; } }
+        function throws() { throw new Error(); }
+        const arrow = () => 1;",
+    );
+    let semantic = tester.build();
+
+    let falls = semantic.function_exit_kinds(function_named(&semantic, "falls"));
+    assert!(falls.value_return);
+    assert!(!falls.bare_return);
+    assert!(falls.fall_through);
+
+    // returns of nested functions don't count
+    let empty = semantic.function_exit_kinds(function_named(&semantic, "empty"));
+    assert_eq!(empty, ExitAnalysis { value_return: false, bare_return: false, fall_through: true });
+
+    let throws = semantic.function_exit_kinds(function_named(&semantic, "throws"));
+    assert_eq!(throws, ExitAnalysis::default());
+
+    let arrow =
+        first_node_id(&semantic, |kind| matches!(kind, AstKind::ArrowFunctionExpression(_)));
+    assert!(semantic.function_exit_kinds(arrow).always_returns_value());
+}
+
+#[test]
+fn test_is_callee() {
+    let source = "callee(arg); new Ctor(arg); (paren)(arg); obj.method(arg); tag`${arg}`; f(f);";
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let callees = nodes
+        .iter()
+        .filter(|node| nodes.is_callee(node.id()))
+        .map(|node| node.kind().span().source_text(source))
+        .collect::<Vec<_>>();
+    // The object of a callee and the arguments are not callees, but both a parenthesized callee
+    // and the expression inside the parentheses are
+    assert_eq!(callees, ["callee", "Ctor", "(paren)", "paren", "obj.method", "tag", "f"]);
+}
@@ -0,0 +1,68 @@
+mod util;
+
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNode, AstNodeId, Semantic};
+pub use util::SemanticTester;
+
+fn node_ids(semantic: &Semantic, predicate: fn(&AstKind) -> bool) -> Vec<AstNodeId> {
+    semantic.nodes().iter().filter(|node| predicate(&node.kind())).map(AstNode::id).collect()
+}
+
+#[test]
+fn test_break_in_nested_loop() {
+    let tester = SemanticTester::js(
+        "outer: for (const a of xs) { while (a) { break; continue outer; } break outer; }",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let for_of = node_ids(&semantic, |kind| matches!(kind, AstKind::ForOfStatement(_)))[0];
+    let while_stmt = node_ids(&semantic, |kind| matches!(kind, AstKind::WhileStatement(_)))[0];
+    let breaks = node_ids(&semantic, |kind| matches!(kind, AstKind::BreakStatement(_)));
+    let continue_stmt =
+        node_ids(&semantic, |kind| matches!(kind, AstKind::ContinueStatement(_)))[0];
+
+    assert_eq!(nodes.nearest_loop(breaks[0]), Some(while_stmt));
+    assert_eq!(nodes.nearest_loop(while_stmt), Some(for_of));
+    assert_eq!(nodes.nearest_loop(for_of), None);
+
+    assert_eq!(nodes.breakable_target(breaks[0]), Some(while_stmt));
+    assert_eq!(nodes.breakable_target(continue_stmt), Some(for_of));
+    assert_eq!(nodes.breakable_target(breaks[1]), Some(for_of));
+}
+
+#[test]
+fn test_break_in_switch() {
+    let tester = SemanticTester::js(
+        "while (x) { switch (x) { case 1: break; case 2: continue; } }
+        label: { break label; }",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let while_stmt = node_ids(&semantic, |kind| matches!(kind, AstKind::WhileStatement(_)))[0];
+    let switch_stmt = node_ids(&semantic, |kind| matches!(kind, AstKind::SwitchStatement(_)))[0];
+    let breaks = node_ids(&semantic, |kind| matches!(kind, AstKind::BreakStatement(_)));
+    let continue_stmt =
+        node_ids(&semantic, |kind| matches!(kind, AstKind::ContinueStatement(_)))[0];
+
+    assert_eq!(nodes.breakable_target(breaks[0]), Some(switch_stmt));
+    // `continue` skips the switch
+    assert_eq!(nodes.breakable_target(continue_stmt), Some(while_stmt));
+    assert_eq!(nodes.nearest_loop(breaks[0]), Some(while_stmt));
+
+    let block = nodes.parent_id(breaks[1]).unwrap();
+    assert!(matches!(nodes.kind(block), AstKind::BlockStatement(_)));
+    assert_eq!(nodes.breakable_target(breaks[1]), Some(block));
+    assert_eq!(nodes.nearest_loop(breaks[1]), None);
+}
+
+#[test]
+fn test_function_boundary() {
+    let tester = SemanticTester::js("while (x) { (function () { return; })(); }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let return_stmt = node_ids(&semantic, |kind| matches!(kind, AstKind::ReturnStatement(_)))[0];
+    assert_eq!(nodes.nearest_loop(return_stmt), None);
+}
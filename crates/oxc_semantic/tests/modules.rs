@@ -136,6 +136,47 @@ fn test_exported_default_class() {
     }
 }
 
+#[test]
+fn test_import_binding_references() {
+    let test = SemanticTester::ts(
+        "
+        import { Foo } from 'foo';
+        Foo = 1;
+        let x: Foo;
+        ",
+    );
+    let semantic = test.build();
+    let entry = &semantic.module_record().import_entries[0];
+    let references = semantic.import_binding_references(entry).collect::<Vec<_>>();
+    assert_eq!(references.len(), 2);
+
+    let (_, flag, is_type) = references[0];
+    assert!(flag.is_write_only());
+    assert!(!is_type);
+
+    let (_, flag, is_type) = references[1];
+    assert!(flag.is_type());
+    assert!(is_type);
+}
+
+#[test]
+fn test_require_calls() {
+    let test = SemanticTester::js(
+        "
+        import x from 'a';
+        const b = require('b');
+        require(name);
+        function f(require) { require('c'); }
+        ",
+    );
+    let semantic = test.build();
+    assert_eq!(semantic.module_record().requested_modules.len(), 1);
+    assert!(semantic.module_record().requested_modules.contains_key("a"));
+
+    let calls = semantic.require_calls().map(|(_, specifier)| specifier).collect::<Vec<_>>();
+    assert_eq!(calls, vec![Some("b"), None]);
+}
+
 // FIXME
 #[test]
 #[ignore]
@@ -1,6 +1,7 @@
 mod util;
 
-use oxc_semantic::ScopeFlags;
+use oxc_ast::AstKind;
+use oxc_semantic::{ScopeFlags, Semantic};
 pub use util::{Expect, SemanticTester};
 
 #[test]
@@ -106,3 +107,77 @@ fn test_switch_case() {
     .has_number_of_references(1)
     .test();
 }
+
+#[test]
+fn test_hoisted_declarations() {
+    let tester = SemanticTester::js(
+        "
+    function outer() {
+        if (cond) {
+            var a = 1;
+        }
+        var a;
+        let b = 2;
+        function inner() {}
+    }
+    ",
+    );
+    let semantic = tester.build();
+    let scopes = semantic.scopes();
+    let symbols = semantic.symbols();
+
+    let a = symbols.get_symbol_id_from_name("a").unwrap();
+    let b = symbols.get_symbol_id_from_name("b").unwrap();
+    let inner = symbols.get_symbol_id_from_name("inner").unwrap();
+    let outer_scope_id = symbols.get_scope_id(inner);
+
+    // `a` is declared in the `if` block but hoisted to the function scope, once
+    assert_ne!(symbols.get_scope_id(a), outer_scope_id);
+    assert_eq!(scopes.hoisted_vars(outer_scope_id).collect::<Vec<_>>(), [a]);
+    assert_eq!(scopes.hoisted_functions(outer_scope_id).collect::<Vec<_>>(), [inner]);
+    assert_eq!(scopes.hoisted_vars(symbols.get_scope_id(a)).count(), 0);
+
+    // Lexical bindings are not hoisted
+    assert_eq!(symbols.get_scope_id(b), outer_scope_id);
+    assert!(!scopes.hoisted_vars(outer_scope_id).any(|symbol_id| symbol_id == b));
+
+    let outer = symbols.get_symbol_id_from_name("outer").unwrap();
+    assert_eq!(scopes.hoisted_functions(scopes.root_scope_id()).collect::<Vec<_>>(), [outer]);
+    assert_eq!(scopes.hoisted_vars(scopes.root_scope_id()).count(), 0);
+}
+
+#[test]
+fn test_node_strict_mode() {
+    fn returns_strict_mode(semantic: &Semantic) -> Vec<bool> {
+        semantic
+            .nodes()
+            .iter()
+            .filter(|node| matches!(node.kind(), AstKind::ReturnStatement(_)))
+            .map(|node| node.is_in_strict_mode(semantic.scopes()))
+            .collect()
+    }
+
+    // Module code is always strict
+    let tester = SemanticTester::js("function f() { return 1; }");
+    assert_eq!(returns_strict_mode(&tester.build()), [true]);
+
+    let tester = SemanticTester::js(
+        r#"
+    function strict() { "use strict"; return 1; }
+    function sloppy() { return 2; }
+    class C { m() { return 3; } }
+    "#,
+    )
+    .with_module(false);
+    assert_eq!(returns_strict_mode(&tester.build()), [true, false, true]);
+
+    // Leaving a nested class or block doesn't leave strict mode code
+    let tester = SemanticTester::js(
+        r#"
+    class A { m() { class B {} return 1; } n() { return 2; } }
+    function f() { "use strict"; { { return 3; } } }
+    "#,
+    )
+    .with_module(false);
+    assert_eq!(returns_strict_mode(&tester.build()), [true, true, true]);
+}
@@ -1,6 +1,7 @@
 mod util;
 
-use oxc_semantic::SymbolFlags;
+use oxc_ast::AstKind;
+use oxc_semantic::{PatternBinding, Semantic, SymbolFlags};
 pub use util::SemanticTester;
 
 #[test]
@@ -115,3 +116,53 @@ fn test_export_flag() {
     tester.has_root_symbol("b").contains_flags(SymbolFlags::Export).test();
     tester.has_root_symbol("c").contains_flags(SymbolFlags::Export).test();
 }
+
+/// `(name, is_rest, has_default, depth)` of the names bound by the first variable declarator
+fn pattern_bindings<'a>(semantic: &'a Semantic) -> Vec<(&'a str, bool, bool, u32)> {
+    let declarator = semantic
+        .nodes()
+        .iter()
+        .find(|node| matches!(node.kind(), AstKind::VariableDeclarator(_)))
+        .expect("expected a variable declarator");
+    semantic
+        .pattern_bindings(declarator.id())
+        .into_iter()
+        .map(|PatternBinding { symbol_id, is_rest, has_default, depth }| {
+            (semantic.symbols().get_name(symbol_id), is_rest, has_default, depth)
+        })
+        .collect()
+}
+
+#[test]
+fn test_pattern_bindings_object() {
+    let tester = SemanticTester::js("const { a, b = 1, c: { d }, ...rest } = obj;");
+    let semantic = tester.build();
+    assert_eq!(
+        pattern_bindings(&semantic),
+        vec![
+            ("a", false, false, 1),
+            ("b", false, true, 1),
+            ("d", false, false, 2),
+            ("rest", true, false, 1)
+        ]
+    );
+}
+
+#[test]
+fn test_pattern_bindings_array() {
+    let tester = SemanticTester::js("const [x, , [y], z = 2, ...[w]] = arr;");
+    let semantic = tester.build();
+    assert_eq!(
+        pattern_bindings(&semantic),
+        vec![
+            ("x", false, false, 1),
+            ("y", false, false, 2),
+            ("z", false, true, 1),
+            ("w", true, false, 2)
+        ]
+    );
+
+    let tester = SemanticTester::js("let a = 1;");
+    let semantic = tester.build();
+    assert_eq!(pattern_bindings(&semantic), vec![("a", false, false, 0)]);
+}
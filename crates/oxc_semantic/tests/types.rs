@@ -0,0 +1,35 @@
+mod util;
+
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNodeId, Semantic};
+use util::first_node_id;
+pub use util::SemanticTester;
+
+fn reference_named(semantic: &Semantic, name: &str) -> AstNodeId {
+    first_node_id(
+        semantic,
+        |kind| matches!(kind, AstKind::IdentifierReference(ident) if ident.name == name),
+    )
+}
+
+#[test]
+fn test_is_in_type_context() {
+    let tester = SemanticTester::ts(
+        "
+        type T = typeof a | Array<B>;
+        interface I { c: C }
+        function f<U extends D>(x: E): F { return g as G; }
+        h<H>(i!);
+        class J extends k implements L<M> {}
+        ",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    for name in ["a", "B", "C", "D", "E", "F", "G", "H", "L", "M"] {
+        assert!(nodes.is_in_type_context(reference_named(&semantic, name)), "{name}");
+    }
+    for name in ["g", "h", "i", "k"] {
+        assert!(!nodes.is_in_type_context(reference_named(&semantic, name)), "{name}");
+    }
+}
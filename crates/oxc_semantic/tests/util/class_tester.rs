@@ -56,4 +56,22 @@ impl<'a> ClassTester<'a> {
         debug_assert!(method.is_some(), "Expected accessor `{name}` not found");
         self
     }
+
+    pub fn has_super_class(&self, name: &str) -> &Self {
+        let node_id = self.semantic.classes().get_node_id(self.class_id);
+        let super_class = self.semantic.nodes().class_super_class(node_id);
+        debug_assert!(
+            super_class.is_some_and(|expr| expr.is_specific_id(name)),
+            "Expected class to extend `{name}`"
+        );
+        debug_assert!(self.semantic.nodes().has_super_class(node_id));
+        self
+    }
+
+    pub fn has_no_super_class(&self) -> &Self {
+        let node_id = self.semantic.classes().get_node_id(self.class_id);
+        debug_assert!(self.semantic.nodes().class_super_class(node_id).is_none());
+        debug_assert!(!self.semantic.nodes().has_super_class(node_id));
+        self
+    }
 }
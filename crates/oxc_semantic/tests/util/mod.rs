@@ -5,8 +5,9 @@ use std::{path::PathBuf, sync::Arc};
 
 use itertools::Itertools;
 use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
 use oxc_diagnostics::{miette::NamedSource, Error};
-use oxc_semantic::{print_basic_block, Semantic, SemanticBuilder};
+use oxc_semantic::{print_basic_block, AstNode, AstNodeId, Semantic, SemanticBuilder};
 use oxc_span::SourceType;
 
 pub use class_tester::ClassTester;
@@ -184,3 +185,16 @@ impl<'a> SemanticTester<'a> {
             .collect()
     }
 }
+
+/// Finds the first node, in source order, whose kind matches `predicate`.
+///
+/// ## Fails
+/// If no node matches.
+#[allow(dead_code)] // Not every test target uses it
+pub fn first_node_id<F: Fn(&AstKind) -> bool>(semantic: &Semantic, predicate: F) -> AstNodeId {
+    semantic
+        .nodes()
+        .iter()
+        .find(|node| predicate(&node.kind()))
+        .map_or_else(|| panic!("no matching node"), AstNode::id)
+}
@@ -12,7 +12,9 @@ export type AstNodeId = number;
 export type NodeFlags = {
     JSDoc: 1,
     Class: 2,
-    HasYield: 4
+    HasYield: 4,
+    HasAwait: 8,
+    HasThis: 16
 };
 "#;
 
@@ -22,7 +24,8 @@ bitflags! {
         const JSDoc    = 1 << 0; // If the Node has a JSDoc comment attached
         const Class    = 1 << 1; // If Node is inside a class
         const HasYield = 1 << 2; // If function has yield statement
-
+        const HasAwait = 1 << 3; // If function has await expression or for-await statement
+        const HasThis  = 1 << 4; // If function has this expression, also in nested arrows
     }
 }
 
@@ -38,4 +41,12 @@ impl NodeFlags {
     pub fn has_yield(&self) -> bool {
         self.contains(Self::HasYield)
     }
+
+    pub fn has_await(&self) -> bool {
+        self.contains(Self::HasAwait)
+    }
+
+    pub fn has_this(&self) -> bool {
+        self.contains(Self::HasThis)
+    }
 }
@@ -1,5 +1,5 @@
 use std::{
-    cell::RefCell,
+    cell::{OnceCell, RefCell},
     mem,
     path::{Path, PathBuf},
     rc::Rc,
@@ -9,9 +9,14 @@ use oxc_allocator::Allocator;
 use oxc_ast::AstBuilder;
 use oxc_diagnostics::Error;
 use oxc_semantic::Semantic;
-use oxc_span::SourceType;
+use oxc_span::{Atom, CompactStr, SourceType};
+use oxc_syntax::identifier::is_identifier_part;
+use rustc_hash::FxHashSet;
 
-use crate::{helpers::module_imports::ModuleImports, TransformOptions};
+use crate::{
+    compiler_assumptions::CompilerAssumptions, helpers::module_imports::ModuleImports,
+    TransformOptions,
+};
 
 pub type Ctx<'a> = Rc<TransformCtx<'a>>;
 
@@ -20,6 +25,9 @@ pub struct TransformCtx<'a> {
 
     pub semantic: Semantic<'a>,
 
+    /// <https://babeljs.io/docs/assumptions>
+    pub assumptions: CompilerAssumptions,
+
     /// <https://babeljs.io/docs/options#filename>
     filename: String,
 
@@ -28,6 +36,12 @@ pub struct TransformCtx<'a> {
 
     errors: RefCell<Vec<Error>>,
 
+    /// Names generated by [`TransformCtx::generate_uid`] so far.
+    uids: RefCell<FxHashSet<CompactStr>>,
+
+    /// Names of all symbols in the program, collected on the first [`TransformCtx::generate_uid`].
+    symbol_names: OnceCell<FxHashSet<CompactStr>>,
+
     // Helpers
     /// Manage import statement globally
     pub module_imports: ModuleImports<'a>,
@@ -51,9 +65,12 @@ impl<'a> TransformCtx<'a> {
         Self {
             ast: AstBuilder::new(allocator),
             semantic,
+            assumptions: options.assumptions,
             filename,
             source_path,
             errors: RefCell::new(vec![]),
+            uids: RefCell::new(FxHashSet::default()),
+            symbol_names: OnceCell::new(),
             module_imports: ModuleImports::new(allocator),
         }
     }
@@ -79,4 +96,51 @@ impl<'a> TransformCtx<'a> {
     pub fn source_type(&self) -> &SourceType {
         self.semantic.source_type()
     }
+
+    /// Generates a name for a temporary variable based on `name`, e.g. `_obj`, `_obj2`,
+    /// which does not clash with any binding or reference in the program.
+    /// Characters which can't be part of an identifier are removed, `foo-bar` -> `_fooBar`.
+    ///
+    /// <https://github.com/babel/babel/blob/main/packages/babel-traverse/src/scope/index.ts> `generateUid`
+    pub fn generate_uid(&self, name: &str) -> Atom<'a> {
+        let name = to_identifier(name);
+        let name = name.trim_start_matches('_').trim_end_matches(|c: char| c.is_ascii_digit());
+        let name = if name.is_empty() { "ref" } else { name };
+        let mut uids = self.uids.borrow_mut();
+        let mut i = 1;
+        loop {
+            let uid = if i > 1 { format!("_{name}{i}") } else { format!("_{name}") };
+            if !uids.contains(uid.as_str()) && !self.is_name_taken(&uid) {
+                uids.insert(CompactStr::from(uid.as_str()));
+                return self.ast.new_atom(&uid);
+            }
+            i += 1;
+        }
+    }
+
+    fn is_name_taken(&self, name: &str) -> bool {
+        let symbol_names = self
+            .symbol_names
+            .get_or_init(|| self.semantic.symbols().names.iter().cloned().collect());
+        symbol_names.contains(name)
+            || self.semantic.scopes().root_unresolved_references().contains_key(name)
+    }
+}
+
+/// Removes the characters which can't be part of an identifier and capitalizes the character
+/// after each of them, like Babel's `toIdentifier`.
+fn to_identifier(name: &str) -> String {
+    let mut identifier = String::with_capacity(name.len());
+    let mut capitalize = false;
+    for c in name.chars() {
+        if !is_identifier_part(c) {
+            capitalize = !identifier.is_empty();
+        } else if capitalize {
+            identifier.extend(c.to_uppercase());
+            capitalize = false;
+        } else {
+            identifier.push(c);
+        }
+    }
+    identifier
 }
@@ -0,0 +1,63 @@
+mod spread;
+
+use oxc_ast::ast::*;
+
+use crate::{context::Ctx, options::TransformTarget};
+
+pub use self::spread::Spread;
+
+/// ES2015 syntax, lowered when the target is older than ES2015
+///
+/// This includes the following plugins:
+///
+/// * [plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread)
+pub struct ES2015<'a> {
+    enabled: bool,
+    spread: Spread<'a>,
+}
+
+// Constructors
+impl<'a> ES2015<'a> {
+    pub fn new(target: TransformTarget, ctx: &Ctx<'a>) -> Self {
+        Self { enabled: target < TransformTarget::ES2015, spread: Spread::new(ctx) }
+    }
+}
+
+// Transforms
+impl<'a> ES2015<'a> {
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.spread.transform_program(program);
+        }
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.spread.transform_program_on_exit(program);
+        }
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        if self.enabled {
+            self.spread.transform_arrow_expression_on_exit(expr);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.enabled {
+            self.spread.transform_expression(expr);
+        }
+    }
+
+    pub fn transform_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        if self.enabled {
+            self.spread.transform_function_body(body);
+        }
+    }
+
+    pub fn transform_function_body_on_exit(&mut self, body: &mut FunctionBody<'a>) {
+        if self.enabled {
+            self.spread.transform_function_body_on_exit(body);
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, Span, SPAN};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{context::Ctx, helpers::temporary_variables::TemporaryVariables};
+
+/// [plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread)
+///
+/// In:  `foo(...args); foo(a, ...b, c); obj.m(...args); new Foo(...args);`
+/// Out: `var _obj; foo.apply(void 0, _toConsumableArray(args));
+///       foo.apply(void 0, [a].concat(_toConsumableArray(b), [c]));
+///       (_obj = obj).m.apply(_obj, _toConsumableArray(args));
+///       new (Function.prototype.bind.apply(Foo, [null].concat(_toConsumableArray(args))))();`
+///
+/// Spread values other than array literals are turned into arrays with the `_toConsumableArray`
+/// helper, declared at the top of the program, so iterables such as sets and strings are spread
+/// by their iterator. With the `iterableIsArray` assumption they are used as they are instead.
+/// `arguments` is copied into an array with `Array.prototype.slice` where `concat` would not
+/// flatten it.
+///
+/// The object of a member callee is the `this` of the call and is evaluated only once, by
+/// storing it in a temporary variable declared at the top of the enclosing function unless it
+/// is `this`, `super` or a binding which is never reassigned.
+pub struct Spread<'a> {
+    ctx: Ctx<'a>,
+    vars: TemporaryVariables<'a>,
+    /// Name of the `_toConsumableArray` helper once it is used.
+    to_consumable_array: Option<Atom<'a>>,
+}
+
+impl<'a> Spread<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), vars: TemporaryVariables::new(ctx), to_consumable_array: None }
+    }
+
+    pub fn transform_program(&mut self, _program: &mut Program<'a>) {
+        self.vars.enter_scope();
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        self.vars.exit_scope(&mut program.body);
+        if let Some(name) = self.to_consumable_array.clone() {
+            program.body.insert(0, self.create_to_consumable_array(name));
+        }
+    }
+
+    pub fn transform_function_body(&mut self, _body: &mut FunctionBody<'a>) {
+        self.vars.enter_scope();
+    }
+
+    pub fn transform_function_body_on_exit(&mut self, body: &mut FunctionBody<'a>) {
+        self.vars.exit_scope(&mut body.statements);
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        self.vars.exit_arrow_expression(expr);
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::CallExpression(call) if has_spread(&call.arguments) => {
+                self.transform_call_expression(call);
+            }
+            Expression::ChainExpression(chain) => {
+                if let ChainElement::CallExpression(call) = &mut chain.expression {
+                    if has_spread(&call.arguments) {
+                        self.transform_call_expression(call);
+                    }
+                }
+            }
+            Expression::NewExpression(new_expr) if has_spread(&new_expr.arguments) => {
+                let span = new_expr.span;
+                let callee = self.ctx.ast.move_expression(&mut new_expr.callee);
+                let null = self.ctx.ast.literal_null_expression(NullLiteral::new(SPAN));
+                let arguments = self.build_arguments(&mut new_expr.arguments, Some(null));
+                *expr = self.create_bound_new_expression(span, callee, arguments);
+            }
+            _ => {}
+        }
+    }
+
+    /// `foo(...args)` -> `foo.apply(void 0, args)`
+    /// `obj.m(...args)` -> `(_obj = obj).m.apply(_obj, args)`
+    /// `foo?.(...args)` -> `foo?.apply(void 0, args)`
+    fn transform_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        // `super(...args)` can't be called with `apply`
+        if matches!(call.callee, Expression::Super(_)) {
+            return;
+        }
+
+        // `(obj.m)(...args)` is called with `obj` as `this` as well
+        let this_arg = match without_parentheses_mut(&mut call.callee) {
+            Expression::MemberExpression(member) => {
+                let object = member_object_mut(member);
+                if matches!(object, Expression::Super(_)) {
+                    self.ctx.ast.this_expression(SPAN)
+                } else {
+                    let (value, reference) = self.vars.memoise(object, true);
+                    *object = value;
+                    reference
+                }
+            }
+            _ => self.ctx.ast.void_0(),
+        };
+        let arguments = self.build_arguments(&mut call.arguments, None);
+        call.arguments.clear();
+
+        let ast = &self.ctx.ast;
+        let callee = ast.move_expression(&mut call.callee);
+        // The optional call checks the callee, which is now the object of `apply`
+        let optional = mem::replace(&mut call.optional, false);
+        call.callee = ast.static_member_expression(
+            SPAN,
+            callee,
+            ast.identifier_name(SPAN, "apply"),
+            optional,
+        );
+        call.arguments.push(Argument::Expression(this_arg));
+        call.arguments.push(Argument::Expression(arguments));
+    }
+
+    /// `new (Function.prototype.bind.apply(Foo, arguments))()`
+    fn create_bound_new_expression(
+        &self,
+        span: Span,
+        callee: Expression<'a>,
+        arguments: Expression<'a>,
+    ) -> Expression<'a> {
+        let ast = &self.ctx.ast;
+        let mut bind =
+            ast.identifier_reference_expression(ast.identifier_reference(SPAN, "Function"));
+        for property in ["prototype", "bind", "apply"] {
+            bind = ast.static_member_expression(
+                SPAN,
+                bind,
+                ast.identifier_name(SPAN, property),
+                false,
+            );
+        }
+        let mut bind_arguments = ast.new_vec_with_capacity(2);
+        bind_arguments.push(Argument::Expression(callee));
+        bind_arguments.push(Argument::Expression(arguments));
+        let constructor = ast.call_expression(SPAN, bind, bind_arguments, false, None);
+        ast.new_expression(span, constructor, ast.new_vec(), None)
+    }
+
+    /// Turns the arguments into a single array, `leading` is put in front of them.
+    ///
+    /// `(...args)` -> `args`, `(a, ...b, c)` -> `[a].concat(b, [c])`,
+    /// `(...a, ...b)` -> `[].concat(a, b)`, with the spread values turned into arrays.
+    fn build_arguments(
+        &mut self,
+        arguments: &mut Vec<'a, Argument<'a>>,
+        leading: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let ctx = Rc::clone(&self.ctx);
+        let ast = &ctx.ast;
+
+        // `apply` takes any array-like, including `arguments`
+        if leading.is_none() && arguments.len() == 1 {
+            if let Some(Argument::SpreadElement(spread)) = arguments.first_mut() {
+                let argument = ast.move_expression(&mut spread.argument);
+                if argument.is_specific_id("arguments") {
+                    return argument;
+                }
+                return self.array_value(argument);
+            }
+        }
+
+        let mut parts = std::vec::Vec::new();
+        let mut elements = ast.new_vec();
+        if let Some(leading) = leading {
+            elements.push(ArrayExpressionElement::Expression(leading));
+        }
+        for argument in arguments.drain(..) {
+            match argument {
+                Argument::SpreadElement(spread) => {
+                    if !elements.is_empty() {
+                        let elements = mem::replace(&mut elements, ast.new_vec());
+                        parts.push(ast.array_expression(SPAN, elements, None));
+                    }
+                    parts.push(self.spread_value(spread.unbox().argument));
+                }
+                Argument::Expression(expr) => {
+                    elements.push(ArrayExpressionElement::Expression(expr));
+                }
+            }
+        }
+        if !elements.is_empty() {
+            parts.push(ast.array_expression(SPAN, elements, None));
+        }
+
+        if !matches!(parts.first(), Some(Expression::ArrayExpression(_))) {
+            parts.insert(0, ast.array_expression(SPAN, ast.new_vec(), None));
+        }
+        let mut parts = parts.into_iter();
+        let first = parts.next().unwrap();
+        let concat =
+            ast.static_member_expression(SPAN, first, ast.identifier_name(SPAN, "concat"), false);
+        let rest = ast.new_vec_from_iter(parts.map(Argument::Expression));
+        ast.call_expression(SPAN, concat, rest, false, None)
+    }
+
+    /// `concat` only flattens arrays, `arguments` -> `Array.prototype.slice.call(arguments)`
+    fn spread_value(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        if !expr.is_specific_id("arguments") {
+            return self.array_value(expr);
+        }
+        let ast = &self.ctx.ast;
+        let mut slice =
+            ast.identifier_reference_expression(ast.identifier_reference(SPAN, "Array"));
+        for property in ["prototype", "slice", "call"] {
+            slice = ast.static_member_expression(
+                SPAN,
+                slice,
+                ast.identifier_name(SPAN, property),
+                false,
+            );
+        }
+        let mut arguments = ast.new_vec_with_capacity(1);
+        arguments.push(Argument::Expression(expr));
+        ast.call_expression(SPAN, slice, arguments, false, None)
+    }
+
+    /// `args` -> `_toConsumableArray(args)`, unless the value is known to be an array.
+    fn array_value(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        if self.ctx.assumptions.iterable_is_array || matches!(expr, Expression::ArrayExpression(_))
+        {
+            return expr;
+        }
+        let ctx = &self.ctx;
+        let name = self
+            .to_consumable_array
+            .get_or_insert_with(|| ctx.generate_uid("toConsumableArray"))
+            .clone();
+        let ast = &self.ctx.ast;
+        let callee = ast.identifier_reference_expression(IdentifierReference::new(SPAN, name));
+        call(ast, callee, [expr])
+    }
+
+    /// ```js
+    /// function _toConsumableArray(r) {
+    ///   if (Array.isArray(r)) return r.slice();
+    ///   if ("undefined" != typeof Symbol && null != r[Symbol.iterator] || null != r["@@iterator"])
+    ///     return Array.from(r);
+    ///   throw new TypeError("Invalid attempt to spread non-iterable instance. ...");
+    /// }
+    /// ```
+    fn create_to_consumable_array(&self, name: Atom<'a>) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let ident = |name: &str| identifier(ast, name);
+        let string = |value: &str| {
+            ast.literal_string_expression(StringLiteral::new(SPAN, ast.new_atom(value)))
+        };
+        let not_null = |expr: Expression<'a>| {
+            let null = ast.literal_null_expression(NullLiteral::new(SPAN));
+            ast.binary_expression(SPAN, null, BinaryOperator::Inequality, expr)
+        };
+
+        let is_array = call(ast, member(ast, ident("Array"), "isArray"), [ident("r")]);
+        let slice = call(ast, member(ast, ident("r"), "slice"), []);
+        let copy_array =
+            ast.if_statement(SPAN, is_array, ast.return_statement(SPAN, Some(slice)), None);
+
+        let has_symbol = ast.binary_expression(
+            SPAN,
+            string("undefined"),
+            BinaryOperator::Inequality,
+            ast.unary_expression(SPAN, UnaryOperator::Typeof, ident("Symbol")),
+        );
+        let iterator = member(ast, ident("Symbol"), "iterator");
+        let has_iterator = ast.logical_expression(
+            SPAN,
+            has_symbol,
+            LogicalOperator::And,
+            not_null(ast.computed_member_expression(SPAN, ident("r"), iterator, false)),
+        );
+        let has_legacy_iterator =
+            not_null(ast.computed_member_expression(SPAN, ident("r"), string("@@iterator"), false));
+        let is_iterable =
+            ast.logical_expression(SPAN, has_iterator, LogicalOperator::Or, has_legacy_iterator);
+        let from = call(ast, member(ast, ident("Array"), "from"), [ident("r")]);
+        let copy_iterable =
+            ast.if_statement(SPAN, is_iterable, ast.return_statement(SPAN, Some(from)), None);
+
+        let message = "Invalid attempt to spread non-iterable instance.\nIn order to be iterable, \
+                       non-array objects must have a [Symbol.iterator]() method.";
+        let error = ast.new_expression(
+            SPAN,
+            ident("TypeError"),
+            ast.new_vec_single(Argument::Expression(string(message))),
+            None,
+        );
+        let statements =
+            ast.new_vec_from_iter([copy_array, copy_iterable, ast.throw_statement(SPAN, error)]);
+
+        let id = ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, ast.new_atom("r")));
+        let param = ast.plain_formal_parameter(SPAN, ast.binding_pattern(id, None, false));
+        let params = ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ast.new_vec_single(param),
+            None,
+        );
+        let body = ast.function_body(SPAN, ast.new_vec(), statements);
+        let id = Some(BindingIdentifier::new(SPAN, name));
+        let func =
+            ast.plain_function(FunctionType::FunctionDeclaration, SPAN, id, params, Some(body));
+        ast.function_declaration(func)
+    }
+}
+
+fn identifier<'a>(ast: &AstBuilder<'a>, name: &str) -> Expression<'a> {
+    ast.identifier_reference_expression(ast.identifier_reference(SPAN, name))
+}
+
+fn member<'a>(ast: &AstBuilder<'a>, object: Expression<'a>, property: &str) -> Expression<'a> {
+    ast.static_member_expression(SPAN, object, ast.identifier_name(SPAN, property), false)
+}
+
+fn call<'a, const N: usize>(
+    ast: &AstBuilder<'a>,
+    callee: Expression<'a>,
+    arguments: [Expression<'a>; N],
+) -> Expression<'a> {
+    let arguments = ast.new_vec_from_iter(arguments.into_iter().map(Argument::Expression));
+    ast.call_expression(SPAN, callee, arguments, false, None)
+}
+
+fn has_spread(arguments: &[Argument]) -> bool {
+    arguments.iter().any(|argument| matches!(argument, Argument::SpreadElement(_)))
+}
+
+fn without_parentheses_mut<'a, 'b>(expr: &'b mut Expression<'a>) -> &'b mut Expression<'a> {
+    match expr {
+        Expression::ParenthesizedExpression(paren) => {
+            without_parentheses_mut(&mut paren.expression)
+        }
+        _ => expr,
+    }
+}
+
+fn member_object_mut<'a, 'b>(member: &'b mut MemberExpression<'a>) -> &'b mut Expression<'a> {
+    match member {
+        MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+        MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+        MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        helpers::tester::{print, print_with_options},
+        CompilerAssumptions, TransformOptions, TransformTarget,
+    };
+
+    const TO_CONSUMABLE_ARRAY: &str = "function _toConsumableArray(r) {
+        if (Array.isArray(r)) return r.slice();
+        if ('undefined' != typeof Symbol && null != r[Symbol.iterator] || null != r['@@iterator']) return Array.from(r);
+        throw new TypeError('Invalid attempt to spread non-iterable instance.\\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.');
+    }";
+
+    /// With the `iterableIsArray` assumption
+    fn test(source_text: &str, expected: &str) {
+        let assumptions = CompilerAssumptions { iterable_is_array: true, ..Default::default() };
+        let options =
+            TransformOptions { target: TransformTarget::ES5, assumptions, ..Default::default() };
+        assert_eq!(print_with_options(source_text, Some(options)), print(expected, None));
+    }
+
+    /// Without assumptions, `expected` is preceded by the `_toConsumableArray` helper
+    fn test_iterable(source_text: &str, expected: &str) {
+        let expected = format!("{TO_CONSUMABLE_ARRAY}\n{expected}");
+        assert_eq!(print(source_text, Some(TransformTarget::ES5)), print(&expected, None));
+    }
+
+    #[test]
+    fn iterable() {
+        test_iterable("foo(...args);", "foo.apply(void 0, _toConsumableArray(args));");
+        test_iterable("foo(...new Set(s));", "foo.apply(void 0, _toConsumableArray(new Set(s)));");
+        test_iterable("foo(...'str');", "foo.apply(void 0, _toConsumableArray('str'));");
+        test_iterable(
+            "foo(a, ...b, ...[c]);",
+            "foo.apply(void 0, [a].concat(_toConsumableArray(b), [c]));",
+        );
+        test_iterable(
+            "new Foo(...args);",
+            "new (Function.prototype.bind.apply(Foo, [null].concat(_toConsumableArray(args))))();",
+        );
+
+        let source = "let _toConsumableArray; foo(...args);";
+        let expected = format!(
+            "{}\nlet _toConsumableArray; foo.apply(void 0, _toConsumableArray2(args));",
+            TO_CONSUMABLE_ARRAY.replace("_toConsumableArray", "_toConsumableArray2")
+        );
+        assert_eq!(print(source, Some(TransformTarget::ES5)), print(&expected, None));
+    }
+
+    #[test]
+    fn iterable_without_helper() {
+        let test = |source: &str, expected: &str| {
+            assert_eq!(print(source, Some(TransformTarget::ES5)), print(expected, None));
+        };
+        test("foo(...[a, b]);", "foo.apply(void 0, [a, b]);");
+        test("function f() { g(...arguments); }", "function f() { g.apply(void 0, arguments); }");
+    }
+
+    #[test]
+    fn call() {
+        test("foo(...args);", "foo.apply(void 0, args);");
+        test("foo(a, ...b, c);", "foo.apply(void 0, [a].concat(b, [c]));");
+        test("foo(...a, ...b);", "foo.apply(void 0, [].concat(a, b));");
+        test("foo(...a, b, ...c);", "foo.apply(void 0, [].concat(a, [b], c));");
+        test("foo(...bar(...args));", "foo.apply(void 0, bar.apply(void 0, args));");
+    }
+
+    #[test]
+    fn member_call() {
+        test("let obj; obj.m(...args);", "let obj; obj.m.apply(obj, args);");
+        test("obj.m(...args);", "var _obj; (_obj = obj).m.apply(_obj, args);");
+        test("a.b.c(a, ...d);", "var _a$b; (_a$b = a.b).c.apply(_a$b, [a].concat(d));");
+        test(
+            "a['foo-bar'].c(...d);",
+            "var _a$fooBar; (_a$fooBar = a['foo-bar']).c.apply(_a$fooBar, d);",
+        );
+        test(
+            "class A extends B { m() { this.n(...args); super.m(...args); } }",
+            "class A extends B { m() { this.n.apply(this, args); super.m.apply(this, args); } }",
+        );
+        test(
+            "const f = () => obj.m(...args);",
+            "const f = () => { var _obj; return (_obj = obj).m.apply(_obj, args); };",
+        );
+    }
+
+    #[test]
+    fn optional_call() {
+        test("foo?.(...args);", "foo?.apply(void 0, args);");
+        test("obj.m?.(...args);", "var _obj; (_obj = obj).m?.apply(_obj, args);");
+        test("let obj; obj?.m(...args);", "let obj; obj?.m.apply(obj, args);");
+        test("let a; a?.b(...c).d;", "let a; a?.b.apply(a, c).d;");
+    }
+
+    #[test]
+    fn parenthesized_callee() {
+        test("let obj; (obj.m)(...args);", "let obj; obj.m.apply(obj, args);");
+        test("(obj.m)(...args);", "var _obj; (_obj = obj).m.apply(_obj, args);");
+    }
+
+    #[test]
+    fn new_expression() {
+        test(
+            "new Foo(...args);",
+            "new (Function.prototype.bind.apply(Foo, [null].concat(args)))();",
+        );
+        test(
+            "new Foo(a, ...b, c);",
+            "new (Function.prototype.bind.apply(Foo, [null, a].concat(b, [c])))();",
+        );
+    }
+
+    #[test]
+    fn arguments() {
+        test("function f() { g(...arguments); }", "function f() { g.apply(void 0, arguments); }");
+        test(
+            "function f() { g(a, ...arguments); }",
+            "function f() { g.apply(void 0, [a].concat(Array.prototype.slice.call(arguments))); }",
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        test(
+            "class A extends B { constructor() { super(...args); } }",
+            "class A extends B { constructor() { super(...args); } }",
+        );
+        test("foo(a, b);", "foo(a, b);");
+        let source = "foo(...args);";
+        assert_eq!(print(source, Some(TransformTarget::ES2015)), print(source, None));
+    }
+}
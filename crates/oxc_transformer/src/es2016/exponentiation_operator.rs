@@ -0,0 +1,235 @@
+use std::rc::Rc;
+
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+
+use crate::{context::Ctx, helpers::temporary_variables::TemporaryVariables};
+
+/// [plugin-transform-exponentiation-operator](https://babel.dev/docs/babel-plugin-transform-exponentiation-operator)
+///
+/// In:  `a ** b; a **= b; obj.x **= 2;`
+/// Out: `var _obj; Math.pow(a, b); a = Math.pow(a, b); (_obj = obj).x = Math.pow(_obj.x, 2);`
+///
+/// The object and the computed key of a member target are evaluated only once, by storing them
+/// in temporary variables declared at the top of the enclosing function. Bindings which are
+/// never reassigned, `this`, `super` and literals are reused as they are.
+///
+/// `Math.pow` doesn't accept BigInts, so `**` is kept when an operand is a BigInt literal.
+pub struct ExponentiationOperator<'a> {
+    ctx: Ctx<'a>,
+    vars: TemporaryVariables<'a>,
+}
+
+impl<'a> ExponentiationOperator<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), vars: TemporaryVariables::new(ctx) }
+    }
+
+    pub fn transform_program(&mut self, _program: &mut Program<'a>) {
+        self.vars.enter_scope();
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        self.vars.exit_scope(&mut program.body);
+    }
+
+    pub fn transform_function_body(&mut self, _body: &mut FunctionBody<'a>) {
+        self.vars.enter_scope();
+    }
+
+    pub fn transform_function_body_on_exit(&mut self, body: &mut FunctionBody<'a>) {
+        self.vars.exit_scope(&mut body.statements);
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        self.vars.exit_arrow_expression(expr);
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `a ** b` -> `Math.pow(a, b)`
+            Expression::BinaryExpression(binary)
+                if binary.operator == BinaryOperator::Exponential =>
+            {
+                if is_bigint(&binary.left) || is_bigint(&binary.right) {
+                    return;
+                }
+                let span = binary.span;
+                let left = self.ctx.ast.move_expression(&mut binary.left);
+                let right = self.ctx.ast.move_expression(&mut binary.right);
+                *expr = self.math_pow(span, left, right);
+            }
+            Expression::AssignmentExpression(assign)
+                if assign.operator == AssignmentOperator::Exponential =>
+            {
+                if is_bigint(&assign.right) {
+                    return;
+                }
+                let AssignmentTarget::SimpleAssignmentTarget(target) = &mut assign.left else {
+                    return;
+                };
+
+                let (write_target, left) = match target {
+                    // `a **= b` -> `a = Math.pow(a, b)`
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                        let ast = &self.ctx.ast;
+                        let write_target =
+                            ast.simple_assignment_target_identifier(ast.copy(&**ident));
+                        let left = ast.identifier_reference_expression(ast.copy(&**ident));
+                        (write_target, left)
+                    }
+                    // `a.b **= c` -> `a.b = Math.pow(a.b, c)`
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                        let (write, read) = self.split_member_expression(member);
+                        let ast = &self.ctx.ast;
+                        (
+                            ast.simple_assignment_target_member_expression(write),
+                            ast.member_expression(read),
+                        )
+                    }
+                    _ => return,
+                };
+
+                let span = assign.span;
+                let right = self.ctx.ast.move_expression(&mut assign.right);
+                let right = self.math_pow(SPAN, left, right);
+                *expr = self.ctx.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    write_target,
+                    right,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// `Math.pow(left, right)`
+    fn math_pow(&self, span: Span, left: Expression<'a>, right: Expression<'a>) -> Expression<'a> {
+        let ast = &self.ctx.ast;
+        let math = ast.identifier_reference_expression(ast.identifier_reference(SPAN, "Math"));
+        let callee =
+            ast.static_member_expression(SPAN, math, ast.identifier_name(SPAN, "pow"), false);
+        let arguments =
+            ast.new_vec_from_iter([Argument::Expression(left), Argument::Expression(right)]);
+        ast.call_expression(span, callee, arguments, false, None)
+    }
+
+    /// Splits `obj[key]` into the member assigned to, which is evaluated first, and the member
+    /// read afterwards, `(_obj = obj)[_key = key]` and `_obj[_key]`.
+    fn split_member_expression(
+        &mut self,
+        member: &mut MemberExpression<'a>,
+    ) -> (MemberExpression<'a>, MemberExpression<'a>) {
+        let span = member.span();
+        match member {
+            MemberExpression::StaticMemberExpression(expr) => {
+                let (object, object_ref) = self.vars.memoise(&mut expr.object, true);
+                let write = self.ctx.ast.static_member(span, object, expr.property.clone(), false);
+                let read =
+                    self.ctx.ast.static_member(SPAN, object_ref, expr.property.clone(), false);
+                (write, read)
+            }
+            MemberExpression::ComputedMemberExpression(expr) => {
+                let (object, object_ref) = self.vars.memoise(&mut expr.object, true);
+                let (key, key_ref) = self.vars.memoise(&mut expr.expression, false);
+                let write = self.ctx.ast.computed_member(span, object, key, false);
+                let read = self.ctx.ast.computed_member(SPAN, object_ref, key_ref, false);
+                (write, read)
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                let (object, object_ref) = self.vars.memoise(&mut expr.object, true);
+                let write = self.ctx.ast.private_field(span, object, expr.field.clone(), false);
+                let read = self.ctx.ast.private_field(SPAN, object_ref, expr.field.clone(), false);
+                (write, read)
+            }
+        }
+    }
+}
+
+fn is_bigint(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::BigintLiteral(_) => true,
+        Expression::UnaryExpression(unary) => is_bigint(&unary.argument),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{helpers::tester::print, TransformTarget};
+
+    fn test(source_text: &str, expected: &str) {
+        assert_eq!(print(source_text, Some(TransformTarget::ES2015)), print(expected, None));
+    }
+
+    #[test]
+    fn binary() {
+        test("a ** b;", "Math.pow(a, b);");
+        test("a ** b ** c;", "Math.pow(a, Math.pow(b, c));");
+        test("(a + b) ** c;", "Math.pow((a + b), c);");
+        test("a * b ** c;", "a * Math.pow(b, c);");
+        test("f(a ** 2);", "f(Math.pow(a, 2));");
+    }
+
+    #[test]
+    fn identifier() {
+        test("let a; a **= b;", "let a; a = Math.pow(a, b);");
+        test("let a; a **= b ** c;", "let a; a = Math.pow(a, Math.pow(b, c));");
+    }
+
+    #[test]
+    fn member() {
+        test("obj.x **= 2;", "var _obj; (_obj = obj).x = Math.pow(_obj.x, 2);");
+        test("let obj; obj.x **= 2;", "let obj; obj.x = Math.pow(obj.x, 2);");
+        test("a.b.c **= d;", "var _a$b; (_a$b = a.b).c = Math.pow(_a$b.c, d);");
+        test(
+            "class C { #x; m() { this.#x **= 2; } }",
+            "class C { #x; m() { this.#x = Math.pow(this.#x, 2); } }",
+        );
+    }
+
+    #[test]
+    fn computed_member() {
+        test("a[f()] **= b;", "var _a, _f; (_a = a)[_f = f()] = Math.pow(_a[_f], b);");
+        test("let a; a[f()] **= b;", "var _f; let a; a[_f = f()] = Math.pow(a[_f], b);");
+        test("let a, k; a[k] **= b;", "let a, k; a[k] = Math.pow(a[k], b);");
+    }
+
+    #[test]
+    fn computed_string_key() {
+        test(
+            "a['foo-bar'].c **= 2;",
+            "var _a$fooBar; (_a$fooBar = a['foo-bar']).c = Math.pow(_a$fooBar.c, 2);",
+        );
+        test("a['-'].c **= 2;", "var _a$; (_a$ = a['-']).c = Math.pow(_a$.c, 2);");
+    }
+
+    #[test]
+    fn temporary_variables() {
+        test(
+            "function f() { obj.x **= 2; }",
+            "function f() { var _obj; (_obj = obj).x = Math.pow(_obj.x, 2); }",
+        );
+        test(
+            "() => obj.x **= 2;",
+            "() => { var _obj; return (_obj = obj).x = Math.pow(_obj.x, 2); };",
+        );
+    }
+
+    #[test]
+    fn bigint() {
+        test("1n ** 2n;", "1n ** 2n;");
+        test("a ** -(1n);", "a ** -(1n);");
+        test("let a; a **= 2n;", "let a; a **= 2n;");
+        // Only literals are known to be BigInts
+        test("let a = 2n; a ** b;", "let a = 2n; Math.pow(a, b);");
+    }
+
+    #[test]
+    fn target() {
+        let source_text = "a ** b; obj.x **= 2;";
+        assert_eq!(print(source_text, Some(TransformTarget::ES2016)), print(source_text, None));
+    }
+}
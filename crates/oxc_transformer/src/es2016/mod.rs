@@ -0,0 +1,66 @@
+mod exponentiation_operator;
+
+use oxc_ast::ast::*;
+
+use crate::{context::Ctx, options::TransformTarget};
+
+pub use self::exponentiation_operator::ExponentiationOperator;
+
+/// ES2016 syntax, lowered when the target is older than ES2016
+///
+/// This includes the following plugins:
+///
+/// * [plugin-transform-exponentiation-operator](https://babel.dev/docs/babel-plugin-transform-exponentiation-operator)
+pub struct ES2016<'a> {
+    enabled: bool,
+    exponentiation_operator: ExponentiationOperator<'a>,
+}
+
+// Constructors
+impl<'a> ES2016<'a> {
+    pub fn new(target: TransformTarget, ctx: &Ctx<'a>) -> Self {
+        Self {
+            enabled: target < TransformTarget::ES2016,
+            exponentiation_operator: ExponentiationOperator::new(ctx),
+        }
+    }
+}
+
+// Transforms
+impl<'a> ES2016<'a> {
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.exponentiation_operator.transform_program(program);
+        }
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.exponentiation_operator.transform_program_on_exit(program);
+        }
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        if self.enabled {
+            self.exponentiation_operator.transform_arrow_expression_on_exit(expr);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.enabled {
+            self.exponentiation_operator.transform_expression(expr);
+        }
+    }
+
+    pub fn transform_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        if self.enabled {
+            self.exponentiation_operator.transform_function_body(body);
+        }
+    }
+
+    pub fn transform_function_body_on_exit(&mut self, body: &mut FunctionBody<'a>) {
+        if self.enabled {
+            self.exponentiation_operator.transform_function_body_on_exit(body);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::rc::Rc;
+
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+
+use crate::{context::Ctx, helpers::temporary_variables::TemporaryVariables};
+
+/// [plugin-transform-logical-assignment-operators](https://babel.dev/docs/babel-plugin-transform-logical-assignment-operators)
+///
+/// In:  `a ||= b; obj.x ??= y; a[f()] &&= b;`
+/// Out: `var _a, _f; a || (a = b); obj.x ?? (obj.x = y); (_a = a)[_f = f()] && (_a[_f] = b);`
+///
+/// The object and the computed key of a member target are evaluated only once, by storing them
+/// in temporary variables declared at the top of the enclosing function. Bindings which are
+/// never reassigned, `this`, `super` and literals are reused as they are.
+pub struct LogicalAssignmentOperators<'a> {
+    ctx: Ctx<'a>,
+    vars: TemporaryVariables<'a>,
+}
+
+impl<'a> LogicalAssignmentOperators<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), vars: TemporaryVariables::new(ctx) }
+    }
+
+    pub fn transform_program(&mut self, _program: &mut Program<'a>) {
+        self.vars.enter_scope();
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        self.vars.exit_scope(&mut program.body);
+    }
+
+    pub fn transform_function_body(&mut self, _body: &mut FunctionBody<'a>) {
+        self.vars.enter_scope();
+    }
+
+    pub fn transform_function_body_on_exit(&mut self, body: &mut FunctionBody<'a>) {
+        self.vars.exit_scope(&mut body.statements);
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        self.vars.exit_arrow_expression(expr);
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { return };
+        let operator = match assign.operator {
+            AssignmentOperator::LogicalAnd => LogicalOperator::And,
+            AssignmentOperator::LogicalOr => LogicalOperator::Or,
+            AssignmentOperator::LogicalNullish => LogicalOperator::Coalesce,
+            _ => return,
+        };
+        let AssignmentTarget::SimpleAssignmentTarget(target) = &mut assign.left else { return };
+
+        let (left, write_target) = match target {
+            // `a ||= b` -> `a || (a = b)`
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let ast = &self.ctx.ast;
+                let left = ast.identifier_reference_expression(ast.copy(&**ident));
+                let write_target = ast.simple_assignment_target_identifier(ast.copy(&**ident));
+                (left, write_target)
+            }
+            // `a.b ||= c` -> `a.b || (a.b = c)`
+            SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                let (read, write) = self.split_member_expression(member);
+                let ast = &self.ctx.ast;
+                (ast.member_expression(read), ast.simple_assignment_target_member_expression(write))
+            }
+            _ => return,
+        };
+
+        let ast = &self.ctx.ast;
+        let span = assign.span;
+        let right = ast.move_expression(&mut assign.right);
+        let assignment =
+            ast.assignment_expression(SPAN, AssignmentOperator::Assign, write_target, right);
+        let right = ast.parenthesized_expression(SPAN, assignment);
+        *expr = ast.logical_expression(span, left, operator, right);
+    }
+
+    /// Splits `obj[key]` into the member read first and the member assigned to afterwards,
+    /// `(_obj = obj)[_key = key]` and `_obj[_key]`.
+    fn split_member_expression(
+        &mut self,
+        member: &mut MemberExpression<'a>,
+    ) -> (MemberExpression<'a>, MemberExpression<'a>) {
+        let span = member.span();
+        match member {
+            MemberExpression::StaticMemberExpression(expr) => {
+                let (object, object_ref) = self.vars.memoise(&mut expr.object, true);
+                let read = self.ctx.ast.static_member(span, object, expr.property.clone(), false);
+                let write =
+                    self.ctx.ast.static_member(SPAN, object_ref, expr.property.clone(), false);
+                (read, write)
+            }
+            MemberExpression::ComputedMemberExpression(expr) => {
+                let (object, object_ref) = self.vars.memoise(&mut expr.object, true);
+                let (key, key_ref) = self.vars.memoise(&mut expr.expression, false);
+                let read = self.ctx.ast.computed_member(span, object, key, false);
+                let write = self.ctx.ast.computed_member(SPAN, object_ref, key_ref, false);
+                (read, write)
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                let (object, object_ref) = self.vars.memoise(&mut expr.object, true);
+                let read = self.ctx.ast.private_field(span, object, expr.field.clone(), false);
+                let write = self.ctx.ast.private_field(SPAN, object_ref, expr.field.clone(), false);
+                (read, write)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{helpers::tester::print, TransformTarget};
+
+    fn test(source_text: &str, expected: &str) {
+        assert_eq!(print(source_text, Some(TransformTarget::ES2020)), print(expected, None));
+    }
+
+    #[test]
+    fn identifier() {
+        test("let a; a ||= b;", "let a; a || (a = b);");
+        test("let a; a &&= b;", "let a; a && (a = b);");
+        test("let a; a ??= b;", "let a; a ?? (a = b);");
+    }
+
+    #[test]
+    fn member() {
+        test("obj.x ??= y;", "var _obj; (_obj = obj).x ?? (_obj.x = y);");
+        test("let obj; obj.x ??= y;", "let obj; obj.x ?? (obj.x = y);");
+        test("a.b.c &&= d;", "var _a$b; (_a$b = a.b).c && (_a$b.c = d);");
+        test(
+            "class C { #x; m() { this.#x ||= 1; } }",
+            "class C { #x; m() { this.#x || (this.#x = 1); } }",
+        );
+    }
+
+    #[test]
+    fn computed_member() {
+        test("a[f()] ||= b;", "var _a, _f; (_a = a)[_f = f()] || (_a[_f] = b);");
+        test("let a; a[f()] ||= b;", "var _f; let a; a[_f = f()] || (a[_f] = b);");
+        test("let a; a['x'] ??= b;", "let a; a['x'] ?? (a['x'] = b);");
+        test("let a, k; a[k] ??= b;", "let a, k; a[k] ?? (a[k] = b);");
+        test("const a = {}; a.x ||= b;", "const a = {}; a.x || (a.x = b);");
+        test(
+            "let a, k; a[k] ??= (k = 2, b);",
+            "var _k; let a, k; a[_k = k] ?? (a[_k] = (k = 2, b));",
+        );
+        test("let a; a.x ||= b; a = c;", "var _a; let a; (_a = a).x || (_a.x = b); a = c;");
+    }
+
+    #[test]
+    fn temporary_variables() {
+        test(
+            "function f() { obj.x ||= 1; }",
+            "function f() { var _obj; (_obj = obj).x || (_obj.x = 1); }",
+        );
+        test("let _obj; obj.x ||= 1;", "var _obj2; let _obj; (_obj2 = obj).x || (_obj2.x = 1);");
+        test("() => obj.x ||= 1;", "() => { var _obj; return (_obj = obj).x || (_obj.x = 1); };");
+        test(
+            "obj.x ||= (obj.y ||= 1);",
+            "var _obj, _obj2; (_obj = obj).x || (_obj.x = ((_obj2 = obj).y || (_obj2.y = 1)));",
+        );
+    }
+
+    #[test]
+    fn target() {
+        let source_text = "a.b ||= c;";
+        assert_eq!(print(source_text, Some(TransformTarget::ES2021)), print(source_text, None));
+    }
+}
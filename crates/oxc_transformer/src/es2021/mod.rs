@@ -0,0 +1,66 @@
+mod logical_assignment_operators;
+
+use oxc_ast::ast::*;
+
+use crate::{context::Ctx, options::TransformTarget};
+
+pub use self::logical_assignment_operators::LogicalAssignmentOperators;
+
+/// ES2021 syntax, lowered when the target is older than ES2021
+///
+/// This includes the following plugins:
+///
+/// * [plugin-transform-logical-assignment-operators](https://babel.dev/docs/babel-plugin-transform-logical-assignment-operators)
+pub struct ES2021<'a> {
+    enabled: bool,
+    logical_assignment_operators: LogicalAssignmentOperators<'a>,
+}
+
+// Constructors
+impl<'a> ES2021<'a> {
+    pub fn new(target: TransformTarget, ctx: &Ctx<'a>) -> Self {
+        Self {
+            enabled: target < TransformTarget::ES2021,
+            logical_assignment_operators: LogicalAssignmentOperators::new(ctx),
+        }
+    }
+}
+
+// Transforms
+impl<'a> ES2021<'a> {
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.logical_assignment_operators.transform_program(program);
+        }
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.logical_assignment_operators.transform_program_on_exit(program);
+        }
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        if self.enabled {
+            self.logical_assignment_operators.transform_arrow_expression_on_exit(expr);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.enabled {
+            self.logical_assignment_operators.transform_expression(expr);
+        }
+    }
+
+    pub fn transform_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        if self.enabled {
+            self.logical_assignment_operators.transform_function_body(body);
+        }
+    }
+
+    pub fn transform_function_body_on_exit(&mut self, body: &mut FunctionBody<'a>) {
+        if self.enabled {
+            self.logical_assignment_operators.transform_function_body_on_exit(body);
+        }
+    }
+}
@@ -0,0 +1,1096 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut, Visit, VisitMut},
+    AstBuilder,
+};
+use oxc_diagnostics::Error;
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
+    NumberBase,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::context::Ctx;
+
+use super::diagnostics::{
+    PrivateMethodBodyUnsupported, PrivateMethodReferenceUnsupported,
+    PrivateMethodsInClassExpression, PrivateMethodsWithFieldInitializers,
+};
+
+/// [plugin-transform-private-methods](https://babel.dev/docs/babel-plugin-transform-private-methods)
+///
+/// Private methods and accessors are moved out of the class into function declarations, which
+/// are called with the object after checking that it has the brand of the class. Instances get
+/// the brand in the constructor by being added to a `WeakSet`, the brand of static methods is
+/// the class itself.
+///
+/// In:  `class C { #m() {} f(o) { this.#m(); return #m in o; } }`
+/// Out: `var _C_brand = new WeakSet(); class C { constructor() { _C_brand.add(this); }
+///       f(o) { _m.call(_assertClassBrand(_C_brand, this)); return _C_brand.has(o); } }
+///       function _m() {}`
+///
+/// Only named class declarations are transformed, the brand and the moved functions can't be
+/// declared next to class expressions. A class is also not transformed when a private method or
+/// accessor is used in a way which isn't rewritten (optional chaining, compound assignment,
+/// update, destructuring, a getter without setter being assigned to), when the moved functions
+/// would refer to `super` or to the private fields of the class, which need the private fields
+/// to be lowered as well, or when instances need the brand and the class has instance field
+/// initializers. Those run before the constructor body which adds the brand, so they would need
+/// to be lowered into the constructor too. These classes are reported as unsupported.
+pub struct ClassPrivateMethods<'a> {
+    ctx: Ctx<'a>,
+    helpers: Helpers<'a>,
+}
+
+/// Names of the helper functions used so far, declared at the top of the program.
+#[derive(Default)]
+struct Helpers<'a> {
+    /// `_assertClassBrand(brand, object, value)`
+    assert_class_brand: Option<Atom<'a>>,
+    /// `_classPrivateSetter(brand, setter, object, value)`
+    class_private_setter: Option<Atom<'a>>,
+}
+
+#[derive(Debug, Clone)]
+struct PrivateMethod<'a> {
+    r#static: bool,
+    /// Names of the functions the method and accessors are moved to. They hold the private name
+    /// itself until the class is known to be transformed.
+    method: Option<Atom<'a>>,
+    getter: Option<Atom<'a>>,
+    setter: Option<Atom<'a>>,
+}
+
+/// A use of a private name which can be rewritten.
+#[derive(Debug, Clone, Copy)]
+enum PrivateReference {
+    /// `o.#m()`
+    Call,
+    /// `o.#m`
+    Read,
+    /// `o.#m = v`
+    Write,
+    /// `#m in o`
+    In,
+}
+
+impl<'a> PrivateMethod<'a> {
+    fn supports(&self, reference: PrivateReference) -> bool {
+        match reference {
+            PrivateReference::Call => self.method.is_some(),
+            PrivateReference::Read => self.method.is_some() || self.getter.is_some(),
+            PrivateReference::Write => self.setter.is_some(),
+            PrivateReference::In => true,
+        }
+    }
+
+    fn function_mut(&mut self, kind: MethodDefinitionKind) -> &mut Option<Atom<'a>> {
+        match kind {
+            MethodDefinitionKind::Get => &mut self.getter,
+            MethodDefinitionKind::Set => &mut self.setter,
+            _ => &mut self.method,
+        }
+    }
+}
+
+/// Private names declared by the class being transformed.
+#[derive(Default)]
+struct PrivateNames<'a> {
+    /// The private methods and accessors which are moved out of the class.
+    methods: FxHashMap<Atom<'a>, PrivateMethod<'a>>,
+    /// Every other private member.
+    fields: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> PrivateNames<'a> {
+    fn collect(body: &ClassBody<'a>) -> Self {
+        let mut names = Self::default();
+        for elem in &body.body {
+            if let Some(def) = lowered_method(elem) {
+                let PropertyKey::PrivateIdentifier(ident) = &def.key else { continue };
+                let method = names.methods.entry(ident.name.clone()).or_insert(PrivateMethod {
+                    r#static: def.r#static,
+                    method: None,
+                    getter: None,
+                    setter: None,
+                });
+                *method.function_mut(def.kind) = Some(ident.name.clone());
+            } else if let Some(PropertyKey::PrivateIdentifier(ident)) = elem.property_key() {
+                names.fields.insert(ident.name.clone());
+            }
+        }
+        names
+    }
+
+    /// Removes the names redeclared by a nested class, which refer to its own members inside it.
+    fn shadow(&mut self, body: &ClassBody<'a>) -> Self {
+        let mut shadowed = Self::default();
+        for elem in &body.body {
+            let Some(PropertyKey::PrivateIdentifier(ident)) = elem.property_key() else {
+                continue;
+            };
+            if let Some(method) = self.methods.remove(&ident.name) {
+                shadowed.methods.insert(ident.name.clone(), method);
+            }
+            if self.fields.remove(&ident.name) {
+                shadowed.fields.insert(ident.name.clone());
+            }
+        }
+        shadowed
+    }
+
+    fn restore(&mut self, shadowed: Self) {
+        self.methods.extend(shadowed.methods);
+        self.fields.extend(shadowed.fields);
+    }
+}
+
+/// A private method or accessor with a body.
+fn lowered_method<'b, 'a>(elem: &'b ClassElement<'a>) -> Option<&'b MethodDefinition<'a>> {
+    match elem {
+        ClassElement::MethodDefinition(def) if is_lowered_method(def) => Some(&**def),
+        _ => None,
+    }
+}
+
+fn is_lowered_method(def: &MethodDefinition) -> bool {
+    matches!(def.key, PropertyKey::PrivateIdentifier(_))
+        && def.kind != MethodDefinitionKind::Constructor
+        && def.value.body.is_some()
+}
+
+/// A non-static field or auto-accessor with an initializer.
+fn is_instance_field_initializer(elem: &ClassElement) -> bool {
+    match elem {
+        ClassElement::PropertyDefinition(def) => !def.r#static && def.value.is_some(),
+        ClassElement::AccessorProperty(def) => !def.r#static && def.value.is_some(),
+        _ => false,
+    }
+}
+
+/// `o.#m`, without optional chaining.
+fn private_field<'b, 'a>(expr: &'b Expression<'a>) -> Option<&'b PrivateFieldExpression<'a>> {
+    match expr {
+        Expression::MemberExpression(member) => match &**member {
+            MemberExpression::PrivateFieldExpression(field) if !field.optional => Some(field),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn private_field_mut<'b, 'a>(
+    expr: &'b mut Expression<'a>,
+) -> Option<&'b mut PrivateFieldExpression<'a>> {
+    match expr {
+        Expression::MemberExpression(member) => match &mut **member {
+            MemberExpression::PrivateFieldExpression(field) if !field.optional => Some(field),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn private_assignment_target<'b, 'a>(
+    assign: &'b mut AssignmentExpression<'a>,
+) -> Option<&'b mut PrivateFieldExpression<'a>> {
+    if assign.operator != AssignmentOperator::Assign {
+        return None;
+    }
+    match &mut assign.left {
+        AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::MemberAssignmentTarget(member),
+        ) => match &mut **member {
+            MemberExpression::PrivateFieldExpression(field) if !field.optional => Some(field),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The kind of use and the private name of `expr`, for the uses which can be rewritten.
+fn private_reference<'b, 'a>(expr: &'b Expression<'a>) -> Option<(PrivateReference, &'b Atom<'a>)> {
+    match expr {
+        Expression::CallExpression(call) if !call.optional => {
+            private_field(&call.callee).map(|field| (PrivateReference::Call, &field.field.name))
+        }
+        Expression::MemberExpression(_) => {
+            private_field(expr).map(|field| (PrivateReference::Read, &field.field.name))
+        }
+        Expression::AssignmentExpression(assign)
+            if assign.operator == AssignmentOperator::Assign =>
+        {
+            match &assign.left {
+                AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) => match &**member {
+                    MemberExpression::PrivateFieldExpression(field) if !field.optional => {
+                        Some((PrivateReference::Write, &field.field.name))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        Expression::PrivateInExpression(expr) => Some((PrivateReference::In, &expr.left.name)),
+        _ => None,
+    }
+}
+
+impl<'a> ClassPrivateMethods<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), helpers: Helpers::default() }
+    }
+
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        let Some(assert_class_brand) = self.helpers.assert_class_brand.clone() else { return };
+        if let Some(name) = self.helpers.class_private_setter.clone() {
+            let setter = self.create_class_private_setter(name, assert_class_brand.clone());
+            program.body.insert(0, setter);
+        }
+        program.body.insert(0, self.create_assert_class_brand(assert_class_brand));
+    }
+
+    /// Reports class expressions with private methods, which are not transformed.
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        if class.is_expression()
+            && class.body.body.iter().any(|elem| lowered_method(elem).is_some())
+        {
+            self.ctx.error(PrivateMethodsInClassExpression(class.span));
+        }
+    }
+
+    /// Transforms the class declarations of `stmts`, declaring the brand before each class and
+    /// the moved functions after it.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let has_private_methods = |stmt: &Statement<'a>| {
+            class_declaration(stmt).is_some_and(|class| {
+                class.body.body.iter().any(|elem| lowered_method(elem).is_some())
+            })
+        };
+        if !stmts.iter().any(has_private_methods) {
+            return;
+        }
+
+        let mut new_stmts = self.ctx.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let Some((brand, functions)) =
+                class_declaration_mut(&mut stmt).and_then(|class| self.lower_class(class))
+            else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            new_stmts.extend(brand);
+            new_stmts.push(stmt);
+            new_stmts.extend(functions);
+        }
+        *stmts = new_stmts;
+    }
+
+    /// Returns the declaration of the brand of instances and the moved functions,
+    /// or `None` if the class is left as it is, reporting why it isn't supported.
+    fn lower_class(
+        &mut self,
+        class: &mut Class<'a>,
+    ) -> Option<(Option<Statement<'a>>, std::vec::Vec<Statement<'a>>)> {
+        let names = PrivateNames::collect(&class.body);
+        if names.methods.is_empty() {
+            return None;
+        }
+        // `export default class {}`
+        let Some(class_name) = class.id.as_ref().map(|id| id.name.clone()) else {
+            self.ctx.error(PrivateMethodsInClassExpression(class.span));
+            return None;
+        };
+        let needs_brand = names.methods.values().any(|method| !method.r#static);
+        if needs_brand {
+            if let Some(elem) =
+                class.body.body.iter().find(|elem| is_instance_field_initializer(elem))
+            {
+                self.ctx.error(PrivateMethodsWithFieldInitializers(elem.span()));
+                return None;
+            }
+        }
+
+        let mut checker = UnsupportedReferences {
+            names,
+            in_moved_function: false,
+            in_method_body: false,
+            found: None,
+        };
+        checker.visit_class_elements(&class.body);
+        if let Some(error) = checker.found {
+            self.ctx.error(error);
+            return None;
+        }
+        let mut names = checker.names;
+
+        let brand = needs_brand.then(|| self.ctx.generate_uid(&format!("{class_name}_brand")));
+        for elem in &class.body.body {
+            let Some(def) = lowered_method(elem) else { continue };
+            let PropertyKey::PrivateIdentifier(ident) = &def.key else { continue };
+            let Some(method) = names.methods.get_mut(&ident.name) else { continue };
+            let name = match def.kind {
+                MethodDefinitionKind::Get => format!("get_{}", ident.name),
+                MethodDefinitionKind::Set => format!("set_{}", ident.name),
+                _ => ident.name.to_string(),
+            };
+            *method.function_mut(def.kind) = Some(self.ctx.generate_uid(&name));
+        }
+
+        let mut references = PrivateReferences {
+            ctx: Rc::clone(&self.ctx),
+            names,
+            class_name: class_name.clone(),
+            brand: brand.clone().unwrap_or(class_name),
+            helpers: &mut self.helpers,
+        };
+        references.visit_class_elements(&mut class.body);
+        let mut names = references.names;
+
+        let mut functions = vec![];
+        let elements = mem::replace(&mut class.body.body, self.ctx.ast.new_vec());
+        for elem in elements {
+            match elem {
+                ClassElement::MethodDefinition(def) if is_lowered_method(&def) => {
+                    let def = def.unbox();
+                    let PropertyKey::PrivateIdentifier(ident) = &def.key else { continue };
+                    let Some(name) = names
+                        .methods
+                        .get_mut(&ident.name)
+                        .and_then(|method| method.function_mut(def.kind).clone())
+                    else {
+                        continue;
+                    };
+                    let mut func = def.value;
+                    func.r#type = FunctionType::FunctionDeclaration;
+                    func.id = Some(BindingIdentifier::new(SPAN, name));
+                    functions.push(self.ctx.ast.function_declaration(func));
+                }
+                elem => class.body.body.push(elem),
+            }
+        }
+
+        let brand = brand.map(|brand| {
+            self.add_brand_to_constructor(class, &brand);
+            self.create_brand_declaration(brand)
+        });
+        Some((brand, functions))
+    }
+
+    /// Adds `_C_brand.add(this)` to the start of the constructor, or after `super()` in
+    /// derived classes, creating the constructor if there is none.
+    fn add_brand_to_constructor(&self, class: &mut Class<'a>, brand: &Atom<'a>) {
+        let ast = &self.ctx.ast;
+        let derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|elem| match elem {
+            ClassElement::MethodDefinition(def)
+                if def.kind == MethodDefinitionKind::Constructor && def.value.body.is_some() =>
+            {
+                def.value.body.as_mut()
+            }
+            _ => None,
+        });
+
+        if let Some(body) = constructor {
+            if derived {
+                BrandInitialization { ctx: Rc::clone(&self.ctx), brand: brand.clone() }
+                    .visit_function_body(body);
+            } else {
+                body.statements.insert(0, ast.expression_statement(SPAN, add_brand(ast, brand)));
+            }
+            return;
+        }
+
+        // `constructor(...args) { super(...args); _C_brand.add(this); }`
+        let mut statements = ast.new_vec();
+        let mut rest = None;
+        if derived {
+            let args = ast.new_atom("args");
+            let id = ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, args.clone()));
+            rest = Some(ast.rest_element(SPAN, ast.binding_pattern(id, None, false)));
+            let spread = ast.spread_element(
+                SPAN,
+                ast.identifier_reference_expression(IdentifierReference::new(SPAN, args)),
+            );
+            let arguments = ast.new_vec_single(Argument::SpreadElement(spread));
+            let call = ast.call_expression(SPAN, ast.super_(SPAN), arguments, false, None);
+            statements.push(ast.expression_statement(SPAN, call));
+        }
+        statements.push(ast.expression_statement(SPAN, add_brand(ast, brand)));
+        let params =
+            ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ast.new_vec(), rest);
+        let body = ast.function_body(SPAN, ast.new_vec(), statements);
+        let func =
+            ast.plain_function(FunctionType::FunctionExpression, SPAN, None, params, Some(body));
+        class.body.body.insert(0, ast.class_constructor(SPAN, func));
+    }
+
+    /// `var _C_brand = new WeakSet();`
+    fn create_brand_declaration(&self, brand: Atom<'a>) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let kind = VariableDeclarationKind::Var;
+        let id = ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, brand));
+        let id = ast.binding_pattern(id, None, false);
+        let init = ast.new_expression(SPAN, identifier(ast, "WeakSet"), ast.new_vec(), None);
+        let decl = ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl =
+            ast.variable_declaration(SPAN, kind, ast.new_vec_single(decl), Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    /// ```js
+    /// function _assertClassBrand(e, t, n) {
+    ///   if (typeof e == "function" ? e === t : e.has(t)) return arguments.length < 3 ? t : n;
+    ///   throw new TypeError("Private element is not present on this object");
+    /// }
+    /// ```
+    fn create_assert_class_brand(&self, name: Atom<'a>) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let ident = |name| identifier(ast, name);
+
+        let is_function = ast.binary_expression(
+            SPAN,
+            ast.unary_expression(SPAN, UnaryOperator::Typeof, ident("e")),
+            BinaryOperator::Equality,
+            ast.literal_string_expression(ast.string_literal(SPAN, "function")),
+        );
+        let is_class =
+            ast.binary_expression(SPAN, ident("e"), BinaryOperator::StrictEquality, ident("t"));
+        let has = call(ast, member(ast, ident("e"), "has"), [ident("t")]);
+        let test = ast.conditional_expression(SPAN, is_function, is_class, has);
+
+        let has_value = ast.binary_expression(
+            SPAN,
+            member(ast, ident("arguments"), "length"),
+            BinaryOperator::LessThan,
+            ast.literal_number_expression(ast.number_literal(SPAN, 3.0, "3", NumberBase::Decimal)),
+        );
+        let value = ast.conditional_expression(SPAN, has_value, ident("t"), ident("n"));
+
+        let message = ast.literal_string_expression(
+            ast.string_literal(SPAN, "Private element is not present on this object"),
+        );
+        let error = ast.new_expression(
+            SPAN,
+            ident("TypeError"),
+            ast.new_vec_single(Argument::Expression(message)),
+            None,
+        );
+        let statements = ast.new_vec_from_iter([
+            ast.if_statement(SPAN, test, ast.return_statement(SPAN, Some(value)), None),
+            ast.throw_statement(SPAN, error),
+        ]);
+        self.create_function(name, &["e", "t", "n"], statements)
+    }
+
+    /// ```js
+    /// function _classPrivateSetter(s, r, a, t) {
+    ///   r.call(_assertClassBrand(s, a), t);
+    ///   return t;
+    /// }
+    /// ```
+    fn create_class_private_setter(
+        &self,
+        name: Atom<'a>,
+        assert_class_brand: Atom<'a>,
+    ) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let ident = |name| identifier(ast, name);
+        let object = call(
+            ast,
+            ast.identifier_reference_expression(IdentifierReference::new(SPAN, assert_class_brand)),
+            [ident("s"), ident("a")],
+        );
+        let set = call(ast, member(ast, ident("r"), "call"), [object, ident("t")]);
+        let statements = ast.new_vec_from_iter([
+            ast.expression_statement(SPAN, set),
+            ast.return_statement(SPAN, Some(ident("t"))),
+        ]);
+        self.create_function(name, &["s", "r", "a", "t"], statements)
+    }
+
+    fn create_function(
+        &self,
+        name: Atom<'a>,
+        params: &[&str],
+        statements: Vec<'a, Statement<'a>>,
+    ) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let params = ast.new_vec_from_iter(params.iter().map(|param| {
+            let id =
+                ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, ast.new_atom(param)));
+            ast.plain_formal_parameter(SPAN, ast.binding_pattern(id, None, false))
+        }));
+        let params =
+            ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, None);
+        let body = ast.function_body(SPAN, ast.new_vec(), statements);
+        let id = Some(BindingIdentifier::new(SPAN, name));
+        let func =
+            ast.plain_function(FunctionType::FunctionDeclaration, SPAN, id, params, Some(body));
+        ast.function_declaration(func)
+    }
+}
+
+pub(super) fn class_declaration<'b, 'a>(stmt: &'b Statement<'a>) -> Option<&'b Class<'a>> {
+    match stmt {
+        Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(&**class),
+        Statement::ModuleDeclaration(decl) => match &**decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(&**class),
+                _ => None,
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(&**class),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(super) fn class_declaration_mut<'b, 'a>(
+    stmt: &'b mut Statement<'a>,
+) -> Option<&'b mut Class<'a>> {
+    match stmt {
+        Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(&mut **class),
+        Statement::ModuleDeclaration(decl) => match &mut **decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(&mut **class),
+                _ => None,
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(&mut **class),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn identifier<'a>(ast: &AstBuilder<'a>, name: &str) -> Expression<'a> {
+    ast.identifier_reference_expression(ast.identifier_reference(SPAN, name))
+}
+
+pub(super) fn member<'a>(
+    ast: &AstBuilder<'a>,
+    object: Expression<'a>,
+    property: &str,
+) -> Expression<'a> {
+    ast.static_member_expression(SPAN, object, ast.identifier_name(SPAN, property), false)
+}
+
+pub(super) fn call<'a, const N: usize>(
+    ast: &AstBuilder<'a>,
+    callee: Expression<'a>,
+    arguments: [Expression<'a>; N],
+) -> Expression<'a> {
+    let arguments = ast.new_vec_from_iter(arguments.into_iter().map(Argument::Expression));
+    ast.call_expression(SPAN, callee, arguments, false, None)
+}
+
+/// `_C_brand.add(this)`
+fn add_brand<'a>(ast: &AstBuilder<'a>, brand: &Atom<'a>) -> Expression<'a> {
+    let brand = ast.identifier_reference_expression(IdentifierReference::new(SPAN, brand.clone()));
+    call(ast, member(ast, brand, "add"), [ast.this_expression(SPAN)])
+}
+
+/// Finds the uses of private names which prevent the class from being transformed.
+struct UnsupportedReferences<'a> {
+    names: PrivateNames<'a>,
+    /// Whether a method which is moved out of the class is being visited.
+    in_moved_function: bool,
+    /// Whether `super` refers to the class, which the moved function no longer extends.
+    in_method_body: bool,
+    /// The first use which prevents the class from being transformed.
+    found: Option<Error>,
+}
+
+impl<'a> UnsupportedReferences<'a> {
+    fn report<T: Into<Error>>(&mut self, error: T) {
+        if self.found.is_none() {
+            self.found = Some(error.into());
+        }
+    }
+
+    fn visit_class_elements(&mut self, body: &ClassBody<'a>) {
+        for elem in &body.body {
+            if let Some(def) = lowered_method(elem) {
+                self.in_moved_function = true;
+                self.in_method_body = true;
+                walk::walk_function(self, &def.value, None);
+                self.in_moved_function = false;
+                self.in_method_body = false;
+            } else {
+                self.visit_class_element(elem);
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for UnsupportedReferences<'a> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        let Some((reference, method)) = private_reference(expr)
+            .and_then(|(reference, name)| Some((reference, self.names.methods.get(name)?)))
+        else {
+            walk::walk_expression(self, expr);
+            return;
+        };
+        if !method.supports(reference) {
+            self.report(PrivateMethodReferenceUnsupported(expr.span()));
+            return;
+        }
+        match expr {
+            Expression::CallExpression(call) => {
+                if let Some(field) = private_field(&call.callee) {
+                    self.visit_expression(&field.object);
+                }
+                for arg in &call.arguments {
+                    self.visit_argument(arg);
+                }
+            }
+            Expression::MemberExpression(member) => self.visit_expression(member.object()),
+            Expression::AssignmentExpression(assign) => {
+                if let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &assign.left
+                {
+                    self.visit_expression(member.object());
+                }
+                self.visit_expression(&assign.right);
+            }
+            Expression::PrivateInExpression(expr) => self.visit_expression(&expr.right),
+            _ => {}
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        // `o.#m?.()` would lose its `this`
+        if private_field(&expr.callee)
+            .is_some_and(|field| self.names.methods.contains_key(&field.field.name))
+        {
+            self.report(PrivateMethodReferenceUnsupported(expr.span));
+            return;
+        }
+        walk::walk_call_expression(self, expr);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &TaggedTemplateExpression<'a>) {
+        if private_field(&expr.tag)
+            .is_some_and(|field| self.names.methods.contains_key(&field.field.name))
+        {
+            self.report(PrivateMethodReferenceUnsupported(expr.span));
+            return;
+        }
+        walk::walk_tagged_template_expression(self, expr);
+    }
+
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        if self.names.methods.contains_key(&ident.name) {
+            self.report(PrivateMethodReferenceUnsupported(ident.span));
+        } else if self.in_moved_function && self.names.fields.contains(&ident.name) {
+            self.report(PrivateMethodBodyUnsupported(ident.span));
+        }
+    }
+
+    fn visit_super(&mut self, expr: &Super) {
+        if self.in_method_body {
+            self.report(PrivateMethodBodyUnsupported(expr.span));
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: Option<ScopeFlags>) {
+        let in_method_body = mem::replace(&mut self.in_method_body, false);
+        walk::walk_function(self, func, flags);
+        self.in_method_body = in_method_body;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        let shadowed = self.names.shadow(&class.body);
+        let in_method_body = mem::replace(&mut self.in_method_body, false);
+        walk::walk_class(self, class);
+        self.in_method_body = in_method_body;
+        self.names.restore(shadowed);
+    }
+}
+
+/// Rewrites the uses of the private methods and accessors of a class.
+struct PrivateReferences<'a, 'b> {
+    ctx: Ctx<'a>,
+    names: PrivateNames<'a>,
+    class_name: Atom<'a>,
+    /// The `WeakSet` of instances.
+    brand: Atom<'a>,
+    helpers: &'b mut Helpers<'a>,
+}
+
+impl<'a, 'b> PrivateReferences<'a, 'b> {
+    fn visit_class_elements(&mut self, body: &mut ClassBody<'a>) {
+        for elem in body.body.iter_mut() {
+            match elem {
+                ClassElement::MethodDefinition(def) if is_lowered_method(def) => {
+                    walk_mut::walk_function_mut(self, &mut def.value, None);
+                }
+                _ => self.visit_class_element(elem),
+            }
+        }
+    }
+
+    fn method(&self, name: &Atom<'a>) -> Option<PrivateMethod<'a>> {
+        self.names.methods.get(name).cloned()
+    }
+
+    /// `_C_brand`, or `C` for static methods.
+    fn brand(&self, method: &PrivateMethod<'a>) -> Expression<'a> {
+        let brand = if method.r#static { &self.class_name } else { &self.brand };
+        self.ctx.ast.identifier_reference_expression(IdentifierReference::new(SPAN, brand.clone()))
+    }
+
+    fn reference(&self, name: Atom<'a>) -> Expression<'a> {
+        self.ctx.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name))
+    }
+
+    /// `_assertClassBrand(_C_brand, o)`
+    fn assert_class_brand(
+        &mut self,
+        method: &PrivateMethod<'a>,
+        object: Expression<'a>,
+        value: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let ctx = &self.ctx;
+        let name = self
+            .helpers
+            .assert_class_brand
+            .get_or_insert_with(|| ctx.generate_uid("assertClassBrand"))
+            .clone();
+        let ast = &self.ctx.ast;
+        let mut arguments = ast.new_vec_from_iter([
+            Argument::Expression(self.brand(method)),
+            Argument::Expression(object),
+        ]);
+        arguments.extend(value.map(Argument::Expression));
+        ast.call_expression(SPAN, self.reference(name), arguments, false, None)
+    }
+
+    /// `o.#m(a)` -> `_m.call(_assertClassBrand(_C_brand, o), a)`
+    fn transform_call_expression(
+        &mut self,
+        call: &mut CallExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if call.optional {
+            return None;
+        }
+        let field = private_field_mut(&mut call.callee)?;
+        let method = self.method(&field.field.name)?;
+        let function = method.method.clone()?;
+        self.visit_expression(&mut field.object);
+        let object = self.ctx.ast.move_expression(&mut field.object);
+        for arg in call.arguments.iter_mut() {
+            self.visit_argument(arg);
+        }
+
+        let object = self.assert_class_brand(&method, object, None);
+        let ast = &self.ctx.ast;
+        let mut arguments = ast.new_vec_single(Argument::Expression(object));
+        arguments.extend(mem::replace(&mut call.arguments, ast.new_vec()));
+        let callee = member(ast, self.reference(function), "call");
+        Some(ast.call_expression(call.span, callee, arguments, false, None))
+    }
+
+    /// `o.#m` -> `_assertClassBrand(_C_brand, o, _m)`
+    /// `o.#x` -> `_get_x.call(_assertClassBrand(_C_brand, o))`
+    fn transform_member_expression(&mut self, expr: &mut Expression<'a>) -> Option<Expression<'a>> {
+        let field = private_field_mut(expr)?;
+        let method = self.method(&field.field.name)?;
+        let (function, is_getter) = match (&method.method, &method.getter) {
+            (Some(function), _) => (function.clone(), false),
+            (None, Some(getter)) => (getter.clone(), true),
+            (None, None) => return None,
+        };
+        self.visit_expression(&mut field.object);
+        let span = field.span;
+        let object = self.ctx.ast.move_expression(&mut field.object);
+
+        if !is_getter {
+            let value = self.reference(function);
+            return Some(self.assert_class_brand(&method, object, Some(value)));
+        }
+        let object = self.assert_class_brand(&method, object, None);
+        let ast = &self.ctx.ast;
+        let callee = member(ast, self.reference(function), "call");
+        let arguments = ast.new_vec_single(Argument::Expression(object));
+        Some(ast.call_expression(span, callee, arguments, false, None))
+    }
+
+    /// `o.#x = v` -> `_classPrivateSetter(_C_brand, _set_x, o, v)`
+    fn transform_assignment_expression(
+        &mut self,
+        assign: &mut AssignmentExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        let span = assign.span;
+        let field = private_assignment_target(assign)?;
+        let method = self.method(&field.field.name)?;
+        let setter = method.setter.clone()?;
+        self.visit_expression(&mut field.object);
+        let object = self.ctx.ast.move_expression(&mut field.object);
+        self.visit_expression(&mut assign.right);
+        let value = self.ctx.ast.move_expression(&mut assign.right);
+
+        let ctx = &self.ctx;
+        self.helpers.assert_class_brand.get_or_insert_with(|| ctx.generate_uid("assertClassBrand"));
+        let name = self
+            .helpers
+            .class_private_setter
+            .get_or_insert_with(|| ctx.generate_uid("classPrivateSetter"))
+            .clone();
+        let ast = &self.ctx.ast;
+        let arguments = ast.new_vec_from_iter(
+            [self.brand(&method), self.reference(setter), object, value].map(Argument::Expression),
+        );
+        Some(ast.call_expression(span, self.reference(name), arguments, false, None))
+    }
+
+    /// `#m in o` -> `_C_brand.has(o)`, or `o === C` for static methods
+    fn transform_private_in_expression(
+        &mut self,
+        expr: &mut PrivateInExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        let method = self.method(&expr.left.name)?;
+        self.visit_expression(&mut expr.right);
+        let object = self.ctx.ast.move_expression(&mut expr.right);
+        let ast = &self.ctx.ast;
+        if method.r#static {
+            let operator = BinaryOperator::StrictEquality;
+            return Some(ast.binary_expression(expr.span, object, operator, self.brand(&method)));
+        }
+        let callee = member(ast, self.brand(&method), "has");
+        let arguments = ast.new_vec_single(Argument::Expression(object));
+        Some(ast.call_expression(expr.span, callee, arguments, false, None))
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for PrivateReferences<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let new_expr = match expr {
+            Expression::CallExpression(call) => self.transform_call_expression(call),
+            Expression::MemberExpression(_) => self.transform_member_expression(expr),
+            Expression::AssignmentExpression(assign) => {
+                self.transform_assignment_expression(assign)
+            }
+            Expression::PrivateInExpression(expr) => self.transform_private_in_expression(expr),
+            _ => None,
+        };
+        match new_expr {
+            Some(new_expr) => *expr = new_expr,
+            None => walk_mut::walk_expression_mut(self, expr),
+        }
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        let shadowed = self.names.shadow(&class.body);
+        walk_mut::walk_class_mut(self, class);
+        self.names.restore(shadowed);
+    }
+}
+
+/// Adds `_C_brand.add(this)` after each `super()` call in the constructor of a derived class.
+struct BrandInitialization<'a> {
+    ctx: Ctx<'a>,
+    brand: Atom<'a>,
+}
+
+impl<'a> VisitMut<'a> for BrandInitialization<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_mut::walk_statements_mut(self, stmts);
+
+        let is_super_call = |stmt: &Statement<'a>| match stmt {
+            Statement::ExpressionStatement(stmt) => stmt.expression.is_super_call_expression(),
+            _ => false,
+        };
+        if !stmts.iter().any(is_super_call) {
+            return;
+        }
+        let ast = &self.ctx.ast;
+        let old_stmts = mem::replace(stmts, ast.new_vec_with_capacity(stmts.len() + 1));
+        for stmt in old_stmts {
+            let is_super_call = is_super_call(&stmt);
+            stmts.push(stmt);
+            if is_super_call {
+                stmts.push(ast.expression_statement(SPAN, add_brand(ast, &self.brand)));
+            }
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        // `super();` is followed by the brand statement instead
+        if let Expression::CallExpression(call) = &mut stmt.expression {
+            if matches!(call.callee, Expression::Super(_)) {
+                for arg in call.arguments.iter_mut() {
+                    self.visit_argument(arg);
+                }
+                return;
+            }
+        }
+        walk_mut::walk_expression_statement_mut(self, stmt);
+    }
+
+    /// `() => super()` keeps an expression body.
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        if !expr.expression {
+            walk_mut::walk_arrow_expression_mut(self, expr);
+            return;
+        }
+        if let Some(Statement::ExpressionStatement(stmt)) = expr.body.statements.first_mut() {
+            self.visit_expression(&mut stmt.expression);
+        }
+    }
+
+    /// `foo(super())` -> `foo((super(), _C_brand.add(this), this))`
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression_mut(self, expr);
+        if !expr.is_super_call_expression() {
+            return;
+        }
+        let ast = &self.ctx.ast;
+        let expressions = ast.new_vec_from_iter([
+            ast.move_expression(expr),
+            add_brand(ast, &self.brand),
+            ast.this_expression(SPAN),
+        ]);
+        *expr = ast.sequence_expression(SPAN, expressions);
+    }
+
+    // `super()` can't be called from functions or from the constructors of nested classes.
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        helpers::tester::{errors, print},
+        TransformTarget,
+    };
+
+    const ASSERT_CLASS_BRAND: &str = "function _assertClassBrand(e, t, n) { if (typeof e == 'function' ? e === t : e.has(t)) return arguments.length < 3 ? t : n; throw new TypeError('Private element is not present on this object'); }";
+
+    fn test(source_text: &str, expected: &str) {
+        assert_eq!(print(source_text, Some(TransformTarget::ES2021)), print(expected, None));
+    }
+
+    #[test]
+    fn private_method() {
+        test(
+            "class C { #m(a) { return a; } f() { return this.#m(1); } }",
+            &format!(
+                "{ASSERT_CLASS_BRAND} {}",
+                "var _C_brand = new WeakSet(); class C { constructor() { _C_brand.add(this); } f() { return _m.call(_assertClassBrand(_C_brand, this), 1); } } function _m(a) { return a; }"
+            ),
+        );
+        test(
+            "class C { #m() {} f() { return this.#m; } }",
+            &format!(
+                "{ASSERT_CLASS_BRAND} {}",
+                "var _C_brand = new WeakSet(); class C { constructor() { _C_brand.add(this); } f() { return _assertClassBrand(_C_brand, this, _m); } } function _m() {}"
+            ),
+        );
+        test(
+            "class C extends B { constructor() { super(); } #m() {} }",
+            "var _C_brand = new WeakSet(); class C extends B { constructor() { super(); _C_brand.add(this); } } function _m() {}",
+        );
+        test(
+            "class C extends B { #m() {} }",
+            "var _C_brand = new WeakSet(); class C extends B { constructor(...args) { super(...args); _C_brand.add(this); } } function _m() {}",
+        );
+    }
+
+    #[test]
+    fn private_accessors() {
+        test(
+            "class C { get #x() { return 1; } set #x(v) {} f() { this.#x = this.#x; } }",
+            &format!(
+                "{ASSERT_CLASS_BRAND} {}",
+                "function _classPrivateSetter(s, r, a, t) { r.call(_assertClassBrand(s, a), t); return t; } var _C_brand = new WeakSet(); class C { constructor() { _C_brand.add(this); } f() { _classPrivateSetter(_C_brand, _set_x, this, _get_x.call(_assertClassBrand(_C_brand, this))); } } function _get_x() { return 1; } function _set_x(v) {}"
+            ),
+        );
+    }
+
+    #[test]
+    fn private_static_method() {
+        test(
+            "class C { static #m() { return 1; } static f() { return C.#m(); } }",
+            &format!(
+                "{ASSERT_CLASS_BRAND} {}",
+                "class C { static f() { return _m.call(_assertClassBrand(C, C)); } } function _m() { return 1; }"
+            ),
+        );
+    }
+
+    #[test]
+    fn private_in() {
+        test(
+            "class C { #m() {} static is(o) { return #m in o; } }",
+            "var _C_brand = new WeakSet(); class C { constructor() { _C_brand.add(this); } static is(o) { return _C_brand.has(o); } } function _m() {}",
+        );
+        test(
+            "class C { static #m() {} static is(o) { return #m in o; } }",
+            "class C { static is(o) { return o === C; } } function _m() {}",
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let unsupported = |source_text: &str, message: &str| {
+            assert_eq!(errors(source_text, TransformTarget::ES2021), [message]);
+        };
+        let reference = "Private methods can only be called, read, assigned to or checked with \
+                         `in`, without optional chaining.";
+        let body = "Private methods which refer to `super` or to private fields are not supported.";
+        let fields =
+            "Private methods are not supported in classes with instance field initializers.";
+        let declaration = "Private methods are only supported in named class declarations.";
+
+        unsupported("class C { #x; #m() { return this.#x; } }", body);
+        unsupported("class C extends B { #m() { return super.m(); } }", body);
+        unsupported("class C { #m() {} f() { this.#m?.(); } }", reference);
+        unsupported("class C { #m() {} f() { this.#m += 1; } }", reference);
+        unsupported("class C { #m() {} x = this.#m(); }", fields);
+        // The brand would be added after the field initializer calls `f`
+        unsupported("class C { x = this.f(); #m() {} f() { return this.#m(); } }", fields);
+        unsupported("const C = class { #m() {} f() { this.#m(); } };", declaration);
+        unsupported("export default class { #m() {} f() { this.#m(); } }", declaration);
+    }
+
+    #[test]
+    fn instance_fields() {
+        // Fields without initializers and static fields run no code before the brand is added
+        test(
+            "class C { x; static y = 1; #m() {} f() { this.#m(); } }",
+            &format!(
+                "{ASSERT_CLASS_BRAND} {}",
+                "var _C_brand = new WeakSet(); class C { constructor() { _C_brand.add(this); } x; static y = 1; f() { _m.call(_assertClassBrand(_C_brand, this)); } } function _m() {}"
+            ),
+        );
+        test(
+            "class C { x = 1; static #m() {} static f() { C.#m(); } }",
+            &format!(
+                "{ASSERT_CLASS_BRAND} {}",
+                "class C { x = 1; static f() { _m.call(_assertClassBrand(C, C)); } } function _m() {}"
+            ),
+        );
+    }
+
+    #[test]
+    fn target() {
+        let source_text = "class C { #m() {} f() { this.#m(); } }";
+        assert_eq!(print(source_text, Some(TransformTarget::ES2022)), print(source_text, None));
+    }
+}
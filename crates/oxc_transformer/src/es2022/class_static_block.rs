@@ -0,0 +1,211 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::{CompactStr, Span, SPAN};
+use rustc_hash::FxHashSet;
+
+use crate::context::Ctx;
+
+use super::{
+    class_private_methods::{call, class_declaration, class_declaration_mut, member},
+    diagnostics::StaticBlockInClassExpression,
+};
+
+/// [plugin-transform-class-static-block](https://babel.dev/docs/babel-plugin-transform-class-static-block)
+///
+/// Static blocks are replaced by static methods which are called after the class, in the order
+/// of the blocks. The methods are defined in the class body, so the statements can still access
+/// the private static fields of the class.
+///
+/// In:  `class C { static #x = 1; static { foo(C.#x); } }`
+/// Out: `class C { static #x = 1; static _staticBlock() { foo(C.#x); } } C._staticBlock();`
+///
+/// The blocks run after all static field initializers. Only named class declarations are
+/// transformed, the calls can't be placed after class expressions.
+pub struct ClassStaticBlock<'a> {
+    ctx: Ctx<'a>,
+}
+
+fn has_static_block(class: &Class) -> bool {
+    class.body.body.iter().any(|elem| matches!(elem, ClassElement::StaticBlock(_)))
+}
+
+impl<'a> ClassStaticBlock<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx) }
+    }
+
+    /// Reports class expressions with static blocks, which are not transformed.
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        if class.is_expression() && has_static_block(class) {
+            self.ctx.error(StaticBlockInClassExpression(class.span));
+        }
+    }
+
+    /// Transforms the class declarations of `stmts`, calling the methods after each class.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts.iter().any(|stmt| class_declaration(stmt).is_some_and(has_static_block)) {
+            return;
+        }
+
+        let mut new_stmts = self.ctx.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let calls = class_declaration_mut(&mut stmt)
+                .filter(|class| has_static_block(class))
+                .map(|class| self.lower_class(class));
+            new_stmts.push(stmt);
+            new_stmts.extend(calls.into_iter().flatten());
+        }
+        *stmts = new_stmts;
+    }
+
+    /// Replaces the static blocks of `class` by methods and returns the statements calling them.
+    fn lower_class(&self, class: &mut Class<'a>) -> std::vec::Vec<Statement<'a>> {
+        // `export default class {}`
+        let Some(class_name) = class.id.as_ref().map(|id| id.name.clone()) else {
+            self.ctx.error(StaticBlockInClassExpression(class.span));
+            return vec![];
+        };
+
+        let mut static_names = class
+            .body
+            .body
+            .iter()
+            .filter(|elem| elem.r#static())
+            .filter_map(ClassElement::static_name)
+            .collect::<FxHashSet<_>>();
+
+        let ast = &self.ctx.ast;
+        let mut calls = vec![];
+        let mut uid = 0;
+        for elem in class.body.body.iter_mut() {
+            let ClassElement::StaticBlock(block) = elem else { continue };
+
+            let name = loop {
+                uid += 1;
+                let name = if uid == 1 {
+                    CompactStr::from("_staticBlock")
+                } else {
+                    CompactStr::from(format!("_staticBlock{uid}"))
+                };
+                if static_names.insert(name.clone()) {
+                    break name;
+                }
+            };
+
+            let span = block.span;
+            let statements = ast.move_statement_vec(&mut block.body);
+            *elem = self.create_method(span, &name, statements);
+
+            let callee = member(
+                ast,
+                ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    class_name.clone(),
+                )),
+                &name,
+            );
+            calls.push(ast.expression_statement(SPAN, call(ast, callee, [])));
+        }
+        calls
+    }
+
+    /// `static name() { ...statements }`
+    fn create_method(
+        &self,
+        span: Span,
+        name: &str,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> ClassElement<'a> {
+        let ast = &self.ctx.ast;
+        let params = ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ast.new_vec(),
+            None,
+        );
+        let body = ast.function_body(SPAN, ast.new_vec(), statements);
+        let value =
+            ast.plain_function(FunctionType::FunctionExpression, SPAN, None, params, Some(body));
+        ClassElement::MethodDefinition(ast.alloc(MethodDefinition {
+            r#type: MethodDefinitionType::MethodDefinition,
+            span,
+            key: ast.property_key_identifier(ast.identifier_name(SPAN, name)),
+            kind: MethodDefinitionKind::Method,
+            value,
+            computed: false,
+            r#static: true,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: ast.new_vec(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        helpers::tester::{errors, print},
+        TransformTarget,
+    };
+
+    fn test(source_text: &str, expected: &str) {
+        assert_eq!(print(source_text, Some(TransformTarget::ES2021)), print(expected, None));
+    }
+
+    #[test]
+    fn single_block() {
+        test(
+            "class C { static { foo(); bar(); } }",
+            "class C { static _staticBlock() { foo(); bar(); } } C._staticBlock();",
+        );
+        test(
+            "export class C { static { this.foo = 1; } }",
+            "export class C { static _staticBlock() { this.foo = 1; } } C._staticBlock();",
+        );
+        test("class C { static {} }", "class C { static _staticBlock() {} } C._staticBlock();");
+    }
+
+    #[test]
+    fn multiple_blocks() {
+        test(
+            "class C { static a = 1; static { foo(); } static b = 2; static { var x; bar(x); } }",
+            "class C { static a = 1; static _staticBlock() { foo(); } static b = 2; static _staticBlock2() { var x; bar(x); } }
+             C._staticBlock(); C._staticBlock2();",
+        );
+        test(
+            "class C { static _staticBlock = 1; static { foo(); } }",
+            "class C { static _staticBlock = 1; static _staticBlock2() { foo(); } } C._staticBlock2();",
+        );
+    }
+
+    #[test]
+    fn private_static_field() {
+        test(
+            "class C { static #x = 2; static { C.#x = C.#x + 1; } }",
+            "class C { static #x = 2; static _staticBlock() { C.#x = C.#x + 1; } } C._staticBlock();",
+        );
+        test(
+            "class C { static #x; static { if (cond) { C.#x = 1; } } }",
+            "class C { static #x; static _staticBlock() { if (cond) { C.#x = 1; } } } C._staticBlock();",
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let message = "Class static blocks are only supported in named class declarations.";
+        for source_text in
+            ["var C = class { static { foo(); } }", "export default class { static { foo(); } }"]
+        {
+            assert_eq!(errors(source_text, TransformTarget::ES2021), [message]);
+        }
+    }
+
+    #[test]
+    fn target() {
+        let source_text = "class C { static { foo(); } }";
+        assert_eq!(print(source_text, Some(TransformTarget::ES2022)), print(source_text, None));
+    }
+}
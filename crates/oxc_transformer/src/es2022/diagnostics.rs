@@ -0,0 +1,30 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_span::Span;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Private methods are only supported in named class declarations.")]
+#[diagnostic(severity(warning))]
+pub struct PrivateMethodsInClassExpression(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Private methods are not supported in classes with instance field initializers.")]
+#[diagnostic(severity(warning))]
+pub struct PrivateMethodsWithFieldInitializers(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Private methods can only be called, read, assigned to or checked with `in`, without optional chaining.")]
+#[diagnostic(severity(warning))]
+pub struct PrivateMethodReferenceUnsupported(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Private methods which refer to `super` or to private fields are not supported.")]
+#[diagnostic(severity(warning))]
+pub struct PrivateMethodBodyUnsupported(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Class static blocks are only supported in named class declarations.")]
+#[diagnostic(severity(warning))]
+pub struct StaticBlockInClassExpression(#[label] pub Span);
@@ -0,0 +1,56 @@
+mod class_private_methods;
+mod class_static_block;
+mod diagnostics;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+
+use crate::{context::Ctx, options::TransformTarget};
+
+pub use self::{class_private_methods::ClassPrivateMethods, class_static_block::ClassStaticBlock};
+
+/// ES2022 syntax, lowered when the target is older than ES2022
+///
+/// This includes the following plugins:
+///
+/// * [plugin-transform-class-static-block](https://babel.dev/docs/babel-plugin-transform-class-static-block)
+/// * [plugin-transform-private-methods](https://babel.dev/docs/babel-plugin-transform-private-methods)
+pub struct ES2022<'a> {
+    enabled: bool,
+    class_static_block: ClassStaticBlock<'a>,
+    class_private_methods: ClassPrivateMethods<'a>,
+}
+
+// Constructors
+impl<'a> ES2022<'a> {
+    pub fn new(target: TransformTarget, ctx: &Ctx<'a>) -> Self {
+        Self {
+            enabled: target < TransformTarget::ES2022,
+            class_static_block: ClassStaticBlock::new(ctx),
+            class_private_methods: ClassPrivateMethods::new(ctx),
+        }
+    }
+}
+
+// Transforms
+impl<'a> ES2022<'a> {
+    pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        if self.enabled {
+            self.class_private_methods.transform_program_on_exit(program);
+        }
+    }
+
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        if self.enabled {
+            self.class_static_block.transform_class(class);
+            self.class_private_methods.transform_class(class);
+        }
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if self.enabled {
+            self.class_static_block.transform_statements(stmts);
+            self.class_private_methods.transform_statements(stmts);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::context::Ctx;
+
+/// Temporary variables which hold values that are evaluated only once, declared with `var` at
+/// the top of the function they are used in: `var _obj; (_obj = obj).x = _obj.x + 1;`
+///
+/// Plugins enter a scope for the program and for every function body, and declare the variables
+/// of a scope when exiting it.
+pub struct TemporaryVariables<'a> {
+    ctx: Ctx<'a>,
+    /// Temporary variables of each function being transformed, innermost last.
+    declarations: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> TemporaryVariables<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), declarations: vec![] }
+    }
+
+    pub fn enter_scope(&mut self) {
+        self.declarations.push(self.ctx.ast.new_vec());
+    }
+
+    /// Declares the temporary variables of the exited scope at the start of `stmts`.
+    pub fn exit_scope(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let Some(declarations) = self.declarations.pop() else { return };
+        if declarations.is_empty() {
+            return;
+        }
+        let kind = VariableDeclarationKind::Var;
+        let decl = self.ctx.ast.variable_declaration(SPAN, kind, declarations, Modifiers::empty());
+        stmts.insert(0, Statement::Declaration(Declaration::VariableDeclaration(decl)));
+    }
+
+    /// Returns the expression to evaluate first and the expression which refers to its value
+    /// afterwards: `(_obj = obj)` and `_obj`, or the expression and a copy of it when it has
+    /// no side effects and always evaluates to the same value.
+    pub fn memoise(
+        &mut self,
+        expr: &mut Expression<'a>,
+        parenthesize: bool,
+    ) -> (Expression<'a>, Expression<'a>) {
+        let expr = self.ctx.ast.move_expression(expr);
+        if self.is_static(&expr) {
+            let copy = self.ctx.ast.copy(&expr);
+            return (expr, copy);
+        }
+
+        let name = self.ctx.generate_uid(&uid_name(&expr));
+        self.declare(name.clone());
+
+        let ast = &self.ctx.ast;
+        let target =
+            ast.simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        let mut assignment =
+            ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, expr);
+        if parenthesize {
+            assignment = ast.parenthesized_expression(SPAN, assignment);
+        }
+        let reference = ast.identifier_reference_expression(IdentifierReference::new(SPAN, name));
+        (assignment, reference)
+    }
+
+    /// Literals, `this`, `super` and bindings that are never reassigned.
+    fn is_static(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => true,
+            _ => self.ctx.semantic.symbols().is_static(expr),
+        }
+    }
+
+    fn declare(&mut self, name: Atom<'a>) {
+        let Some(declarations) = self.declarations.last_mut() else { return };
+        let ast = &self.ctx.ast;
+        let kind = VariableDeclarationKind::Var;
+        let id = ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        let id = ast.binding_pattern(id, None, false);
+        declarations.push(ast.variable_declarator(SPAN, kind, id, None, false));
+    }
+
+    /// `() => a.b ||= c` needs a block body once temporary variables are declared in it.
+    pub fn exit_arrow_expression(&self, expr: &mut ArrowFunctionExpression<'a>) {
+        if !expr.expression || expr.body.statements.len() < 2 {
+            return;
+        }
+        let Some(Statement::ExpressionStatement(stmt)) = expr.body.statements.pop() else {
+            return;
+        };
+        let stmt = stmt.unbox();
+        expr.body.statements.push(self.ctx.ast.return_statement(stmt.span, Some(stmt.expression)));
+        expr.expression = false;
+    }
+}
+
+/// The name a temporary variable holding `expr` is based on, `obj.x` -> `obj$x`, `f()` -> `f`.
+fn uid_name(expr: &Expression) -> String {
+    match expr {
+        Expression::Identifier(ident) => ident.name.to_string(),
+        Expression::ThisExpression(_) => "this".into(),
+        Expression::MemberExpression(member) => match member.static_property_name() {
+            Some(property) => format!("{}${property}", uid_name(member.object())),
+            None => uid_name(member.object()),
+        },
+        Expression::CallExpression(call) => uid_name(&call.callee),
+        _ => "ref".into(),
+    }
+}
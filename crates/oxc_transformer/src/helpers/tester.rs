@@ -0,0 +1,55 @@
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::{TransformOptions, TransformTarget, Transformer};
+
+/// Prints `source_text` after transforming it for `target`, or as it is with `None`.
+pub fn print(source_text: &str, target: Option<TransformTarget>) -> String {
+    print_with_options(
+        source_text,
+        target.map(|target| TransformOptions { target, ..TransformOptions::default() }),
+    )
+}
+
+/// Prints `source_text` after transforming it with `options`, or as it is with `None`.
+pub fn print_with_options(source_text: &str, options: Option<TransformOptions>) -> String {
+    let (printed, errors) = transform(source_text, options);
+    assert!(errors.is_empty(), "{errors:?}");
+    printed
+}
+
+/// The messages of the errors reported when transforming `source_text` for `target`.
+pub fn errors(source_text: &str, target: TransformTarget) -> Vec<String> {
+    let options = TransformOptions { target, ..TransformOptions::default() };
+    transform(source_text, Some(options)).1
+}
+
+fn transform(source_text: &str, options: Option<TransformOptions>) -> (String, Vec<String>) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let semantic = SemanticBuilder::new(source_text, source_type)
+        .with_trivias(ret.trivias)
+        .build_module_record(PathBuf::new(), &ret.program)
+        .build(&ret.program)
+        .semantic;
+    let program = allocator.alloc(ret.program);
+    let mut errors = vec![];
+    if let Some(options) = options {
+        if let Err(errs) =
+            Transformer::new(&allocator, Path::new("test.js"), semantic, options).build(program)
+        {
+            errors.extend(errs.iter().map(ToString::to_string));
+        }
+    }
+    let printed = Codegen::<false>::new("", source_text, CodegenOptions::default())
+        .build(program)
+        .source_text;
+    (printed, errors)
+}
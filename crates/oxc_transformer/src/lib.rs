@@ -12,6 +12,11 @@
 mod compiler_assumptions;
 mod context;
 mod options;
+// Syntax lowering
+mod es2015;
+mod es2016;
+mod es2021;
+mod es2022;
 // Presets: <https://babel.dev/docs/presets>
 mod react;
 mod typescript;
@@ -19,6 +24,9 @@ mod utils;
 
 mod helpers {
     pub mod module_imports;
+    pub mod temporary_variables;
+    #[cfg(test)]
+    pub mod tester;
 }
 
 use std::{path::Path, rc::Rc};
@@ -32,12 +40,18 @@ use oxc_diagnostics::Error;
 use oxc_semantic::Semantic;
 
 pub use crate::{
-    compiler_assumptions::CompilerAssumptions, options::TransformOptions, react::ReactOptions,
-    typescript::TypeScriptOptions,
+    compiler_assumptions::CompilerAssumptions,
+    options::{TransformOptions, TransformTarget},
+    react::ReactOptions,
+    typescript::{TypeScriptOptions, TypeScriptPragmas},
 };
 
 use crate::{
     context::{Ctx, TransformCtx},
+    es2015::ES2015,
+    es2016::ES2016,
+    es2021::ES2021,
+    es2022::ES2022,
     react::React,
     typescript::TypeScript,
 };
@@ -47,6 +61,10 @@ pub struct Transformer<'a> {
     // NOTE: all callbacks must run in order.
     x0_typescript: TypeScript<'a>,
     x1_react: React<'a>,
+    x2_es2022: ES2022<'a>,
+    x3_es2021: ES2021<'a>,
+    x4_es2016: ES2016<'a>,
+    x5_es2015: ES2015<'a>,
 }
 
 impl<'a> Transformer<'a> {
@@ -61,6 +79,10 @@ impl<'a> Transformer<'a> {
             ctx: Rc::clone(&ctx),
             x0_typescript: TypeScript::new(options.typescript, &ctx),
             x1_react: React::new(options.react, &ctx),
+            x2_es2022: ES2022::new(options.target, &ctx),
+            x3_es2021: ES2021::new(options.target, &ctx),
+            x4_es2016: ES2016::new(options.target, &ctx),
+            x5_es2015: ES2015::new(options.target, &ctx),
         }
     }
 
@@ -80,7 +102,16 @@ impl<'a> Transformer<'a> {
 
 impl<'a> VisitMut<'a> for Transformer<'a> {
     fn visit_program(&mut self, program: &mut Program<'a>) {
+        self.x3_es2021.transform_program(program);
+        self.x4_es2016.transform_program(program);
+        self.x5_es2015.transform_program(program);
+
         walk_mut::walk_program_mut(self, program);
+
+        self.x5_es2015.transform_program_on_exit(program);
+        self.x4_es2016.transform_program_on_exit(program);
+        self.x3_es2021.transform_program_on_exit(program);
+        self.x2_es2022.transform_program_on_exit(program);
         self.x1_react.transform_program_on_exit(program);
         self.x0_typescript.transform_program_on_exit(program);
     }
@@ -91,6 +122,10 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         self.x0_typescript.transform_arrow_expression(expr);
 
         walk_mut::walk_arrow_expression_mut(self, expr);
+
+        self.x3_es2021.transform_arrow_expression_on_exit(expr);
+        self.x4_es2016.transform_arrow_expression_on_exit(expr);
+        self.x5_es2015.transform_arrow_expression_on_exit(expr);
     }
 
     fn visit_binding_pattern(&mut self, pat: &mut BindingPattern<'a>) {
@@ -107,6 +142,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
 
     fn visit_class(&mut self, class: &mut Class<'a>) {
         self.x0_typescript.transform_class(class);
+        self.x2_es2022.transform_class(class);
 
         walk_mut::walk_class_mut(self, class);
     }
@@ -117,6 +153,12 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         walk_mut::walk_class_body_mut(self, body);
     }
 
+    fn visit_decorator(&mut self, decorator: &mut Decorator<'a>) {
+        self.x0_typescript.transform_decorator(decorator);
+
+        walk_mut::walk_decorator_mut(self, decorator);
+    }
+
     fn visit_export_default_declaration(&mut self, decl: &mut ExportDefaultDeclaration<'a>) {
         self.x1_react.transform_export_default_declaration(decl);
 
@@ -132,6 +174,9 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
         self.x0_typescript.transform_expression(expr);
         self.x1_react.transform_expression(expr);
+        self.x3_es2021.transform_expression(expr);
+        self.x4_es2016.transform_expression(expr);
+        self.x5_es2015.transform_expression(expr);
 
         walk_mut::walk_expression_mut(self, expr);
     }
@@ -148,6 +193,18 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         walk_mut::walk_function_mut(self, func, flags);
     }
 
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        self.x3_es2021.transform_function_body(body);
+        self.x4_es2016.transform_function_body(body);
+        self.x5_es2015.transform_function_body(body);
+
+        walk_mut::walk_function_body_mut(self, body);
+
+        self.x5_es2015.transform_function_body_on_exit(body);
+        self.x4_es2016.transform_function_body_on_exit(body);
+        self.x3_es2021.transform_function_body_on_exit(body);
+    }
+
     fn visit_import_declaration(&mut self, decl: &mut ImportDeclaration<'a>) {
         walk_mut::walk_import_declaration_mut(self, decl);
     }
@@ -187,6 +244,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
 
     fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
         self.x0_typescript.transform_statements(stmts);
+        self.x2_es2022.transform_statements(stmts);
 
         walk_mut::walk_statements_mut(self, stmts);
 
@@ -211,6 +269,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.x0_typescript.transform_statement(stmt);
         walk_mut::walk_statement_mut(self, stmt);
     }
 
@@ -219,11 +278,6 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         walk_mut::walk_declaration_mut(self, decl);
     }
 
-    fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
-        self.x0_typescript.transform_if_statement(stmt);
-        walk_mut::walk_if_statement_mut(self, stmt);
-    }
-
     fn visit_module_declaration(&mut self, decl: &mut ModuleDeclaration<'a>) {
         self.x0_typescript.transform_module_declaration(decl);
         walk_mut::walk_module_declaration_mut(self, decl);
@@ -18,6 +18,11 @@ pub struct TransformOptions {
     /// For more information, check the [assumptions](https://babel.dev/docs/assumptions) documentation page.
     pub assumptions: CompilerAssumptions,
 
+    /// The ECMAScript version the output has to run on, syntax from later versions is lowered.
+    ///
+    /// Defaults to `ESNext`, which keeps all syntax.
+    pub target: TransformTarget,
+
     // Plugins
     /// [preset-typescript](https://babeljs.io/docs/babel-preset-typescript)
     pub typescript: TypeScriptOptions,
@@ -25,3 +30,21 @@ pub struct TransformOptions {
     /// [preset-react](https://babeljs.io/docs/babel-preset-react)
     pub react: ReactOptions,
 }
+
+/// ECMAScript versions, ordered from oldest to newest.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum TransformTarget {
+    ES5,
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+    ES2023,
+    ES2024,
+    #[default]
+    ESNext,
+}
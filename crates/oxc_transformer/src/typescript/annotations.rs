@@ -8,25 +8,30 @@ use crate::TypeScriptOptions;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_span::{Atom, SPAN};
-use oxc_syntax::operator::AssignmentOperator;
+use oxc_syntax::{identifier::is_identifier_name, operator::AssignmentOperator};
 use rustc_hash::FxHashSet;
 
-use super::collector::TypeScriptReferenceCollector;
+use super::{collector::TypeScriptReferenceCollector, ModuleMarker};
 
 pub struct TypeScriptAnnotations<'a> {
     #[allow(dead_code)]
     options: Rc<TypeScriptOptions>,
     ctx: Ctx<'a>,
-    /// Assignments to be added to the constructor body
-    assignments: Vec<'a, Statement<'a>>,
+    /// Names of the parameter properties of the current constructor,
+    /// an assignment is added to the constructor body for each of them
+    parameter_properties: Vec<'a, Atom<'a>>,
     has_super_call: bool,
+    /// `parameter_properties` and `has_super_call` of the constructors enclosing the current one,
+    /// so that a class declared inside a constructor doesn't take over their assignments
+    outer_constructors: std::vec::Vec<(Vec<'a, Atom<'a>>, bool)>,
 }
 
 impl<'a> TypeScriptAnnotations<'a> {
     pub fn new(options: &Rc<TypeScriptOptions>, ctx: &Ctx<'a>) -> Self {
         Self {
             has_super_call: false,
-            assignments: ctx.ast.new_vec(),
+            parameter_properties: ctx.ast.new_vec(),
+            outer_constructors: vec![],
             options: Rc::clone(options),
             ctx: Rc::clone(ctx),
         }
@@ -41,17 +46,32 @@ impl<'a> TypeScriptAnnotations<'a> {
             ast.assignment_expression(
                 SPAN,
                 AssignmentOperator::Assign,
-                ast.simple_assignment_target_member_expression(ast.static_member(
-                    SPAN,
-                    ast.this_expression(SPAN),
-                    ast.identifier_name(SPAN, name),
-                    false,
-                )),
+                ast.simple_assignment_target_member_expression(self.create_this_member(name)),
                 ast.identifier_reference_expression(ast.identifier_reference(SPAN, name)),
             ),
         )
     }
 
+    /// Creates `this.name`, or `this["name"]` when `name` is not a valid identifier name.
+    ///
+    /// Parameter property names are binding identifiers and always use the static form,
+    /// members named after arbitrary strings must go through this to stay valid syntax.
+    fn create_this_member(&self, name: &Atom<'a>) -> MemberExpression<'a> {
+        let ast = &self.ctx.ast;
+        let object = ast.this_expression(SPAN);
+        if is_identifier_name(name) {
+            ast.static_member(SPAN, object, ast.identifier_name(SPAN, name), false)
+        } else {
+            let key = ast.literal_string_expression(StringLiteral::new(SPAN, name.clone()));
+            ast.computed_member(SPAN, object, key, false)
+        }
+    }
+
+    // Creates `this.name = name` for each parameter property
+    fn create_parameter_property_assignments(&self) -> impl Iterator<Item = Statement<'a>> + '_ {
+        self.parameter_properties.iter().map(|name| self.create_this_property_assignment(name))
+    }
+
     // Remove type only imports/exports
     pub fn transform_program_on_exit(
         &self,
@@ -63,8 +83,14 @@ impl<'a> TypeScriptAnnotations<'a> {
         let mut removed_count = 0;
 
         program.body.retain_mut(|stmt| {
-            let Statement::ModuleDeclaration(module_decl) = stmt else {
-                return true;
+            let module_decl = match stmt {
+                Statement::ModuleDeclaration(module_decl) => module_decl,
+                // Import-equals declarations which were not lowered are type-only or unused
+                Statement::Declaration(Declaration::TSImportEqualsDeclaration(_)) => {
+                    removed_count += 1;
+                    return false;
+                }
+                _ => return true,
             };
 
             let need_delete = match &mut **module_decl {
@@ -135,6 +161,11 @@ impl<'a> TypeScriptAnnotations<'a> {
                                 .as_ref()
                                 .is_some_and(|specifiers| specifiers.is_empty()))
                 }
+                // `export default interface Foo {}` and overloads are removed,
+                // `export default class C implements I {}` is kept.
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    decl.declaration.is_typescript_syntax()
+                }
                 _ => false,
             };
 
@@ -148,27 +179,80 @@ impl<'a> TypeScriptAnnotations<'a> {
         });
 
         // Determine if we still have import/export statements, otherwise we
-        // need to inject an empty statement (`export {}`) so that the file is
-        // still considered a module
+        // need to inject a marker statement (`export {}` by default) so that
+        // the file is still considered a module
         if module_count == 0 && removed_count > 0 {
-            let export_decl = ModuleDeclaration::ExportNamedDeclaration(
-                self.ctx.ast.plain_export_named_declaration(SPAN, self.ctx.ast.new_vec(), None),
-            );
-            program.body.push(self.ctx.ast.module_declaration(export_decl));
+            match self.options.module_marker {
+                ModuleMarker::Export => {
+                    let export_decl = ModuleDeclaration::ExportNamedDeclaration(
+                        self.ctx.ast.plain_export_named_declaration(
+                            SPAN,
+                            self.ctx.ast.new_vec(),
+                            None,
+                        ),
+                    );
+                    program.body.push(self.ctx.ast.module_declaration(export_decl));
+                }
+                ModuleMarker::EsModuleFlag => {
+                    program.body.push(self.create_es_module_flag());
+                }
+                ModuleMarker::Disabled => {}
+            }
         }
     }
 
+    // Creates `Object.defineProperty(exports, "__esModule", { value: true })`
+    fn create_es_module_flag(&self) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+
+        let callee = ast.static_member_expression(
+            SPAN,
+            ast.identifier_reference_expression(ast.identifier_reference(SPAN, "Object")),
+            ast.identifier_name(SPAN, "defineProperty"),
+            false,
+        );
+
+        let descriptor = {
+            let property = ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                ast.property_key_identifier(ast.identifier_name(SPAN, "value")),
+                ast.literal_boolean_expression(ast.boolean_literal(SPAN, true)),
+                None,
+                false,
+                false,
+                false,
+            );
+            ast.object_expression(
+                SPAN,
+                ast.new_vec_single(ObjectPropertyKind::ObjectProperty(property)),
+                None,
+            )
+        };
+
+        let mut arguments = ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(
+            ast.identifier_reference_expression(ast.identifier_reference(SPAN, "exports")),
+        ));
+        arguments.push(Argument::Expression(
+            ast.literal_string_expression(ast.string_literal(SPAN, "__esModule")),
+        ));
+        arguments.push(Argument::Expression(descriptor));
+
+        ast.expression_statement(SPAN, ast.call_expression(SPAN, callee, arguments, false, None))
+    }
+
     pub fn transform_arrow_expression(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
         expr.type_parameters = None;
         expr.return_type = None;
     }
 
+    /// Patterns nested in object and array patterns, rest elements included, are visited
+    /// separately and have their annotations removed by their own call.
     pub fn transform_binding_pattern(&mut self, pat: &mut BindingPattern<'a>) {
         pat.type_annotation = None;
-
-        if pat.kind.is_binding_identifier() {
-            pat.optional = false;
-        }
+        // `function f({ a }?: T) {}`
+        pat.optional = false;
     }
 
     pub fn transform_call_expression(&mut self, expr: &mut CallExpression<'a>) {
@@ -176,17 +260,26 @@ impl<'a> TypeScriptAnnotations<'a> {
     }
 
     pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        // Type arguments inside the heritage expression, e.g. `extends mixin<T>()`,
+        // are removed when the expression is walked.
         class.type_parameters = None;
         class.super_type_parameters = None;
         class.implements = None;
     }
 
+    /// Remove type only members: index signatures, `declare` fields, abstract members
+    /// and overload signatures.
+    ///
+    /// The remaining members keep their source order and no members are added to the class
+    /// body, parameter properties are assigned in the constructor body instead. Decorators stay
+    /// on their members, so they are still applied in source order.
     pub fn transform_class_body(&mut self, body: &mut ClassBody<'a>) {
-        // Remove type only members
+        // Computed keys of the retained members are walked afterwards,
+        // so identifiers in them are collected as value references and keep their imports.
         body.body.retain(|elem| match elem {
             ClassElement::MethodDefinition(method) => {
                 matches!(method.r#type, MethodDefinitionType::MethodDefinition)
-                    || !method.value.is_typescript_syntax()
+                    && !method.value.is_typescript_syntax()
             }
             ClassElement::PropertyDefinition(prop) => {
                 if prop.value.as_ref().is_some_and(Expression::is_typescript_syntax)
@@ -203,10 +296,20 @@ impl<'a> TypeScriptAnnotations<'a> {
     }
 
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        // Keep the parentheses around a stripped `as`, `satisfies` or `<T>` expression, so that
+        // the operand still groups the same way inside its parent, e.g. `(<number>a) ** b`.
+        if let Expression::ParenthesizedExpression(paren) = expr {
+            if paren.expression.without_parenthesized().is_typescript_syntax() {
+                paren.expression = self.ctx.ast.copy(paren.expression.get_inner_expression());
+                return;
+            }
+        }
         *expr = self.ctx.ast.copy(expr.get_inner_expression());
     }
 
     pub fn transform_formal_parameter(&mut self, param: &mut FormalParameter<'a>) {
+        // Parameter properties are collected in `transform_method_definition` before this runs.
+        // Decorators are left in place for the decorator transform.
         param.accessibility = None;
     }
 
@@ -228,14 +331,18 @@ impl<'a> TypeScriptAnnotations<'a> {
         // Collects parameter properties so that we can add an assignment
         // for each of them in the constructor body.
         if def.kind == MethodDefinitionKind::Constructor {
+            let parameter_properties =
+                std::mem::replace(&mut self.parameter_properties, self.ctx.ast.new_vec());
+            let has_super_call = std::mem::take(&mut self.has_super_call);
+            self.outer_constructors.push((parameter_properties, has_super_call));
+
             for param in &def.value.params.items {
-                if !param.is_public() {
+                if !param.is_parameter_property() {
                     continue;
                 }
 
                 if let Some(id) = param.pattern.get_identifier() {
-                    let assignment = self.create_this_property_assignment(id);
-                    self.assignments.push(assignment);
+                    self.parameter_properties.push(id.clone());
                 }
             }
         }
@@ -246,24 +353,25 @@ impl<'a> TypeScriptAnnotations<'a> {
     }
 
     pub fn transform_method_definition_on_exit(&mut self, def: &mut MethodDefinition<'a>) {
-        if def.kind == MethodDefinitionKind::Constructor && !self.assignments.is_empty() {
-            // When the constructor doesn't have a super call,
-            // we simply add assignments to the bottom of the function body
-            if self.has_super_call {
-                self.assignments.clear();
-            } else {
-                def.value
-                    .body
-                    .get_or_insert_with(|| {
-                        self.ctx.ast.function_body(
-                            SPAN,
-                            self.ctx.ast.new_vec(),
-                            self.ctx.ast.new_vec(),
-                        )
-                    })
-                    .statements
-                    .extend(self.assignments.drain(..));
-            }
+        if def.kind != MethodDefinitionKind::Constructor {
+            return;
+        }
+        // When the constructor doesn't have a super call,
+        // we simply add assignments to the top of the function body
+        if !self.parameter_properties.is_empty() && !self.has_super_call {
+            let assignments = self.create_parameter_property_assignments();
+            let assignments = self.ctx.ast.new_vec_from_iter(assignments);
+            def.value
+                .body
+                .get_or_insert_with(|| {
+                    self.ctx.ast.function_body(SPAN, self.ctx.ast.new_vec(), self.ctx.ast.new_vec())
+                })
+                .statements
+                .splice(0..0, assignments);
+        }
+        if let Some((parameter_properties, has_super_call)) = self.outer_constructors.pop() {
+            self.parameter_properties = parameter_properties;
+            self.has_super_call = has_super_call;
         }
     }
 
@@ -300,49 +408,67 @@ impl<'a> TypeScriptAnnotations<'a> {
         });
 
         // Add assignments after super calls
-        if !self.assignments.is_empty() {
-            let mut super_indexes = vec![];
-            for (index, stmt) in stmts.iter().rev().enumerate() {
-                if matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
-                {
-                    super_indexes.push(index);
-                }
-            }
-            if !super_indexes.is_empty() {
+        if !self.parameter_properties.is_empty() {
+            let is_super_call = |stmt: &Statement<'a>| match stmt {
+                Statement::ExpressionStatement(stmt) => stmt.expression.is_super_call_expression(),
+                _ => false,
+            };
+            let super_calls = stmts.iter().filter(|stmt| is_super_call(stmt)).count();
+            if super_calls > 0 {
                 self.has_super_call = true;
-                for index in super_indexes.iter().rev() {
-                    stmts.splice((index + 1)..=*index, self.ctx.ast.copy(&self.assignments));
+                // Each super call gets its own assignments, built directly into the new list
+                // instead of copying a shared list once per call
+                let capacity = stmts.len() + super_calls * self.parameter_properties.len();
+                let mut new_stmts = self.ctx.ast.new_vec_with_capacity(capacity);
+                for stmt in stmts.drain(..) {
+                    let insert_assignments = is_super_call(&stmt);
+                    new_stmts.push(stmt);
+                    if insert_assignments {
+                        new_stmts.extend(self.create_parameter_property_assignments());
+                    }
                 }
+                *stmts = new_stmts;
             }
         }
     }
 
-    /// Transform if statement's consequent and alternate to block statements if they are super calls
+    /// Transform the bodies of statements to block statements if they are super calls,
+    /// so that the parameter property assignments can be added after them
     /// ```ts
     /// if (true) super() else super();
     /// // to
     /// if (true) { super() } else { super() }
     /// ```
-    pub fn transform_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
-        if !self.assignments.is_empty() {
-            if matches!(&stmt.consequent, Statement::ExpressionStatement(expr) if expr.expression.is_super_call_expression())
-            {
-                stmt.consequent =
-                    self.ctx.ast.block_statement(self.ctx.ast.block(
-                        SPAN,
-                        self.ctx.ast.new_vec_single(self.ctx.ast.copy(&stmt.consequent)),
-                    ));
-            }
-            if let Some(alternate) = &stmt.alternate {
-                if matches!(alternate, Statement::ExpressionStatement(expr) if expr.expression.is_super_call_expression())
-                {
-                    stmt.alternate =
-                        Some(self.ctx.ast.block_statement(self.ctx.ast.block(
-                            SPAN,
-                            self.ctx.ast.new_vec_single(self.ctx.ast.copy(alternate)),
-                        )));
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.parameter_properties.is_empty() {
+            return;
+        }
+        match stmt {
+            Statement::IfStatement(stmt) => {
+                self.wrap_super_call(&mut stmt.consequent);
+                if let Some(alternate) = &mut stmt.alternate {
+                    self.wrap_super_call(alternate);
                 }
             }
+            Statement::ForStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            Statement::ForInStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            Statement::ForOfStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            Statement::WhileStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            Statement::DoWhileStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            Statement::LabeledStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            Statement::WithStatement(stmt) => self.wrap_super_call(&mut stmt.body),
+            _ => {}
+        }
+    }
+
+    fn wrap_super_call(&self, body: &mut Statement<'a>) {
+        if matches!(body, Statement::ExpressionStatement(expr) if expr.expression.is_super_call_expression())
+        {
+            let stmt = self.ctx.ast.move_statement(body);
+            *body = self
+                .ctx
+                .ast
+                .block_statement(self.ctx.ast.block(SPAN, self.ctx.ast.new_vec_single(stmt)));
         }
     }
 
@@ -1,25 +1,32 @@
 use oxc_ast::ast::{ExportNamedDeclaration, IdentifierReference};
 use oxc_span::Atom;
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 
 /// Collects identifier references
 /// Indicates whether the BindingIdentifier is referenced or used in the ExportNamedDeclaration
 #[derive(Debug)]
 pub struct TypeScriptReferenceCollector<'a> {
-    names: FxHashSet<Atom<'a>>,
+    /// Number of value references of each name
+    names: FxHashMap<Atom<'a>, u32>,
 }
 
 impl<'a> TypeScriptReferenceCollector<'a> {
     pub fn new() -> Self {
-        Self { names: FxHashSet::default() }
+        Self { names: FxHashMap::default() }
+    }
+
+    /// Number of times `name` is referenced as a value, type references are removed
+    /// before they are visited and aren't counted.
+    pub fn reference_count(&self, name: &Atom) -> u32 {
+        self.names.get(name).copied().unwrap_or_default()
     }
 
     pub fn has_reference(&self, name: &Atom) -> bool {
-        self.names.contains(name)
+        self.reference_count(name) > 0
     }
 
     pub fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
-        self.names.insert(ident.name.clone());
+        self.add_reference(&ident.name);
     }
 
     pub fn visit_transform_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
@@ -29,8 +36,19 @@ impl<'a> TypeScriptReferenceCollector<'a> {
 
         for specifier in &decl.specifiers {
             if specifier.export_kind.is_value() {
-                self.names.insert(specifier.local.name().clone());
+                self.add_reference(specifier.local.name());
             }
         }
     }
+
+    /// Stops counting a reference from code which is removed, such as an unused alias.
+    pub fn remove_reference(&mut self, name: &Atom<'a>) {
+        if let Some(count) = self.names.get_mut(name) {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    fn add_reference(&mut self, name: &Atom<'a>) {
+        *self.names.entry(name.clone()).or_default() += 1;
+    }
 }
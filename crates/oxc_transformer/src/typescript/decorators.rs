@@ -0,0 +1,424 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, GetSpan, Span, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UpdateOperator},
+    NumberBase,
+};
+use rustc_hash::FxHashSet;
+
+use crate::context::Ctx;
+
+use super::diagnostics::{ParameterDecoratorComputedKey, ParameterDecoratorInClassExpression};
+
+/// Lowers the decorators of classes with parameter decorators to `_decorate` calls, as
+/// TypeScript does with `experimentalDecorators`.
+///
+/// Parameter decorators are not JavaScript syntax. They become `_param(index, decorator)`
+/// entries after the decorators of their method, or of the class for constructor parameters,
+/// and all decorators of the class are applied after its declaration.
+///
+/// In:  `@dec class C { constructor(@inject a) {} @dec m(@inject b) {} }`
+/// Out: `class C { constructor(a) {} m(b) {} }
+///       _decorate([dec, _param(0, inject)], C.prototype, "m", null);
+///       C = _decorate([dec, _param(0, inject)], C);`
+///
+/// The decorators of classes without parameter decorators are left in place.
+pub struct TypeScriptDecorators<'a> {
+    ctx: Ctx<'a>,
+    /// Spans of the classes whose decorators are lowered when their statement is exited.
+    classes: FxHashSet<Span>,
+    /// `_decorate(decorators, target, key, desc)`
+    decorate: Option<Atom<'a>>,
+    /// `_param(index, decorator)`
+    param: Option<Atom<'a>>,
+}
+
+impl<'a> TypeScriptDecorators<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), classes: FxHashSet::default(), decorate: None, param: None }
+    }
+
+    pub fn transform_program_on_exit(&self, program: &mut Program<'a>) {
+        if let Some(name) = self.param.clone() {
+            program.body.insert(0, self.create_param(name));
+        }
+        if let Some(name) = self.decorate.clone() {
+            program.body.insert(0, self.create_decorate(name));
+        }
+    }
+
+    /// Turns parameter decorators into `_param(index, decorator)` decorators of their method or
+    /// class. Runs before the metadata is added, which TypeScript applies first.
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        let has_parameter_decorators = class.body.body.iter().any(|elem| match elem {
+            ClassElement::MethodDefinition(def) => {
+                def.value.params.items.iter().any(|param| !param.decorators.is_empty())
+            }
+            _ => false,
+        });
+        if !has_parameter_decorators {
+            return;
+        }
+        if class.is_expression() {
+            self.ctx.error(ParameterDecoratorInClassExpression(class.span));
+            return;
+        }
+        // `export default class {}`
+        if class.id.is_none() {
+            class.id = Some(BindingIdentifier::new(SPAN, self.ctx.generate_uid("default")));
+        }
+
+        let param = self.param.get_or_insert_with(|| self.ctx.generate_uid("param")).clone();
+        let ast = &self.ctx.ast;
+        for elem in class.body.body.iter_mut() {
+            let ClassElement::MethodDefinition(def) = elem else { continue };
+            let def = &mut **def;
+            let decorators = if def.kind == MethodDefinitionKind::Constructor {
+                &mut class.decorators
+            } else {
+                &mut def.decorators
+            };
+            for (index, param_node) in def.value.params.items.iter_mut().enumerate() {
+                for decorator in param_node.decorators.drain(..) {
+                    let raw = ast.new_str(&index.to_string());
+                    #[allow(clippy::cast_precision_loss)]
+                    let index = ast.literal_number_expression(ast.number_literal(
+                        SPAN,
+                        index as f64,
+                        raw,
+                        NumberBase::Decimal,
+                    ));
+                    let callee = ast.identifier_reference_expression(IdentifierReference::new(
+                        SPAN,
+                        param.clone(),
+                    ));
+                    let expr = call(ast, callee, [index, decorator.expression]);
+                    decorators.push(ast.decorator(decorator.span, expr));
+                }
+            }
+        }
+        self.classes.insert(class.span);
+    }
+
+    /// Applies the decorators of the lowered classes of `stmts` after their declarations.
+    pub fn transform_statements_on_exit(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if self.classes.is_empty() {
+            return;
+        }
+        let mut new_stmts = self.ctx.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let decorations = class_declaration_mut(&mut stmt)
+                .filter(|class| self.classes.contains(&class.span))
+                .map(|class| self.lower_class(class));
+            new_stmts.push(stmt);
+            if let Some(decorations) = decorations {
+                new_stmts.extend(decorations);
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    /// `_decorate([dec], C.prototype, "m", null);` for each decorated member,
+    /// then `C = _decorate([dec], C);`
+    fn lower_class(&mut self, class: &mut Class<'a>) -> std::vec::Vec<Statement<'a>> {
+        self.classes.remove(&class.span);
+        let Some(id) = &class.id else { return vec![] };
+        let class_name = id.name.clone();
+        let decorate =
+            self.decorate.get_or_insert_with(|| self.ctx.generate_uid("decorate")).clone();
+        let ast = &self.ctx.ast;
+        let class_reference = || {
+            ast.identifier_reference_expression(IdentifierReference::new(SPAN, class_name.clone()))
+        };
+        let decorate_reference = || {
+            ast.identifier_reference_expression(IdentifierReference::new(SPAN, decorate.clone()))
+        };
+
+        let mut stmts = vec![];
+        for elem in class.body.body.iter_mut() {
+            let (decorators, key, r#static, descriptor) = match elem {
+                ClassElement::MethodDefinition(def) => {
+                    let def = &mut **def;
+                    let null = ast.literal_null_expression(NullLiteral::new(SPAN));
+                    (&mut def.decorators, &def.key, def.r#static, null)
+                }
+                ClassElement::PropertyDefinition(def) => {
+                    let def = &mut **def;
+                    (&mut def.decorators, &def.key, def.r#static, ast.void_0())
+                }
+                ClassElement::AccessorProperty(def) => {
+                    let def = &mut **def;
+                    (&mut def.decorators, &def.key, def.r#static, ast.void_0())
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => continue,
+            };
+            if decorators.is_empty() {
+                continue;
+            }
+            let Some(name) = key.static_name() else {
+                self.ctx.error(ParameterDecoratorComputedKey(key.span()));
+                continue;
+            };
+            let target = if r#static {
+                class_reference()
+            } else {
+                member(ast, class_reference(), "prototype")
+            };
+            let decorators = decorator_array(ast, decorators);
+            let name = ast.literal_string_expression(ast.string_literal(SPAN, &name));
+            let decorate = call(ast, decorate_reference(), [decorators, target, name, descriptor]);
+            stmts.push(ast.expression_statement(SPAN, decorate));
+        }
+
+        if !class.decorators.is_empty() {
+            let decorators = decorator_array(ast, &mut class.decorators);
+            let decorate = call(ast, decorate_reference(), [decorators, class_reference()]);
+            let target = ast.simple_assignment_target_identifier(IdentifierReference::new(
+                SPAN,
+                class_name.clone(),
+            ));
+            let assign =
+                ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, decorate);
+            stmts.push(ast.expression_statement(SPAN, assign));
+        }
+        stmts
+    }
+
+    /// ```js
+    /// function _decorate(decorators, target, key, desc) {
+    ///   var c = arguments.length,
+    ///     r = c < 3 ? target : desc === null ? (desc = Object.getOwnPropertyDescriptor(target, key)) : desc;
+    ///   for (var i = decorators.length - 1; i >= 0; i--)
+    ///     r = (c < 3 ? decorators[i](r) : c > 3 ? decorators[i](target, key, r) : decorators[i](target, key)) || r;
+    ///   return c > 3 && r && Object.defineProperty(target, key, r), r;
+    /// }
+    /// ```
+    fn create_decorate(&self, name: Atom<'a>) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let ident = |name: &str| identifier(ast, name);
+        let number = |value: f64, raw: &'static str| {
+            ast.literal_number_expression(ast.number_literal(SPAN, value, raw, NumberBase::Decimal))
+        };
+        let binary = |left, operator, right| ast.binary_expression(SPAN, left, operator, right);
+        let assign = |name: &str, value| {
+            let target =
+                ast.simple_assignment_target_identifier(ast.identifier_reference(SPAN, name));
+            ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value)
+        };
+
+        // var c = arguments.length, r = ...;
+        let descriptor = call(
+            ast,
+            member(ast, ident("Object"), "getOwnPropertyDescriptor"),
+            [ident("target"), ident("key")],
+        );
+        let descriptor = ast.conditional_expression(
+            SPAN,
+            binary(
+                ident("desc"),
+                BinaryOperator::StrictEquality,
+                ast.literal_null_expression(NullLiteral::new(SPAN)),
+            ),
+            ast.parenthesized_expression(SPAN, assign("desc", descriptor)),
+            ident("desc"),
+        );
+        let initial = ast.conditional_expression(
+            SPAN,
+            binary(ident("c"), BinaryOperator::LessThan, number(3.0, "3")),
+            ident("target"),
+            descriptor,
+        );
+        let declarations = ast.new_vec_from_iter([
+            var_declarator(ast, "c", Some(member(ast, ident("arguments"), "length"))),
+            var_declarator(ast, "r", Some(initial)),
+        ]);
+        let kind = VariableDeclarationKind::Var;
+        let declaration = ast.variable_declaration(SPAN, kind, declarations, Modifiers::empty());
+        let declaration = Statement::Declaration(Declaration::VariableDeclaration(declaration));
+
+        // for (var i = decorators.length - 1; i >= 0; i--) r = (...) || r;
+        let length = member(ast, ident("decorators"), "length");
+        let init = binary(length, BinaryOperator::Subtraction, number(1.0, "1"));
+        let init = ast.variable_declaration(
+            SPAN,
+            kind,
+            ast.new_vec_single(var_declarator(ast, "i", Some(init))),
+            Modifiers::empty(),
+        );
+        let test = binary(ident("i"), BinaryOperator::GreaterEqualThan, number(0.0, "0"));
+        let update = ast.update_expression(
+            SPAN,
+            UpdateOperator::Decrement,
+            false,
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                ast.alloc(ast.identifier_reference(SPAN, "i")),
+            ),
+        );
+        let decorator =
+            || ast.computed_member_expression(SPAN, ident("decorators"), ident("i"), false);
+        let apply = ast.conditional_expression(
+            SPAN,
+            binary(ident("c"), BinaryOperator::LessThan, number(3.0, "3")),
+            call(ast, decorator(), [ident("r")]),
+            ast.conditional_expression(
+                SPAN,
+                binary(ident("c"), BinaryOperator::GreaterThan, number(3.0, "3")),
+                call(ast, decorator(), [ident("target"), ident("key"), ident("r")]),
+                call(ast, decorator(), [ident("target"), ident("key")]),
+            ),
+        );
+        let apply = ast.logical_expression(
+            SPAN,
+            ast.parenthesized_expression(SPAN, apply),
+            LogicalOperator::Or,
+            ident("r"),
+        );
+        let body = ast.expression_statement(SPAN, assign("r", apply));
+        let for_statement = ast.for_statement(
+            SPAN,
+            Some(ForStatementInit::VariableDeclaration(init)),
+            Some(test),
+            Some(update),
+            body,
+        );
+
+        // return c > 3 && r && Object.defineProperty(target, key, r), r;
+        let define = call(
+            ast,
+            member(ast, ident("Object"), "defineProperty"),
+            [ident("target"), ident("key"), ident("r")],
+        );
+        let define = ast.logical_expression(
+            SPAN,
+            ast.logical_expression(
+                SPAN,
+                binary(ident("c"), BinaryOperator::GreaterThan, number(3.0, "3")),
+                LogicalOperator::And,
+                ident("r"),
+            ),
+            LogicalOperator::And,
+            define,
+        );
+        let result = ast.sequence_expression(SPAN, ast.new_vec_from_iter([define, ident("r")]));
+
+        let statements = ast.new_vec_from_iter([
+            declaration,
+            for_statement,
+            ast.return_statement(SPAN, Some(result)),
+        ]);
+        create_function(ast, name, &["decorators", "target", "key", "desc"], statements)
+    }
+
+    /// ```js
+    /// function _param(index, decorator) {
+    ///   return function (target, key) { decorator(target, key, index); };
+    /// }
+    /// ```
+    fn create_param(&self, name: Atom<'a>) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let ident = |name: &str| identifier(ast, name);
+        let apply = call(ast, ident("decorator"), [ident("target"), ident("key"), ident("index")]);
+        let body = ast.new_vec_single(ast.expression_statement(SPAN, apply));
+        let body = ast.function_body(SPAN, ast.new_vec(), body);
+        let func = ast.plain_function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            formal_parameters(ast, &["target", "key"]),
+            Some(body),
+        );
+        let statements =
+            ast.new_vec_single(ast.return_statement(SPAN, Some(ast.function_expression(func))));
+        create_function(ast, name, &["index", "decorator"], statements)
+    }
+}
+
+/// `[a, b]` from the decorators `@a @b`, which are removed.
+fn decorator_array<'a>(
+    ast: &AstBuilder<'a>,
+    decorators: &mut Vec<'a, Decorator<'a>>,
+) -> Expression<'a> {
+    let elements = ast.new_vec_from_iter(
+        decorators
+            .drain(..)
+            .map(|decorator| ArrayExpressionElement::Expression(decorator.expression)),
+    );
+    ast.array_expression(SPAN, elements, None)
+}
+
+fn class_declaration_mut<'b, 'a>(stmt: &'b mut Statement<'a>) -> Option<&'b mut Class<'a>> {
+    match stmt {
+        Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(&mut **class),
+        Statement::ModuleDeclaration(decl) => match &mut **decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(&mut **class),
+                _ => None,
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(&mut **class),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn create_function<'a>(
+    ast: &AstBuilder<'a>,
+    name: Atom<'a>,
+    params: &[&str],
+    statements: Vec<'a, Statement<'a>>,
+) -> Statement<'a> {
+    let body = ast.function_body(SPAN, ast.new_vec(), statements);
+    let id = Some(BindingIdentifier::new(SPAN, name));
+    let func = ast.plain_function(
+        FunctionType::FunctionDeclaration,
+        SPAN,
+        id,
+        formal_parameters(ast, params),
+        Some(body),
+    );
+    ast.function_declaration(func)
+}
+
+fn formal_parameters<'a>(
+    ast: &AstBuilder<'a>,
+    params: &[&str],
+) -> oxc_allocator::Box<'a, FormalParameters<'a>> {
+    let params = ast.new_vec_from_iter(params.iter().map(|param| {
+        let id = ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, ast.new_atom(param)));
+        ast.plain_formal_parameter(SPAN, ast.binding_pattern(id, None, false))
+    }));
+    ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, None)
+}
+
+fn var_declarator<'a>(
+    ast: &AstBuilder<'a>,
+    name: &str,
+    init: Option<Expression<'a>>,
+) -> VariableDeclarator<'a> {
+    let id = ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, ast.new_atom(name)));
+    let id = ast.binding_pattern(id, None, false);
+    ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, init, false)
+}
+
+fn identifier<'a>(ast: &AstBuilder<'a>, name: &str) -> Expression<'a> {
+    ast.identifier_reference_expression(ast.identifier_reference(SPAN, name))
+}
+
+fn member<'a>(ast: &AstBuilder<'a>, object: Expression<'a>, property: &str) -> Expression<'a> {
+    ast.static_member_expression(SPAN, object, ast.identifier_name(SPAN, property), false)
+}
+
+fn call<'a, const N: usize>(
+    ast: &AstBuilder<'a>,
+    callee: Expression<'a>,
+    arguments: [Expression<'a>; N],
+) -> Expression<'a> {
+    let arguments = ast.new_vec_from_iter(arguments.into_iter().map(Argument::Expression));
+    ast.call_expression(SPAN, callee, arguments, false, None)
+}
@@ -13,3 +13,18 @@ pub struct ImportEqualsRequireUnsupported(#[label] pub Span);
 #[error("`export = <value>;` is only supported when compiling modules to CommonJS.\nPlease consider using `export default <value>;`, or add @babel/plugin-transform-modules-commonjs to your Babel config.")]
 #[diagnostic(severity(warning))]
 pub struct ExportAssignmentUnsupported(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Namespaces exporting non-const are not supported by Babel. Change to const or see: https://babeljs.io/docs/en/babel-plugin-transform-typescript#impartial-namespace-support")]
+#[diagnostic(severity(warning))]
+pub struct NamespaceExportNonConst(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Parameter decorators are only supported in class declarations.")]
+#[diagnostic(severity(warning))]
+pub struct ParameterDecoratorInClassExpression(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators of members with computed keys are not supported in classes with parameter decorators.")]
+#[diagnostic(severity(warning))]
+pub struct ParameterDecoratorComputedKey(#[label] pub Span);
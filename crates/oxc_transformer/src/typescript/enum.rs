@@ -1,18 +1,258 @@
-use std::mem;
+use std::{mem, rc::Rc};
 
 use oxc_allocator::{Box, Vec};
-use oxc_ast::ast::*;
-use oxc_span::{Atom, SPAN};
+use oxc_ast::{
+    ast::*,
+    visit::{walk_mut, VisitMut},
+    AstBuilder, AstKind,
+};
+use oxc_semantic::{ScopeId, Semantic, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, Span, SPAN};
 use oxc_syntax::{
-    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     NumberBase,
 };
 
-use crate::utils::is_valid_identifier;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::Ctx, utils::is_valid_identifier, TypeScriptOptions};
+
+/// Whether each member of an enum holds a string value, keyed by member name.
+pub type EnumMembers<'a> = FxHashMap<Atom<'a>, bool>;
+
+/// Values of the members of a `const enum`, keyed by member name.
+type ConstEnumMembers<'a> = FxHashMap<Atom<'a>, ConstantValue<'a>>;
+
+#[derive(Debug, Clone)]
+enum ConstantValue<'a> {
+    Number(f64),
+    String(Atom<'a>),
+}
+
+/// Transforms `enum` declarations into their runtime form,
+/// and inlines the members of `const enum`s.
+///
+/// ```TypeScript
+/// const enum Color { Red, Green = Red + 2 }
+/// foo(Color.Green);
+/// ```
+/// ```JavaScript
+/// foo(2);
+/// ```
+///
+/// A `const enum` keeps its runtime form when it is exported, referenced other than through
+/// one of its members, or has members whose values are not known at compile time.
+pub struct TypeScriptEnum<'a> {
+    options: Rc<TypeScriptOptions>,
+    ctx: Ctx<'a>,
+    /// Members of the enums transformed so far, keyed by the symbol of the enum,
+    /// for merged enum declarations.
+    enums: FxHashMap<SymbolId, EnumMembers<'a>>,
+    /// Members of the `const enum`s seen so far, keyed by the symbol of the enum.
+    const_enums: FxHashMap<SymbolId, ConstEnumMembers<'a>>,
+}
+
+impl<'a> TypeScriptEnum<'a> {
+    pub fn new(options: &Rc<TypeScriptOptions>, ctx: &Ctx<'a>) -> Self {
+        Self {
+            options: Rc::clone(options),
+            ctx: Rc::clone(ctx),
+            enums: FxHashMap::default(),
+            const_enums: FxHashMap::default(),
+        }
+    }
+
+    /// Evaluates the `const enum`s declared in `stmts` before any of their uses are visited,
+    /// and removes the declarations which are not needed at runtime.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut inlined = FxHashSet::default();
+        for stmt in stmts.iter() {
+            let decl = match stmt {
+                Statement::Declaration(Declaration::TSEnumDeclaration(decl)) => decl,
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+                        Some(Declaration::TSEnumDeclaration(decl)) => decl,
+                        _ => continue,
+                    },
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !decl.modifiers.contains(ModifierKind::Const) {
+                continue;
+            }
+            let Some(symbol_id) = decl.id.symbol_id.get() else { continue };
+            if self.evaluate_const_enum(decl, symbol_id) {
+                inlined.insert(symbol_id);
+            }
+        }
+
+        if inlined.is_empty() {
+            return;
+        }
+        inlined.retain(|symbol_id| self.is_only_member_accessed(*symbol_id));
+        stmts.retain(|stmt| match stmt {
+            Statement::Declaration(Declaration::TSEnumDeclaration(decl)) => {
+                !decl.id.symbol_id.get().is_some_and(|symbol_id| inlined.contains(&symbol_id))
+            }
+            _ => true,
+        });
+    }
+
+    /// `Color.Red` -> `0`
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+        let Expression::MemberExpression(member) = expr else { return };
+        let Some(value) = self.const_enum_member(member) else { return };
+        let span = member.span();
+        *expr = self.constant_expression(value, span);
+    }
+
+    /// The value of `Color.Red` or `Color["Red"]` when `Color` refers to an inlined `const enum`.
+    fn const_enum_member(&self, member: &MemberExpression<'a>) -> Option<&ConstantValue<'a>> {
+        let Expression::Identifier(ident) = member.object() else { return None };
+        let name = member.static_property_name()?;
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.ctx.semantic.symbols().get_reference(reference_id).symbol_id()?;
+        self.const_enums.get(&symbol_id)?.get(name)
+    }
+
+    /// Records the values of the members of a `const enum`, merged with earlier declarations of it.
+    ///
+    /// Returns `false` and leaves the enum to its runtime form when any value is not a constant.
+    fn evaluate_const_enum(&mut self, decl: &TSEnumDeclaration<'a>, symbol_id: SymbolId) -> bool {
+        let mut members = self.const_enums.remove(&symbol_id).unwrap_or_default();
+        let mut next = Some(0.0);
+        for member in &decl.members {
+            let name = match &member.id {
+                TSEnumMemberName::Identifier(id) => id.name.clone(),
+                TSEnumMemberName::StringLiteral(str) => str.value.clone(),
+                TSEnumMemberName::ComputedPropertyName(..)
+                | TSEnumMemberName::NumericLiteral(..) => return false,
+            };
+            let value = match &member.initializer {
+                Some(init) => self.evaluate(init, &decl.id.name, &members),
+                None => next.map(ConstantValue::Number),
+            };
+            let Some(value) = value else { return false };
+            next = match value {
+                ConstantValue::Number(value) => Some(value + 1.0),
+                ConstantValue::String(_) => None,
+            };
+            members.insert(name, value);
+        }
+        self.const_enums.insert(symbol_id, members);
+        true
+    }
+
+    /// Evaluates a `const enum` member initializer, which may refer to earlier members
+    /// by name (`B = A << 1`) or through the enum (`B = E.A`), and to other inlined enums.
+    fn evaluate(
+        &self,
+        expr: &Expression<'a>,
+        enum_name: &Atom<'a>,
+        members: &ConstEnumMembers<'a>,
+    ) -> Option<ConstantValue<'a>> {
+        match expr {
+            Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+            Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.clone())),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                lit.quasis.first()?.value.cooked.clone().map(ConstantValue::String)
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.evaluate(&expr.expression, enum_name, members)
+            }
+            Expression::Identifier(ident) => members.get(&ident.name).cloned(),
+            Expression::MemberExpression(member) => {
+                let is_own_member = matches!(
+                    member.object(),
+                    Expression::Identifier(ident) if ident.name == enum_name
+                );
+                if is_own_member {
+                    members.get(member.static_property_name()?).cloned()
+                } else {
+                    self.const_enum_member(member).cloned()
+                }
+            }
+            Expression::UnaryExpression(expr) => {
+                let ConstantValue::Number(value) =
+                    self.evaluate(&expr.argument, enum_name, members)?
+                else {
+                    return None;
+                };
+                match expr.operator {
+                    UnaryOperator::UnaryNegation => Some(ConstantValue::Number(-value)),
+                    UnaryOperator::UnaryPlus => Some(ConstantValue::Number(value)),
+                    UnaryOperator::BitwiseNot => {
+                        Some(ConstantValue::Number(f64::from(!to_int_32(value))))
+                    }
+                    _ => None,
+                }
+            }
+            Expression::BinaryExpression(expr) => {
+                let left = self.evaluate(&expr.left, enum_name, members)?;
+                let right = self.evaluate(&expr.right, enum_name, members)?;
+                match (left, right) {
+                    (ConstantValue::String(left), ConstantValue::String(right))
+                        if expr.operator == BinaryOperator::Addition =>
+                    {
+                        let value = self.ctx.ast.new_atom(&format!("{left}{right}"));
+                        Some(ConstantValue::String(value))
+                    }
+                    (ConstantValue::Number(left), ConstantValue::Number(right)) => {
+                        evaluate_binary(expr.operator, left, right).map(ConstantValue::Number)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether every reference to the enum reads one of its members,
+    /// so its declaration can be removed.
+    fn is_only_member_accessed(&self, symbol_id: SymbolId) -> bool {
+        let semantic = &self.ctx.semantic;
+        if semantic.symbols().get_flag(symbol_id).contains(SymbolFlags::Export) {
+            return false;
+        }
+        let Some(members) = self.const_enums.get(&symbol_id) else { return false };
+        semantic.symbols().get_resolved_references(symbol_id).all(|reference| {
+            if reference.flag().is_type() {
+                return true;
+            }
+            let Some(AstKind::MemberExpression(member)) =
+                semantic.nodes().parent_kind(reference.node_id())
+            else {
+                return false;
+            };
+            member.object().span() == reference.span()
+                && member.static_property_name().is_some_and(|name| members.contains_key(name))
+        })
+    }
 
-use super::TypeScript;
+    fn constant_expression(&self, value: &ConstantValue<'a>, span: Span) -> Expression<'a> {
+        let ast = &self.ctx.ast;
+        match value {
+            ConstantValue::String(value) => {
+                ast.literal_string_expression(StringLiteral::new(span, value.clone()))
+            }
+            ConstantValue::Number(value) => {
+                let abs = value.abs();
+                let raw = ast.new_str(&abs.to_string());
+                let lit = ast.number_literal(span, abs, raw, NumberBase::Decimal);
+                let expr = ast.literal_number_expression(lit);
+                if value.is_sign_negative() {
+                    ast.unary_expression(span, UnaryOperator::UnaryNegation, expr)
+                } else {
+                    expr
+                }
+            }
+        }
+    }
 
-impl<'a> TypeScript<'a> {
     /// ```TypeScript
     /// enum Foo {
     ///   X
@@ -25,7 +265,7 @@ impl<'a> TypeScript<'a> {
     /// })(Foo || {});
     /// ```
     pub fn transform_ts_enum(
-        &self,
+        &mut self,
         decl: &mut Box<'a, TSEnumDeclaration<'a>>,
     ) -> Option<Declaration<'a>> {
         if decl.modifiers.contains(ModifierKind::Declare) {
@@ -58,7 +298,8 @@ impl<'a> TypeScript<'a> {
 
         // Foo[Foo["X"] = 0] = "X";
         let enum_name = decl.id.name.clone();
-        let statements = self.transform_ts_enum_members(&mut decl.members, &enum_name);
+        let symbol_id = decl.id.symbol_id.get();
+        let statements = self.transform_ts_enum_members(&mut decl.members, &enum_name, symbol_id);
         let body = self.ctx.ast.function_body(decl.span, self.ctx.ast.new_vec(), statements);
 
         let callee =
@@ -96,10 +337,14 @@ impl<'a> TypeScript<'a> {
         Some(Declaration::VariableDeclaration(variable_declaration))
     }
 
+    /// Members of earlier declarations of the same enum (`enum E { A } enum E { B = A }`)
+    /// and members declared further down are referenced through the enum object as `E["A"]`,
+    /// since they have no local binding in this declaration.
     pub fn transform_ts_enum_members(
-        &self,
+        &mut self,
         members: &mut Vec<'a, TSEnumMember<'a>>,
         enum_name: &Atom<'a>,
+        symbol_id: Option<SymbolId>,
     ) -> Vec<'a, Statement<'a>> {
         let mut default_init = self.ctx.ast.literal_number_expression(NumericLiteral {
             span: SPAN,
@@ -109,6 +354,19 @@ impl<'a> TypeScript<'a> {
         });
         let mut statements = self.ctx.ast.new_vec();
 
+        let previous = symbol_id.and_then(|id| self.enums.remove(&id)).unwrap_or_default();
+        let scope_id = symbol_id.map(|id| self.ctx.semantic.symbols().get_scope_id(id));
+        let names = members
+            .iter()
+            .filter_map(|member| match &member.id {
+                TSEnumMemberName::Identifier(id) => Some(id.name.clone()),
+                TSEnumMemberName::StringLiteral(str) => Some(str.value.clone()),
+                TSEnumMemberName::ComputedPropertyName(..)
+                | TSEnumMemberName::NumericLiteral(..) => None,
+            })
+            .collect::<FxHashSet<_>>();
+        let mut current = EnumMembers::default();
+
         for member in members.iter_mut() {
             let (member_name, member_span) = match &member.id {
                 TSEnumMemberName::Identifier(id) => (&id.name, id.span),
@@ -122,7 +380,19 @@ impl<'a> TypeScript<'a> {
                 .ast
                 .move_expression(member.initializer.as_mut().unwrap_or(&mut default_init));
 
-            let is_str = init.is_string_literal();
+            EnumMemberReferences {
+                ast: &self.ctx.ast,
+                semantic: &self.ctx.semantic,
+                scope_id,
+                enum_name,
+                previous: &previous,
+                current: &current,
+                names: &names,
+            }
+            .visit_expression(&mut init);
+
+            let is_str = is_string_initializer(&init, enum_name, &previous, &current);
+            current.insert(member_name.clone(), is_str);
 
             let mut self_ref = {
                 let obj = self.ctx.ast.identifier_reference_expression(IdentifierReference::new(
@@ -182,7 +452,7 @@ impl<'a> TypeScript<'a> {
                 self.ctx.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, init);
 
             // Foo[Foo["x"] = init] = "x"
-            if !is_str {
+            if !is_str && self.options.enum_reverse_mapping {
                 let member_expr = {
                     let obj = self.ctx.ast.identifier_reference_expression(
                         IdentifierReference::new(SPAN, enum_name.clone()),
@@ -217,6 +487,12 @@ impl<'a> TypeScript<'a> {
             };
         }
 
+        if let Some(symbol_id) = symbol_id {
+            let mut all_members = previous;
+            all_members.extend(current);
+            self.enums.insert(symbol_id, all_members);
+        }
+
         let enum_ref = self
             .ctx
             .ast
@@ -228,3 +504,124 @@ impl<'a> TypeScript<'a> {
         statements
     }
 }
+
+/// Rewrites references to enum members which are not bound in the current declaration to `Foo["x"]`.
+struct EnumMemberReferences<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    semantic: &'b Semantic<'a>,
+    /// The scope the enum is declared in.
+    scope_id: Option<ScopeId>,
+    enum_name: &'b Atom<'a>,
+    /// Members of earlier declarations of the same enum.
+    previous: &'b EnumMembers<'a>,
+    /// Members of the current declaration seen so far, which are bound to local constants.
+    current: &'b EnumMembers<'a>,
+    /// All members of the current declaration, including forward references.
+    names: &'b FxHashSet<Atom<'a>>,
+}
+
+impl<'a, 'b> VisitMut<'a> for EnumMemberReferences<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            let name = &ident.name;
+            if !self.current.contains_key(name)
+                && (self.names.contains(name) || self.previous.contains_key(name))
+                && !self.is_shadowed(ident)
+            {
+                let obj = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    self.enum_name.clone(),
+                ));
+                let key =
+                    self.ast.literal_string_expression(StringLiteral::new(SPAN, name.clone()));
+                *expr = self.ast.computed_member_expression(ident.span, obj, key, false);
+            }
+            return;
+        }
+        walk_mut::walk_expression_mut(self, expr);
+    }
+}
+
+impl<'a, 'b> EnumMemberReferences<'a, 'b> {
+    /// Whether `ident` refers to a binding declared inside the initializer, such as the parameter
+    /// in `B = ((A) => A)(1)`, rather than to a member. Members of the enum shadow bindings of the
+    /// scopes the enum is declared in.
+    fn is_shadowed(&self, ident: &IdentifierReference<'a>) -> bool {
+        let Some(reference_id) = ident.reference_id.get() else { return false };
+        let symbols = self.semantic.symbols();
+        let Some(symbol_id) = symbols.get_reference(reference_id).symbol_id() else {
+            return false;
+        };
+        if symbols.get_flag(symbol_id).contains(SymbolFlags::EnumMember) {
+            return false;
+        }
+        let Some(scope_id) = self.scope_id else { return false };
+        let symbol_scope_id = symbols.get_scope_id(symbol_id);
+        !self.semantic.scopes().ancestors(scope_id).any(|id| id == symbol_scope_id)
+    }
+}
+
+/// Whether the initializer evaluates to a string, in which case no reverse mapping is emitted.
+fn is_string_initializer<'a>(
+    expr: &Expression<'a>,
+    enum_name: &Atom<'a>,
+    previous: &EnumMembers<'a>,
+    current: &EnumMembers<'a>,
+) -> bool {
+    let is_string_member =
+        |name: &str| current.get(name).or_else(|| previous.get(name)).copied().unwrap_or(false);
+
+    match expr {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::Identifier(ident) => is_string_member(ident.name.as_str()),
+        Expression::MemberExpression(member) => {
+            matches!(member.object(), Expression::Identifier(ident) if ident.name == enum_name)
+                && member.static_property_name().is_some_and(is_string_member)
+        }
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            is_string_initializer(&expr.left, enum_name, previous, current)
+                || is_string_initializer(&expr.right, enum_name, previous, current)
+        }
+        Expression::ParenthesizedExpression(expr) => {
+            is_string_initializer(&expr.expression, enum_name, previous, current)
+        }
+        _ => false,
+    }
+}
+
+/// [ToInt32](https://tc39.es/ecma262/#sec-toint32), which wraps modulo 2^32.
+#[allow(clippy::cast_possible_truncation)]
+fn to_int_32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    // `as i64` saturates above 2^63, so reduce the value first.
+    (value.trunc() % 4_294_967_296.0) as i64 as i32
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn evaluate_binary(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+    let value = match operator {
+        BinaryOperator::Addition => left + right,
+        BinaryOperator::Subtraction => left - right,
+        BinaryOperator::Multiplication => left * right,
+        BinaryOperator::Division => left / right,
+        BinaryOperator::Remainder => left % right,
+        BinaryOperator::Exponential => left.powf(right),
+        BinaryOperator::BitwiseOR => f64::from(to_int_32(left) | to_int_32(right)),
+        BinaryOperator::BitwiseAnd => f64::from(to_int_32(left) & to_int_32(right)),
+        BinaryOperator::BitwiseXOR => f64::from(to_int_32(left) ^ to_int_32(right)),
+        BinaryOperator::ShiftLeft => {
+            f64::from(to_int_32(left).wrapping_shl(to_int_32(right) as u32 & 31))
+        }
+        BinaryOperator::ShiftRight => {
+            f64::from(to_int_32(left).wrapping_shr(to_int_32(right) as u32 & 31))
+        }
+        BinaryOperator::ShiftRightZeroFill => {
+            f64::from((to_int_32(left) as u32).wrapping_shr(to_int_32(right) as u32 & 31))
+        }
+        _ => return None,
+    };
+    // `const enum` members must be finite numbers.
+    value.is_finite().then_some(value)
+}
@@ -0,0 +1,281 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstKind};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    operator::{BinaryOperator, UnaryOperator},
+    symbol::SymbolFlags,
+};
+
+use crate::context::Ctx;
+
+/// Emits the `design:*` metadata of decorated classes and members (`emitDecoratorMetadata`).
+///
+/// The metadata is serialized from the type annotations before they are removed, and added as
+/// `@Reflect.metadata(key, value)` decorators after the existing ones.
+///
+/// In:  `class C { @dec m(a: number): string {} }`
+/// Out: `class C { @dec @Reflect.metadata("design:type", Function)
+///       @Reflect.metadata("design:paramtypes", [Number])
+///       @Reflect.metadata("design:returntype", String) m(a) {} }`
+pub struct TypeScriptMetadata<'a> {
+    ctx: Ctx<'a>,
+}
+
+impl<'a> TypeScriptMetadata<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx) }
+    }
+
+    /// Adds `design:paramtypes` with the constructor parameters to a class which is decorated
+    /// or has decorated constructor parameters.
+    pub fn transform_class(&self, class: &mut Class<'a>) {
+        // Overload signatures don't have a body
+        let Some(constructor) = class.body.body.iter().find_map(|elem| match elem {
+            ClassElement::MethodDefinition(def)
+                if def.kind == MethodDefinitionKind::Constructor && def.value.body.is_some() =>
+            {
+                Some(def)
+            }
+            _ => None,
+        }) else {
+            return;
+        };
+        let params = &constructor.value.params;
+        if class.decorators.is_empty() && params.items.iter().all(|p| p.decorators.is_empty()) {
+            return;
+        }
+        let param_types = self.serialize_parameter_types(params);
+        class.decorators.push(self.create_metadata("design:paramtypes", param_types));
+    }
+
+    /// Adds `design:type`, `design:paramtypes` and `design:returntype` to a method whose
+    /// decorators or parameter decorators are applied to it.
+    pub fn transform_method_definition(&self, def: &mut MethodDefinition<'a>) {
+        let has_decorators = !def.decorators.is_empty()
+            || def.value.params.items.iter().any(|param| !param.decorators.is_empty());
+        if !has_decorators {
+            return;
+        }
+
+        let function = &def.value;
+        let (design_type, return_type) = match def.kind {
+            // Parameter decorators of a constructor belong to the class
+            MethodDefinitionKind::Constructor => return,
+            MethodDefinitionKind::Method => {
+                let return_type = match &function.return_type {
+                    Some(annotation) => self.serialize_type(&annotation.type_annotation),
+                    None if function.r#async => self.global("Promise"),
+                    None => self.ctx.ast.void_0(),
+                };
+                (self.global("Function"), Some(return_type))
+            }
+            MethodDefinitionKind::Get => {
+                (self.serialize_type_annotation(function.return_type.as_deref()), None)
+            }
+            MethodDefinitionKind::Set => {
+                let annotation = function
+                    .params
+                    .items
+                    .first()
+                    .and_then(|param| param.pattern.type_annotation.as_deref());
+                (self.serialize_type_annotation(annotation), None)
+            }
+        };
+        let param_types = self.serialize_parameter_types(&function.params);
+
+        def.decorators.push(self.create_metadata("design:type", design_type));
+        def.decorators.push(self.create_metadata("design:paramtypes", param_types));
+        if let Some(return_type) = return_type {
+            def.decorators.push(self.create_metadata("design:returntype", return_type));
+        }
+    }
+
+    /// Adds `design:type` to a decorated property.
+    pub fn transform_property_definition(&self, def: &mut PropertyDefinition<'a>) {
+        if def.decorators.is_empty() {
+            return;
+        }
+        let design_type = self.serialize_type_annotation(def.type_annotation.as_deref());
+        def.decorators.push(self.create_metadata("design:type", design_type));
+    }
+
+    /// `@Reflect.metadata("key", value)`
+    fn create_metadata(&self, key: &str, value: Expression<'a>) -> Decorator<'a> {
+        let ast = &self.ctx.ast;
+        let callee = ast.static_member_expression(
+            SPAN,
+            self.global("Reflect"),
+            ast.identifier_name(SPAN, "metadata"),
+            false,
+        );
+        let mut arguments = ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(
+            ast.literal_string_expression(ast.string_literal(SPAN, key)),
+        ));
+        arguments.push(Argument::Expression(value));
+        ast.decorator(SPAN, ast.call_expression(SPAN, callee, arguments, false, None))
+    }
+
+    fn global(&self, name: &str) -> Expression<'a> {
+        self.ctx.ast.identifier_reference_expression(self.ctx.ast.identifier_reference(SPAN, name))
+    }
+
+    /// `[Number, String]`, the types of the rest parameter elements for `...rest: T[]`.
+    fn serialize_parameter_types(&self, params: &FormalParameters<'a>) -> Expression<'a> {
+        let ast = &self.ctx.ast;
+        let mut elements = ast.new_vec_with_capacity(params.items.len() + 1);
+        for param in &params.items {
+            let param_type =
+                self.serialize_type_annotation(param.pattern.type_annotation.as_deref());
+            elements.push(ArrayExpressionElement::Expression(param_type));
+        }
+        if let Some(rest) = &params.rest {
+            let rest_type = match rest.argument.type_annotation.as_deref() {
+                Some(TSTypeAnnotation { type_annotation: TSType::TSArrayType(array), .. }) => {
+                    self.serialize_type(&array.element_type)
+                }
+                _ => self.global("Object"),
+            };
+            elements.push(ArrayExpressionElement::Expression(rest_type));
+        }
+        ast.array_expression(SPAN, elements, None)
+    }
+
+    fn serialize_type_annotation(
+        &self,
+        annotation: Option<&TSTypeAnnotation<'a>>,
+    ) -> Expression<'a> {
+        match annotation {
+            Some(annotation) => self.serialize_type(&annotation.type_annotation),
+            None => self.global("Object"),
+        }
+    }
+
+    /// Serializes a type to the constructor of its runtime values, `Object` when unknown.
+    fn serialize_type(&self, ty: &TSType<'a>) -> Expression<'a> {
+        match ty {
+            TSType::TSVoidKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNeverKeyword(_)
+            | TSType::TSNullKeyword(_) => self.ctx.ast.void_0(),
+            TSType::TSTypeReference(reference) => {
+                self.serialize_type_reference(&reference.type_name)
+            }
+            // `string | null` is `String`, other unions and intersections are `Object`
+            TSType::TSUnionType(union) => self.serialize_constituents(&union.types),
+            TSType::TSIntersectionType(intersection) => {
+                self.serialize_constituents(&intersection.types)
+            }
+            _ => self.global(Self::constructor_name(ty)),
+        }
+    }
+
+    fn serialize_constituents(&self, types: &[TSType<'a>]) -> Expression<'a> {
+        let mut names = types.iter().filter(|ty| !Self::is_nullish(ty)).map(|ty| match ty {
+            TSType::TSTypeReference(_) | TSType::TSUnionType(_) | TSType::TSIntersectionType(_) => {
+                "Object"
+            }
+            _ => Self::constructor_name(ty),
+        });
+        let name = match names.next() {
+            Some(first) if names.all(|name| name == first) => first,
+            _ => "Object",
+        };
+        self.global(name)
+    }
+
+    fn is_nullish(ty: &TSType<'a>) -> bool {
+        match ty {
+            TSType::TSNullKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNeverKeyword(_) => true,
+            TSType::TSLiteralType(literal) => matches!(literal.literal, TSLiteral::NullLiteral(_)),
+            _ => false,
+        }
+    }
+
+    fn constructor_name(ty: &TSType<'a>) -> &'static str {
+        match ty {
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => "Function",
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => "Array",
+            TSType::TSBooleanKeyword(_) | TSType::TSTypePredicate(_) => "Boolean",
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => "String",
+            TSType::TSNumberKeyword(_) => "Number",
+            TSType::TSBigIntKeyword(_) => "BigInt",
+            TSType::TSSymbolKeyword(_) => "Symbol",
+            TSType::TSLiteralType(literal) => match literal.literal {
+                TSLiteral::BooleanLiteral(_) => "Boolean",
+                TSLiteral::NumericLiteral(_) | TSLiteral::UnaryExpression(_) => "Number",
+                TSLiteral::BigintLiteral(_) => "BigInt",
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => "String",
+                _ => "Object",
+            },
+            _ => "Object",
+        }
+    }
+
+    /// `typeof Foo === "undefined" ? Object : Foo`
+    ///
+    /// The guard keeps the metadata from throwing when `Foo` is only a type, e.g. an interface
+    /// of another module. Bindings which are known to be types are `Object`, so the reference
+    /// doesn't keep a type-only import alive.
+    fn serialize_type_reference(&self, type_name: &TSTypeName<'a>) -> Expression<'a> {
+        let ast = &self.ctx.ast;
+        let first_name = TSTypeName::get_first_name(type_name);
+        if self.is_type_only(&first_name) {
+            return self.global("Object");
+        }
+        let test = ast.binary_expression(
+            SPAN,
+            ast.unary_expression(SPAN, UnaryOperator::Typeof, self.global(&first_name.name)),
+            BinaryOperator::StrictEquality,
+            ast.literal_string_expression(ast.string_literal(SPAN, "undefined")),
+        );
+        ast.conditional_expression(
+            SPAN,
+            test,
+            self.global("Object"),
+            self.type_name_expression(type_name),
+        )
+    }
+
+    /// Whether `ident` refers to a binding without a value: an interface, a type alias or an
+    /// import with `import type` or `import { type A }`.
+    fn is_type_only(&self, ident: &IdentifierReference<'a>) -> bool {
+        let symbols = self.ctx.semantic.symbols();
+        let Some(symbol_id) =
+            ident.reference_id.get().and_then(|id| symbols.get_reference(id).symbol_id())
+        else {
+            return false;
+        };
+        let flags = symbols.get_flag(symbol_id);
+        if !flags.contains(SymbolFlags::ImportBinding) {
+            return flags.intersects(SymbolFlags::TypeAlias | SymbolFlags::Interface)
+                && !flags.intersects(SymbolFlags::Value);
+        }
+        let nodes = self.ctx.semantic.nodes();
+        let declaration = symbols.get_declaration(symbol_id);
+        if let AstKind::ImportSpecifier(specifier) = nodes.kind(declaration) {
+            if specifier.import_kind.is_type() {
+                return true;
+            }
+        }
+        nodes.ancestors(declaration).any(|id| {
+            matches!(nodes.kind(id), AstKind::ImportDeclaration(decl) if decl.import_kind.is_type())
+        })
+    }
+
+    /// `A.B.C`
+    fn type_name_expression(&self, type_name: &TSTypeName<'a>) -> Expression<'a> {
+        match type_name {
+            TSTypeName::IdentifierReference(ident) => self.global(&ident.name),
+            TSTypeName::QualifiedName(name) => self.ctx.ast.static_member_expression(
+                SPAN,
+                self.type_name_expression(&name.left),
+                self.ctx.ast.identifier_name(SPAN, &name.right.name),
+                false,
+            ),
+        }
+    }
+}
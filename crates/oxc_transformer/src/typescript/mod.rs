@@ -1,9 +1,12 @@
 mod annotations;
 mod collector;
+mod decorators;
 mod diagnostics;
 mod r#enum;
+mod metadata;
 mod module;
 mod namespace;
+mod pragma;
 
 use std::rc::Rc;
 
@@ -11,17 +14,71 @@ use serde::Deserialize;
 
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_span::SPAN;
 
 use crate::context::Ctx;
 
-use self::{annotations::TypeScriptAnnotations, collector::TypeScriptReferenceCollector};
+pub use self::pragma::TypeScriptPragmas;
+use self::{
+    annotations::TypeScriptAnnotations, collector::TypeScriptReferenceCollector,
+    decorators::TypeScriptDecorators, metadata::TypeScriptMetadata, r#enum::TypeScriptEnum,
+};
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct TypeScriptOptions {
     /// When set to true, the transform will only remove type-only imports (introduced in TypeScript 3.8).
     /// This should only be used if you are using TypeScript >= 3.8.
     only_remove_type_imports: bool,
+
+    /// The statement injected to keep a file a module after all of its imports and exports were removed.
+    ///
+    /// Defaults to `export {}`.
+    module_marker: ModuleMarker,
+
+    /// The module imported for its side effects at the top of files which contain decorators,
+    /// e.g. `reflect-metadata` for runtimes which need a `Reflect.metadata` polyfill.
+    ///
+    /// Defaults to `None`, which does not inject an import.
+    reflect_metadata_import: Option<String>,
+
+    /// Emit the reverse mappings of numeric enum members, `E[E["A"] = 0] = "A"`.
+    /// Set to false for smaller output when members are never looked up by value.
+    ///
+    /// Defaults to `true`.
+    enum_reverse_mapping: bool,
+
+    /// Emit `design:type`, `design:paramtypes` and `design:returntype` metadata for decorated
+    /// classes and members, as `@Reflect.metadata(key, value)` decorators.
+    ///
+    /// Defaults to `false`.
+    emit_decorator_metadata: bool,
+}
+
+impl Default for TypeScriptOptions {
+    fn default() -> Self {
+        Self {
+            only_remove_type_imports: false,
+            module_marker: ModuleMarker::default(),
+            reflect_metadata_import: None,
+            enum_reverse_mapping: true,
+            emit_decorator_metadata: false,
+        }
+    }
+}
+
+/// Decides which statement marks a file as a module once all of its
+/// import and export declarations have been elided.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleMarker {
+    /// `export {};`
+    #[default]
+    Export,
+    /// `Object.defineProperty(exports, "__esModule", { value: true });`
+    EsModuleFlag,
+    /// Do not inject anything.
+    Disabled,
 }
 
 /// [Preset TypeScript](https://babeljs.io/docs/babel-preset-typescript)
@@ -51,7 +108,12 @@ pub struct TypeScript<'a> {
     ctx: Ctx<'a>,
 
     annotations: TypeScriptAnnotations<'a>,
+    decorators: TypeScriptDecorators<'a>,
+    r#enum: TypeScriptEnum<'a>,
+    metadata: TypeScriptMetadata<'a>,
     reference_collector: TypeScriptReferenceCollector<'a>,
+
+    has_decorators: bool,
 }
 
 impl<'a> TypeScript<'a> {
@@ -60,7 +122,11 @@ impl<'a> TypeScript<'a> {
 
         Self {
             annotations: TypeScriptAnnotations::new(&options, ctx),
+            decorators: TypeScriptDecorators::new(ctx),
+            r#enum: TypeScriptEnum::new(&options, ctx),
+            metadata: TypeScriptMetadata::new(ctx),
             reference_collector: TypeScriptReferenceCollector::new(),
+            has_decorators: false,
             options,
             ctx: Rc::clone(ctx),
         }
@@ -71,6 +137,19 @@ impl<'a> TypeScript<'a> {
 impl<'a> TypeScript<'a> {
     pub fn transform_program_on_exit(&self, program: &mut Program<'a>) {
         self.annotations.transform_program_on_exit(program, &self.reference_collector);
+        self.decorators.transform_program_on_exit(program);
+
+        if let Some(source) = &self.options.reflect_metadata_import {
+            if self.has_decorators {
+                let ast = &self.ctx.ast;
+                let source = ast.string_literal(SPAN, source);
+                let import_decl =
+                    ast.import_declaration(SPAN, None, source, None, ImportOrExportKind::Value);
+                let stmt =
+                    ast.module_declaration(ModuleDeclaration::ImportDeclaration(import_decl));
+                program.body.insert(0, stmt);
+            }
+        }
     }
 
     pub fn transform_arrow_expression(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
@@ -86,6 +165,10 @@ impl<'a> TypeScript<'a> {
     }
 
     pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        self.decorators.transform_class(class);
+        if self.options.emit_decorator_metadata {
+            self.metadata.transform_class(class);
+        }
         self.annotations.transform_class(class);
     }
 
@@ -93,12 +176,17 @@ impl<'a> TypeScript<'a> {
         self.annotations.transform_class_body(body);
     }
 
+    pub fn transform_decorator(&mut self, _decorator: &mut Decorator<'a>) {
+        self.has_decorators = true;
+    }
+
     pub fn transform_export_named_declaration(&mut self, decl: &mut ExportNamedDeclaration<'a>) {
         self.reference_collector.visit_transform_export_named_declaration(decl);
     }
 
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
         self.annotations.transform_expression(expr);
+        self.r#enum.transform_expression(expr);
     }
 
     pub fn transform_formal_parameter(&mut self, param: &mut FormalParameter<'a>) {
@@ -118,6 +206,10 @@ impl<'a> TypeScript<'a> {
     }
 
     pub fn transform_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        // Types are serialized before `transform_function` removes them
+        if self.options.emit_decorator_metadata {
+            self.metadata.transform_method_definition(def);
+        }
         self.annotations.transform_method_definition(def);
     }
 
@@ -130,19 +222,26 @@ impl<'a> TypeScript<'a> {
     }
 
     pub fn transform_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
+        if self.options.emit_decorator_metadata {
+            self.metadata.transform_property_definition(def);
+        }
         self.annotations.transform_property_definition(def);
     }
 
     pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        self.r#enum.transform_statements(stmts);
         self.transform_statements_for_namespace(stmts);
     }
 
     pub fn transform_statements_on_exit(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        // Runs on exit so that every reference in the scope of the declarations is collected
+        self.transform_statements_for_import_equals(stmts);
         self.annotations.transform_statements_on_exit(stmts);
+        self.decorators.transform_statements_on_exit(stmts);
     }
 
-    pub fn transform_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
-        self.annotations.transform_if_statement(stmt);
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.annotations.transform_statement(stmt);
     }
 
     pub fn transform_tagged_template_expression(
@@ -157,18 +256,10 @@ impl<'a> TypeScript<'a> {
     }
 
     pub fn transform_declaration(&mut self, decl: &mut Declaration<'a>) {
-        match decl {
-            Declaration::TSImportEqualsDeclaration(ts_import_equals)
-                if ts_import_equals.import_kind.is_value() =>
-            {
-                *decl = self.transform_ts_import_equals(ts_import_equals);
-            }
-            Declaration::TSEnumDeclaration(ts_enum_declaration) => {
-                if let Some(expr) = self.transform_ts_enum(ts_enum_declaration) {
-                    *decl = expr;
-                }
+        if let Declaration::TSEnumDeclaration(ts_enum_declaration) = decl {
+            if let Some(expr) = self.r#enum.transform_ts_enum(ts_enum_declaration) {
+                *decl = expr;
             }
-            _ => {}
         }
     }
 
@@ -1,4 +1,4 @@
-use oxc_allocator::Box;
+use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
 use oxc_span::SPAN;
 
@@ -25,19 +25,70 @@ impl<'a> TypeScript<'a> {
         }
     }
 
+    /// Lowers the import-equals declarations of `stmts` which are exported or referenced as
+    /// values. The others are left in place, they print nothing and the top level ones are
+    /// removed along with the type-only imports in `transform_program_on_exit`.
+    ///
+    /// The declarations are visited last to first, so that the references of a removed
+    /// declaration are no longer counted for the aliases before it:
+    /// `import N = M.N; import A = N.A;` removes both when `A` is unused.
+    pub fn transform_statements_for_import_equals(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut().rev() {
+            match stmt {
+                Statement::Declaration(decl) => {
+                    let Declaration::TSImportEqualsDeclaration(import_equals) = decl else {
+                        continue;
+                    };
+                    if import_equals.import_kind.is_type() {
+                        continue;
+                    }
+                    if self.options.only_remove_type_imports
+                        || self.reference_collector.has_reference(&import_equals.id.name)
+                    {
+                        *decl = self.transform_ts_import_equals(import_equals);
+                    } else if let TSModuleReference::TypeName(type_name) =
+                        &*import_equals.module_reference
+                    {
+                        let name = TSTypeName::get_first_name(type_name).name;
+                        self.reference_collector.remove_reference(&name);
+                    }
+                }
+                Statement::ModuleDeclaration(module_decl) => {
+                    let ModuleDeclaration::ExportNamedDeclaration(export_decl) = &mut **module_decl
+                    else {
+                        continue;
+                    };
+                    let Some(decl) = &mut export_decl.declaration else { continue };
+                    if let Declaration::TSImportEqualsDeclaration(import_equals) = decl {
+                        if import_equals.import_kind.is_value() {
+                            *decl = self.transform_ts_import_equals(import_equals);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// ```TypeScript
     /// import b = babel;
     /// import AliasModule = LongNameModule;
+    /// import fs = require("fs");
+    /// ```
     ///
     /// ```JavaScript
     /// var b = babel;
     /// var AliasModule = LongNameModule;
+    /// const fs = require("fs");
     /// ```
     pub fn transform_ts_import_equals(
         &self,
         decl: &mut Box<'a, TSImportEqualsDeclaration<'a>>,
     ) -> Declaration<'a> {
-        let kind = VariableDeclarationKind::Var;
+        let kind = match &*decl.module_reference {
+            TSModuleReference::TypeName(_) => VariableDeclarationKind::Var,
+            TSModuleReference::ExternalModuleReference(_) => VariableDeclarationKind::Const,
+        };
         let decls = {
             let binding_identifier = BindingIdentifier::new(SPAN, decl.id.name.clone());
             let binding_pattern_kind = self.ctx.ast.binding_pattern_identifier(binding_identifier);
@@ -1,9 +1,9 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use super::TypeScript;
+use super::{diagnostics::NamespaceExportNonConst, TypeScript};
 
 use oxc_allocator::{Box, Vec};
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
 use oxc_span::{Atom, SPAN};
 use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
 
@@ -21,6 +21,37 @@ fn is_namespace(decl: &Declaration<'_>) -> bool {
     matches!(decl, Declaration::TSModuleDeclaration(decl) if !decl.modifiers.is_contains_declare())
 }
 
+/// Names already bound by functions, classes and enums, which namespaces of the same name merge
+/// into instead of declaring them again.
+/// `function Foo() {} namespace Foo {}` -> `function Foo() {} (function (_Foo) {})(Foo || ...);`
+fn declared_names<'a>(stmts: &Vec<'a, Statement<'a>>) -> FxHashSet<Atom<'a>> {
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Statement::Declaration(decl) => Some(decl),
+            Statement::ModuleDeclaration(decl) => match &**decl {
+                ModuleDeclaration::ExportNamedDeclaration(decl) => decl.declaration.as_ref(),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter_map(|decl| match decl {
+            Declaration::FunctionDeclaration(func) if !func.is_typescript_syntax() => {
+                func.id.as_ref().map(|id| id.name.clone())
+            }
+            Declaration::ClassDeclaration(class) if !class.is_declare() => {
+                class.id.as_ref().map(|id| id.name.clone())
+            }
+            Declaration::TSEnumDeclaration(decl)
+                if !decl.modifiers.contains(ModifierKind::Declare) =>
+            {
+                Some(decl.id.name.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 // TODO:
 // 1. register scope for the newly created function: <https://github.com/babel/babel/blob/08b0472069cd207f043dd40a4d157addfdd36011/packages/babel-plugin-transform-typescript/src/namespace.ts#L38>
 impl<'a> TypeScript<'a> {
@@ -45,7 +76,7 @@ impl<'a> TypeScript<'a> {
         // every time a namespace declaration is encountered.
         let mut new_stmts = self.ctx.ast.new_vec();
 
-        let mut state = State::default();
+        let mut state = State { names: declared_names(stmts), ..State::default() };
 
         for mut stmt in self.ctx.ast.move_statement_vec(stmts) {
             if !self.transform_statement_for_namespace(&mut state, &mut new_stmts, &mut stmt) {
@@ -94,7 +125,7 @@ impl<'a> TypeScript<'a> {
             new_stmts.push(stmt);
         }
 
-        let namespace = self.transform_namespace(state, ts_module_decl);
+        let namespace = self.transform_namespace(state, ts_module_decl, None);
         new_stmts.push(namespace);
         true
     }
@@ -138,29 +169,38 @@ impl<'a> TypeScript<'a> {
 
     // `namespace Foo { }` -> `let Foo; (function (_Foo) { })(Foo || (Foo = {}));`
     //                                  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    //
+    // A namespace exported from the namespace `_Parent` is also assigned to it:
+    // `(function (_Foo) { })(Foo || (Foo = _Parent.Foo || (_Parent.Foo = {})));`
     fn transform_namespace(
         &self,
         state: &mut State<'a>,
         block: &mut Box<'a, TSModuleDeclaration<'a>>,
+        parent: Option<&Atom<'a>>,
     ) -> Statement<'a> {
+        let name = block.id.name().clone();
+        let arg_name = self.get_namespace_arg_name(state, &name);
+
         let body_statements = match &mut block.body {
+            // `namespace Foo.Bar { }` is `namespace Foo { export namespace Bar { } }`
             Some(TSModuleDeclarationBody::TSModuleDeclaration(decl)) => {
-                let transformed_module_block = self.transform_namespace(state, decl);
-                self.ctx.ast.new_vec_single(transformed_module_block)
+                let inner_name = decl.id.name().clone();
+                let mut stmts = self.ctx.ast.new_vec_with_capacity(2);
+                stmts.push(self.create_variable_declaration_statement(&inner_name, false));
+                stmts.push(self.transform_namespace(state, decl, Some(&arg_name)));
+                stmts
             }
             Some(TSModuleDeclarationBody::TSModuleBlock(ts_module_block)) => {
-                self.ctx.ast.move_statement_vec(&mut ts_module_block.body)
+                let stmts = self.ctx.ast.move_statement_vec(&mut ts_module_block.body);
+                self.transform_namespace_body(state, stmts, &arg_name)
             }
             None => self.ctx.ast.new_vec(),
         };
 
-        let name = block.id.name();
-
         // `(function (_N) { var x; })(N || (N = {}))`;
         //  ^^^^^^^^^^^^^^^^^^^^^^^^^^
         let callee = {
             let body = self.ctx.ast.function_body(SPAN, self.ctx.ast.new_vec(), body_statements);
-            let arg_name = self.get_namespace_arg_name(state, name);
             let params = {
                 let ident =
                     self.ctx.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, arg_name));
@@ -196,8 +236,22 @@ impl<'a> TypeScript<'a> {
                 let assign_left = self.ctx.ast.simple_assignment_target_identifier(
                     IdentifierReference::new(SPAN, name.clone()),
                 );
-                let assign_right =
-                    self.ctx.ast.object_expression(SPAN, self.ctx.ast.new_vec(), None);
+                let assign_right = match parent {
+                    // `_Parent.N || (_Parent.N = {})`
+                    Some(parent) => {
+                        let object =
+                            self.ctx.ast.object_expression(SPAN, self.ctx.ast.new_vec(), None);
+                        let assign_expr =
+                            self.create_namespace_member_assignment(parent, &name, object);
+                        let left = self
+                            .ctx
+                            .ast
+                            .member_expression(self.create_namespace_member(parent, &name));
+                        let right = self.ctx.ast.parenthesized_expression(SPAN, assign_expr);
+                        self.ctx.ast.logical_expression(SPAN, left, LogicalOperator::Or, right)
+                    }
+                    None => self.ctx.ast.object_expression(SPAN, self.ctx.ast.new_vec(), None),
+                };
                 let op = AssignmentOperator::Assign;
                 let assign_expr =
                     self.ctx.ast.assignment_expression(SPAN, op, assign_left, assign_right);
@@ -214,6 +268,167 @@ impl<'a> TypeScript<'a> {
         self.ctx.ast.expression_statement(SPAN, expr)
     }
 
+    /// Removes the `export` of the declarations in the namespace body and assigns what they
+    /// declare to the namespace object `_N` instead.
+    ///
+    /// * `export const a = 1;` -> `const a = _N.a = 1;`
+    /// * `export const [a] = b;` -> `const [a] = b; _N.a = a;`
+    /// * `export function f() {}` -> `function f() {} _N.f = f;`
+    /// * `export namespace I {}` -> `let I; (function (_I) {})(I || (I = _N.I || (_N.I = {})));`
+    ///
+    /// Exported types and ambient declarations have no runtime value and are removed.
+    fn transform_namespace_body(
+        &self,
+        state: &mut State<'a>,
+        stmts: Vec<'a, Statement<'a>>,
+        arg_name: &Atom<'a>,
+    ) -> Vec<'a, Statement<'a>> {
+        let mut names = declared_names(&stmts);
+        let mut new_stmts = self.ctx.ast.new_vec_with_capacity(stmts.len());
+
+        for mut stmt in stmts {
+            let Statement::ModuleDeclaration(module_decl) = &mut stmt else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            let ModuleDeclaration::ExportNamedDeclaration(export_decl) = &mut **module_decl else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            let Some(decl) = &mut export_decl.declaration else {
+                new_stmts.push(stmt);
+                continue;
+            };
+
+            let decl = self.ctx.ast.move_declaration(decl);
+            match decl {
+                Declaration::VariableDeclaration(mut var_decl)
+                    if !var_decl.is_typescript_syntax() =>
+                {
+                    if var_decl.kind != VariableDeclarationKind::Const {
+                        self.ctx.error(NamespaceExportNonConst(var_decl.span));
+                    }
+                    if var_decl.declarations.iter().all(|decl| decl.id.kind.is_binding_identifier())
+                    {
+                        for declarator in var_decl.declarations.iter_mut() {
+                            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind
+                            else {
+                                continue;
+                            };
+                            if let Some(init) = &mut declarator.init {
+                                let init = self.ctx.ast.move_expression(init);
+                                declarator.init = Some(self.create_namespace_member_assignment(
+                                    arg_name,
+                                    &ident.name,
+                                    init,
+                                ));
+                            }
+                        }
+                        new_stmts.push(Statement::Declaration(Declaration::VariableDeclaration(
+                            var_decl,
+                        )));
+                    } else {
+                        let mut assignments = self.ctx.ast.new_vec();
+                        var_decl.bound_names(&mut |ident| {
+                            let value = self.ctx.ast.identifier_reference_expression(
+                                IdentifierReference::new(SPAN, ident.name.clone()),
+                            );
+                            assignments.push(self.create_namespace_member_assignment(
+                                arg_name,
+                                &ident.name,
+                                value,
+                            ));
+                        });
+                        new_stmts.push(Statement::Declaration(Declaration::VariableDeclaration(
+                            var_decl,
+                        )));
+                        let expr = self.ctx.ast.sequence_expression(SPAN, assignments);
+                        new_stmts.push(self.ctx.ast.expression_statement(SPAN, expr));
+                    }
+                }
+                Declaration::FunctionDeclaration(func) if !func.is_typescript_syntax() => {
+                    let name = func.id.as_ref().map(|id| id.name.clone());
+                    new_stmts.push(Statement::Declaration(Declaration::FunctionDeclaration(func)));
+                    if let Some(name) = name {
+                        new_stmts.push(self.create_namespace_export_statement(arg_name, &name));
+                    }
+                }
+                Declaration::ClassDeclaration(class) if !class.is_declare() => {
+                    let name = class.id.as_ref().map(|id| id.name.clone());
+                    new_stmts.push(Statement::Declaration(Declaration::ClassDeclaration(class)));
+                    if let Some(name) = name {
+                        new_stmts.push(self.create_namespace_export_statement(arg_name, &name));
+                    }
+                }
+                Declaration::TSEnumDeclaration(decl)
+                    if !decl.modifiers.contains(ModifierKind::Declare) =>
+                {
+                    let name = decl.id.name.clone();
+                    new_stmts.push(Statement::Declaration(Declaration::TSEnumDeclaration(decl)));
+                    new_stmts.push(self.create_namespace_export_statement(arg_name, &name));
+                }
+                Declaration::TSImportEqualsDeclaration(decl) if decl.import_kind.is_value() => {
+                    let name = decl.id.name.clone();
+                    new_stmts
+                        .push(Statement::Declaration(Declaration::TSImportEqualsDeclaration(decl)));
+                    new_stmts.push(self.create_namespace_export_statement(arg_name, &name));
+                }
+                Declaration::TSModuleDeclaration(mut decl)
+                    if !decl.modifiers.is_contains_declare() =>
+                {
+                    let name = decl.id.name().clone();
+                    if names.insert(name.clone()) {
+                        new_stmts.push(self.create_variable_declaration_statement(&name, false));
+                    }
+                    new_stmts.push(self.transform_namespace(state, &mut decl, Some(arg_name)));
+                }
+                _ => {}
+            }
+        }
+
+        new_stmts
+    }
+
+    // `_N.name`
+    fn create_namespace_member(
+        &self,
+        arg_name: &Atom<'a>,
+        name: &Atom<'a>,
+    ) -> MemberExpression<'a> {
+        let object = self
+            .ctx
+            .ast
+            .identifier_reference_expression(IdentifierReference::new(SPAN, arg_name.clone()));
+        let property = IdentifierName::new(SPAN, name.clone());
+        self.ctx.ast.static_member(SPAN, object, property, false)
+    }
+
+    // `_N.name = value`
+    fn create_namespace_member_assignment(
+        &self,
+        arg_name: &Atom<'a>,
+        name: &Atom<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let member = self.create_namespace_member(arg_name, name);
+        let target = self.ctx.ast.simple_assignment_target_member_expression(member);
+        self.ctx.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value)
+    }
+
+    // `_N.name = name;`
+    fn create_namespace_export_statement(
+        &self,
+        arg_name: &Atom<'a>,
+        name: &Atom<'a>,
+    ) -> Statement<'a> {
+        let value = self
+            .ctx
+            .ast
+            .identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()));
+        let expr = self.create_namespace_member_assignment(arg_name, name, value);
+        self.ctx.ast.expression_statement(SPAN, expr)
+    }
+
     fn get_namespace_arg_name(&self, state: &mut State<'a>, name: &Atom<'a>) -> Atom<'a> {
         let count = state.arg_names.entry(name.clone()).or_insert(0);
         *count += 1;
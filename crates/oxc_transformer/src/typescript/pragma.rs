@@ -0,0 +1,95 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use oxc_span::GetSpan;
+
+/// File level TypeScript pragmas, `// @ts-nocheck` and `// @ts-expect-error`.
+///
+/// TypeScript only honors these in the comments before the first statement, so
+/// drivers can use them to decide whether a file should be transformed differently.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct TypeScriptPragmas {
+    /// `@ts-nocheck`
+    pub ts_nocheck: bool,
+    /// `@ts-expect-error`
+    pub ts_expect_error: bool,
+}
+
+impl TypeScriptPragmas {
+    /// Scan the leading comments of the program for pragmas.
+    pub fn from_semantic(semantic: &Semantic) -> Self {
+        let AstKind::Program(program) = semantic.nodes().root_node().kind() else {
+            unreachable!("the root node is always a `Program`");
+        };
+        let first_statement_start = program
+            .directives
+            .first()
+            .map(GetSpan::span)
+            .or_else(|| program.body.first().map(GetSpan::span))
+            .map_or(program.span.end, |span| span.start);
+
+        let mut pragmas = Self::default();
+        for (_, span) in semantic.trivias().comments() {
+            if span.start >= first_statement_start {
+                break;
+            }
+            let comment = span
+                .source_text(semantic.source_text())
+                .trim_start_matches(|c: char| c == '*' || c.is_whitespace());
+            // `// @ts-expect-error: reason`
+            match comment.split(|c: char| c == ':' || c.is_whitespace()).next() {
+                Some("@ts-nocheck") => pragmas.ts_nocheck = true,
+                Some("@ts-expect-error") => pragmas.ts_expect_error = true,
+                _ => {}
+            }
+        }
+        pragmas
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::TypeScriptPragmas;
+
+    fn pragmas(source_text: &str) -> TypeScriptPragmas {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        TypeScriptPragmas::from_semantic(&semantic)
+    }
+
+    #[test]
+    fn test_ts_nocheck() {
+        let result = pragmas("// @ts-nocheck\nconst x: number = 0;");
+        assert!(result.ts_nocheck);
+        assert!(!result.ts_expect_error);
+
+        assert!(pragmas("/* @ts-nocheck */\nexport {};").ts_nocheck);
+    }
+
+    #[test]
+    fn test_ts_expect_error() {
+        let result = pragmas("// @ts-expect-error: not typed\nimport foo from 'foo';");
+        assert!(result.ts_expect_error);
+        assert!(!result.ts_nocheck);
+
+        assert!(pragmas("/** @ts-expect-error */\nfoo();").ts_expect_error);
+    }
+
+    #[test]
+    fn test_not_leading() {
+        assert_eq!(pragmas("const x = 0;\n// @ts-nocheck"), TypeScriptPragmas::default());
+        assert_eq!(pragmas("// @ts-nochecks\nconst x = 0;"), TypeScriptPragmas::default());
+        assert_eq!(pragmas("// @ts-check\nconst x = 0;"), TypeScriptPragmas::default());
+        assert_eq!(pragmas("foo();\n// @ts-expect-error\nbar();"), TypeScriptPragmas::default());
+    }
+}
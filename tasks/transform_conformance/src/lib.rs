@@ -51,9 +51,9 @@ fn fixture_root() -> PathBuf {
 const PLUGINS: &[&str] = &[
     // // ES2024
     // "babel-plugin-transform-unicode-sets-regex",
-    // // ES2022
+    // ES2022
     // "babel-plugin-transform-class-properties",
-    // "babel-plugin-transform-class-static-block",
+    "babel-plugin-transform-class-static-block",
     // "babel-plugin-transform-private-methods",
     // "babel-plugin-transform-private-property-in-object",
     // // [Syntax] "babel-plugin-transform-syntax-top-level-await",
@@ -79,9 +79,9 @@ const PLUGINS: &[&str] = &[
     // // [Regex] "babel-plugin-transform-named-capturing-groups-regex",
     // // ES2017
     // "babel-plugin-transform-async-to-generator",
-    // // ES2016
-    // "babel-plugin-transform-exponentiation-operator",
-    // // ES2015
+    // ES2016
+    "babel-plugin-transform-exponentiation-operator",
+    // ES2015
     // "babel-plugin-transform-arrow-functions",
     // "babel-plugin-transform-function-name",
     // "babel-plugin-transform-shorthand-properties",
@@ -91,6 +91,7 @@ const PLUGINS: &[&str] = &[
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
     // "babel-plugin-transform-new-target",
+    "babel-plugin-transform-spread",
     // // ES3
     // "babel-plugin-transform-property-literals",
     // TypeScript
@@ -13,7 +13,9 @@ use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use oxc_tasks_common::{normalize_path, print_diff_in_terminal, BabelOptions, TestOs};
-use oxc_transformer::{ReactOptions, TransformOptions, Transformer, TypeScriptOptions};
+use oxc_transformer::{
+    ReactOptions, TransformOptions, TransformTarget, Transformer, TypeScriptOptions,
+};
 
 use crate::{fixture_root, packages_root, TestRunnerEnv, PLUGINS_NOT_SUPPORTED_YET};
 
@@ -98,6 +100,16 @@ fn transform_options(options: &BabelOptions) -> serde_json::Result<TransformOpti
     Ok(TransformOptions {
         cwd: options.cwd.clone().unwrap(),
         assumptions: serde_json::from_value(options.assumptions.clone()).unwrap_or_default(),
+        // Syntax plugins lower everything newer than the version they are part of.
+        target: if options.get_plugin("transform-spread").is_some() {
+            TransformTarget::ES5
+        } else if options.get_plugin("transform-exponentiation-operator").is_some() {
+            TransformTarget::ES2015
+        } else if options.get_plugin("transform-class-static-block").is_some() {
+            TransformTarget::ES2021
+        } else {
+            TransformTarget::default()
+        },
         typescript: options
             .get_plugin("transform-typescript")
             .map(get_options::<TypeScriptOptions>)
@@ -0,0 +1,10 @@
+class Foo {
+  static _staticBlock = 1;
+  static {
+    log("first");
+  }
+  static {
+    var x = 2;
+    log("second", x);
+  }
+}
@@ -0,0 +1,12 @@
+class Foo {
+  static _staticBlock = 1;
+  static _staticBlock2() {
+    log("first");
+  }
+  static _staticBlock3() {
+    var x = 2;
+    log("second", x);
+  }
+}
+Foo._staticBlock2();
+Foo._staticBlock3();
@@ -0,0 +1,7 @@
+export class Foo {
+  static #bar = 21;
+  static {
+    Foo.#bar *= 2;
+    this.bar = Foo.#bar;
+  }
+}
@@ -0,0 +1,8 @@
+export class Foo {
+  static #bar = 21;
+  static _staticBlock() {
+    Foo.#bar *= 2;
+    this.bar = Foo.#bar;
+  }
+}
+Foo._staticBlock();
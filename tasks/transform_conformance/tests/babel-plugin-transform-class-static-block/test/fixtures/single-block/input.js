@@ -0,0 +1,6 @@
+class Foo {
+  static bar = 42;
+  static {
+    this.foo = this.bar;
+  }
+}
@@ -0,0 +1,7 @@
+class Foo {
+  static bar = 42;
+  static _staticBlock() {
+    this.foo = this.bar;
+  }
+}
+Foo._staticBlock();
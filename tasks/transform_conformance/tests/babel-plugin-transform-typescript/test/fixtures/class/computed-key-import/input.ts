@@ -0,0 +1,5 @@
+import { SYM, type Key } from "m";
+class C {
+  [SYM]() {}
+  declare key: Key;
+}
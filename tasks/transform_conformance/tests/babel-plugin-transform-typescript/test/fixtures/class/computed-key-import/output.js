@@ -0,0 +1,4 @@
+import { SYM } from "m";
+class C {
+  [SYM]() {}
+}
@@ -0,0 +1,3 @@
+class C extends Base<T> {}
+class D extends mixin<T>() {}
+class E extends mixin<T>(Base)<V> {}
@@ -0,0 +1,3 @@
+class C extends Base {}
+class D extends mixin() {}
+class E extends mixin(Base) {}
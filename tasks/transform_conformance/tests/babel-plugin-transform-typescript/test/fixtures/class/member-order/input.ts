@@ -0,0 +1,16 @@
+class C {
+  [key: string]: any;
+  @first a = 1;
+  declare b: number;
+  foo(): void;
+  foo(x: number): void;
+  foo(x?: number) {}
+  private c = 2;
+  @second e() {}
+  constructor(public f: number) {
+    log(f);
+  }
+  get g(): number {
+    return this.f;
+  }
+}
@@ -0,0 +1,13 @@
+class C {
+  @first a = 1;
+  foo(x) {}
+  c = 2;
+  @second e() {}
+  constructor(f) {
+    this.f = f;
+    log(f);
+  }
+  get g() {
+    return this.f;
+  }
+}
@@ -0,0 +1,15 @@
+@Injectable()
+export class Service {
+  @Input() name: string;
+
+  constructor(@Inject(TOKEN) private readonly dep: Dep) {}
+
+  @Log
+  call(@Arg() a: string, b: number, @Arg() c?: string) {}
+
+  static create(@Inject(TOKEN) dep: Dep) {}
+}
+
+export default class {
+  m(@Arg() a) {}
+}
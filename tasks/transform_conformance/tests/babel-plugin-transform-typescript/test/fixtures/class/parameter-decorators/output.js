@@ -0,0 +1,26 @@
+function _decorate(decorators, target, key, desc) {
+  var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc;
+  for (var i = decorators.length - 1; i >= 0; i--) r = (c < 3 ? decorators[i](r) : c > 3 ? decorators[i](target, key, r) : decorators[i](target, key)) || r;
+  return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _param(index, decorator) {
+  return function (target, key) {
+    decorator(target, key, index);
+  };
+}
+export class Service {
+  name;
+  constructor(dep) {
+    this.dep = dep;
+  }
+  call(a, b, c) {}
+  static create(dep) {}
+}
+_decorate([Input()], Service.prototype, "name", void 0);
+_decorate([Log, _param(0, Arg()), _param(2, Arg())], Service.prototype, "call", null);
+_decorate([_param(0, Inject(TOKEN))], Service, "create", null);
+Service = _decorate([Injectable(), _param(0, Inject(TOKEN))], Service);
+export default class _default {
+  m(a) {}
+}
+_decorate([_param(0, Arg())], _default.prototype, "m", null);
@@ -0,0 +1,3 @@
+class C {
+  constructor(@Inject() private svc: Svc, readonly name: string) {}
+}
@@ -0,0 +1,17 @@
+function _decorate(decorators, target, key, desc) {
+  var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc;
+  for (var i = decorators.length - 1; i >= 0; i--) r = (c < 3 ? decorators[i](r) : c > 3 ? decorators[i](target, key, r) : decorators[i](target, key)) || r;
+  return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _param(index, decorator) {
+  return function (target, key) {
+    decorator(target, key, index);
+  };
+}
+class C {
+  constructor(svc, name) {
+    this.svc = svc;
+    this.name = name;
+  }
+}
+C = _decorate([_param(0, Inject())], C);
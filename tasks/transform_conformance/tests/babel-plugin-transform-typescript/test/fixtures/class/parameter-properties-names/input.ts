@@ -0,0 +1,3 @@
+class C {
+  constructor(public of: number, private $el: Element, protected _async: boolean) {}
+}
@@ -0,0 +1,7 @@
+class C {
+  constructor(of, $el, _async) {
+    this.of = of;
+    this.$el = $el;
+    this._async = _async;
+  }
+}
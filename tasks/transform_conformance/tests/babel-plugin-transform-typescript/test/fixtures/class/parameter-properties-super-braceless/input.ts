@@ -0,0 +1,18 @@
+class C extends B {
+  constructor(public a: number) {
+    if (a) super(a);
+    else super();
+  }
+}
+
+class D extends B {
+  constructor(public a: number) {
+    if (a) super(a);
+  }
+}
+
+class E extends B {
+  constructor(public a: number) {
+    init: super();
+  }
+}
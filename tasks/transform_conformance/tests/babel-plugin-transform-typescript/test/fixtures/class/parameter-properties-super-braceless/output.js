@@ -0,0 +1,29 @@
+class C extends B {
+  constructor(a) {
+    if (a) {
+      super(a);
+      this.a = a;
+    } else {
+      super();
+      this.a = a;
+    }
+  }
+}
+
+class D extends B {
+  constructor(a) {
+    if (a) {
+      super(a);
+      this.a = a;
+    }
+  }
+}
+
+class E extends B {
+  constructor(a) {
+    init: {
+      super();
+      this.a = a;
+    }
+  }
+}
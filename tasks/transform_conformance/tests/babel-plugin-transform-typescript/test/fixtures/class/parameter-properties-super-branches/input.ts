@@ -0,0 +1,16 @@
+class C extends B {
+  constructor(public a: number, private b: string, protected c: boolean, readonly d: number, public e: number, f: number) {
+    if (a) {
+      super(a);
+      log(a);
+    } else if (b) super(b);
+    else {
+      super();
+    }
+    done();
+  }
+}
+
+class D {
+  constructor(public x: number) {}
+}
@@ -0,0 +1,34 @@
+class C extends B {
+  constructor(a, b, c, d, e, f) {
+    if (a) {
+      super(a);
+      this.a = a;
+      this.b = b;
+      this.c = c;
+      this.d = d;
+      this.e = e;
+      log(a);
+    } else if (b) {
+      super(b);
+      this.a = a;
+      this.b = b;
+      this.c = c;
+      this.d = d;
+      this.e = e;
+    } else {
+      super();
+      this.a = a;
+      this.b = b;
+      this.c = c;
+      this.d = d;
+      this.e = e;
+    }
+    done();
+  }
+}
+
+class D {
+  constructor(x) {
+    this.x = x;
+  }
+}
@@ -0,0 +1,33 @@
+class C extends B {
+  constructor(public a: number) {
+    try {
+      super(a);
+    } catch {
+      super();
+    }
+  }
+}
+
+class D extends B {
+  constructor(public a: number) {
+    switch (a) {
+      case 0:
+        super(0);
+        break;
+      default: {
+        super(a);
+      }
+    }
+  }
+}
+
+class E extends B {
+  constructor(public a: number) {
+    class F extends B {
+      constructor() {
+        super();
+      }
+    }
+    super(new F());
+  }
+}
@@ -0,0 +1,38 @@
+class C extends B {
+  constructor(a) {
+    try {
+      super(a);
+      this.a = a;
+    } catch {
+      super();
+      this.a = a;
+    }
+  }
+}
+
+class D extends B {
+  constructor(a) {
+    switch (a) {
+      case 0:
+        super(0);
+        this.a = a;
+        break;
+      default: {
+        super(a);
+        this.a = a;
+      }
+    }
+  }
+}
+
+class E extends B {
+  constructor(a) {
+    class F extends B {
+      constructor() {
+        super();
+      }
+    }
+    super(new F());
+    this.a = a;
+  }
+}
@@ -0,0 +1,4 @@
+interface I {}
+export default class C implements I {
+  foo(): void {}
+}
@@ -0,0 +1,9 @@
+import N = M.N;
+import A = N.A;
+import B = N.B;
+import C = M.C;
+import D = C.D;
+import X = M.X;
+import Y = X.Y;
+new B();
+new C();
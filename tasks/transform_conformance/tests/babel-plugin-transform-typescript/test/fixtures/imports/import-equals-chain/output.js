@@ -0,0 +1,6 @@
+var N = M.N;
+var B = N.B;
+var C = M.C;
+new B();
+new C();
+export {};
@@ -0,0 +1,3 @@
+import type A = require("a");
+import B = N.B;
+let x: A | B;
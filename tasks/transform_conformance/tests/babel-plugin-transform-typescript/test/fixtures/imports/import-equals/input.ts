@@ -0,0 +1,5 @@
+import A = N.A;
+import B = N.B;
+import type C = N.C;
+export import D = N.D;
+let x: B | C = new A();
@@ -0,0 +1,3 @@
+var A = N.A;
+export var D = N.D;
+let x = new A();
@@ -0,0 +1,18 @@
+function Merged() {}
+namespace Merged {
+  export const x = 1;
+}
+export namespace N {
+  export const a = 1, b = a + 1;
+  export const [c] = [a];
+  export function f() {}
+  export class C {}
+  export interface I {}
+  export declare const d: number;
+  export namespace Inner {
+    export const y = a;
+  }
+}
+namespace A.B {
+  export const z = 2;
+}
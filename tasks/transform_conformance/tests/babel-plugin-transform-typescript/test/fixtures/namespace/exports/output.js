@@ -0,0 +1,25 @@
+function Merged() {}
+(function (_Merged) {
+  const x = _Merged.x = 1;
+})(Merged || (Merged = {}));
+export let N;
+(function (_N) {
+  const a = _N.a = 1, b = _N.b = a + 1;
+  const [c] = [a];
+  _N.c = c;
+  function f() {}
+  _N.f = f;
+  class C {}
+  _N.C = C;
+  let Inner;
+  (function (_Inner) {
+    const y = _Inner.y = a;
+  })(Inner || (Inner = _N.Inner || (_N.Inner = {})));
+})(N || (N = {}));
+let A;
+(function (_A) {
+  let B;
+  (function (_B) {
+    const z = _B.z = 2;
+  })(B || (B = _A.B || (_A.B = {})));
+})(A || (A = {}));
@@ -0,0 +1,3 @@
+class Foo {
+  constructor(@inject a: string, b: Bar) {}
+}
@@ -0,0 +1,5 @@
+@dec
+class Foo {
+  @dec
+  bar(): void {}
+}
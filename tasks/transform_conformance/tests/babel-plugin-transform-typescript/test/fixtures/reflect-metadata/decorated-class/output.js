@@ -0,0 +1,6 @@
+import "reflect-metadata";
+@dec
+class Foo {
+  @dec
+  bar() {}
+}
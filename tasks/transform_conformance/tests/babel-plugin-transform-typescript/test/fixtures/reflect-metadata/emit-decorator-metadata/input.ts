@@ -0,0 +1,9 @@
+@dec
+class Foo {
+  constructor(a: string[]) {}
+
+  @dec
+  bar(a: number, b: string | null, c: Baz): void {}
+
+  baz(a: number) {}
+}
@@ -0,0 +1,11 @@
+@dec
+@Reflect.metadata("design:paramtypes", [Array])
+class Foo {
+  constructor(a) {}
+  @dec
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [Number, String, typeof Baz === "undefined" ? Object : Baz])
+  @Reflect.metadata("design:returntype", void 0)
+  bar(a, b, c) {}
+  baz(a) {}
+}
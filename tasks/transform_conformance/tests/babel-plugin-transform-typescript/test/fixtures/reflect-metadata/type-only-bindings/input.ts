@@ -0,0 +1,9 @@
+import type { A } from "a";
+import { type B, C } from "b";
+interface I {}
+type T = string;
+
+class Foo {
+  @dec
+  m(a: A, b: B, c: C, i: I, t: T): void {}
+}
@@ -0,0 +1,8 @@
+import { C } from "b";
+class Foo {
+  @dec
+  @Reflect.metadata("design:type", Function)
+  @Reflect.metadata("design:paramtypes", [Object, Object, typeof C === "undefined" ? Object : C, Object, Object])
+  @Reflect.metadata("design:returntype", void 0)
+  m(a, b, c, i, t) {}
+}
@@ -0,0 +1,6 @@
+const enum Bits {
+  Low = 2 ** 64 | 1,
+  Inverted = ~(2 ** 70),
+  Wrapped = (2 ** 32 + 5) << 1,
+}
+foo(Bits.Low, Bits.Inverted, Bits.Wrapped);
@@ -0,0 +1 @@
+foo(1, -1, 10);
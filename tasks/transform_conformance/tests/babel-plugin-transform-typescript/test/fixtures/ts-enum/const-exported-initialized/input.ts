@@ -0,0 +1,5 @@
+export const enum E {
+  A = 1,
+  B = A << 1,
+}
+foo(E.B);
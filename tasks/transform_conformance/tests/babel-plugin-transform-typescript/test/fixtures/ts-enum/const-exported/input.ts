@@ -0,0 +1,5 @@
+export const enum E {
+  A,
+  B = A << 1,
+}
+foo(E.B);
@@ -0,0 +1,8 @@
+export var E = ((E) => {
+  const A = 0;
+  E[E["A"] = A] = "A";
+  const B = A << 1;
+  E[E["B"] = B] = "B";
+  return E;
+})(E || {});
+foo(0);
@@ -0,0 +1,10 @@
+const enum Color {
+  Red,
+  Green = Red + 2,
+  Blue = Color.Green << 1,
+  Negative = -1,
+  Name = "color",
+  Label = Name + "-label",
+}
+let color: Color = Color.Red;
+foo(color, Color.Green, Color["Blue"], Color.Negative, Color.Name, Color.Label);
@@ -0,0 +1,2 @@
+let color = 0;
+foo(color, 2, 4, -1, "color", "color-label");
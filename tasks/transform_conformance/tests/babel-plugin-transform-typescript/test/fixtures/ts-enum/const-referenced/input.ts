@@ -0,0 +1,4 @@
+const enum E {
+  A = 1,
+}
+foo(E.A, E);
@@ -0,0 +1,6 @@
+var E = ((E) => {
+  const A = 1;
+  E[E["A"] = A] = "A";
+  return E;
+})(E || {});
+foo(1, E);
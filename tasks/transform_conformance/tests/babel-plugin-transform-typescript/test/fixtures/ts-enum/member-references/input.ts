@@ -0,0 +1,6 @@
+enum E {
+  A,
+  B = A + 1,
+  C = B * 2,
+  D = E.A | C,
+}
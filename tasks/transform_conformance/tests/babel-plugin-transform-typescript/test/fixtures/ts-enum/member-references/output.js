@@ -0,0 +1,11 @@
+var E = ((E) => {
+  const A = 0;
+  E[E["A"] = A] = "A";
+  const B = A + 1;
+  E[E["B"] = B] = "B";
+  const C = B * 2;
+  E[E["C"] = C] = "C";
+  const D = E.A | C;
+  E[E["D"] = D] = "D";
+  return E;
+})(E || {});
@@ -0,0 +1,8 @@
+enum M {
+  A = 1,
+}
+enum M {
+  B = A | 2,
+  C = D + 1,
+  D = 4,
+}
@@ -0,0 +1,14 @@
+var M = ((M) => {
+  const A = 1;
+  M[M["A"] = A] = "A";
+  return M;
+})(M || {});
+var M = ((M) => {
+  const B = M["A"] | 2;
+  M[M["B"] = B] = "B";
+  const C = M["D"] + 1;
+  M[M["C"] = C] = "C";
+  const D = 4;
+  M[M["D"] = D] = "D";
+  return M;
+})(M || {});
@@ -0,0 +1,7 @@
+var E = ((E) => {
+  const A = 0;
+  E["A"] = A;
+  const B = 1 + A;
+  E["B"] = B;
+  return E;
+})(E || {});
@@ -0,0 +1,7 @@
+var E = ((E) => {
+  const A = 0;
+  E[E["A"] = A] = "A";
+  const B = 1 + A;
+  E[E["B"] = B] = "B";
+  return E;
+})(E || {});
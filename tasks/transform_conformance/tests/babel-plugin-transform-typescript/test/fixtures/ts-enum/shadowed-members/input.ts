@@ -0,0 +1,12 @@
+enum E {
+  A = 1,
+}
+function f(A) {
+  enum E {
+    B = A,
+  }
+}
+enum E {
+  C = ((A) => A)(2),
+  D = A,
+}
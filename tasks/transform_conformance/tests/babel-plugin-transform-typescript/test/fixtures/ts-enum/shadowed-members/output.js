@@ -0,0 +1,19 @@
+var E = ((E) => {
+  const A = 1;
+  E[E["A"] = A] = "A";
+  return E;
+})(E || {});
+function f(A) {
+  var E = ((E) => {
+    const B = A;
+    E[E["B"] = B] = "B";
+    return E;
+  })(E || {});
+}
+var E = ((E) => {
+  const C = ((A) => A)(2);
+  E[E["C"] = C] = "C";
+  const D = E["A"];
+  E[E["D"] = D] = "D";
+  return E;
+})(E || {});
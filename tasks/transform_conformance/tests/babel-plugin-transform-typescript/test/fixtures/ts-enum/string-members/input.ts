@@ -0,0 +1,8 @@
+enum S {
+  A = "a",
+  B = A,
+  C = A + "c",
+  "d-e" = "de",
+  F = S["d-e"],
+  G = 1,
+}
@@ -0,0 +1,14 @@
+var S = ((S) => {
+  const A = "a";
+  S["A"] = A;
+  const B = A;
+  S["B"] = B;
+  const C = A + "c";
+  S["C"] = C;
+  S["d-e"] = "de";
+  const F = S["d-e"];
+  S["F"] = F;
+  const G = 1;
+  S[S["G"] = G] = "G";
+  return S;
+})(S || {});
@@ -0,0 +1,9 @@
+const [{ a }]: Foo[] = arr;
+const [{ b }, c]: [Foo, number] = arr;
+const { d: [e] }: { d: readonly number[] } = obj;
+const [...rest]: number[] = arr;
+const [f, ...g]: readonly number[] = arr;
+const [[h], ...i]: readonly [readonly [number], ...string[]] = arr;
+
+function j([k, ...l]: readonly [number, ...string[]], ...m: number[]) {}
+function n({ o }?: Foo, p?: number) {}
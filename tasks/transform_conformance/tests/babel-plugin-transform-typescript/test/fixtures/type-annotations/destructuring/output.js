@@ -0,0 +1,9 @@
+const [{ a }] = arr;
+const [{ b }, c] = arr;
+const { d: [e] } = obj;
+const [...rest] = arr;
+const [f, ...g] = arr;
+const [[h], ...i] = arr;
+
+function j([k, ...l], ...m) {}
+function n({ o }, p) {}
@@ -0,0 +1,8 @@
+(-x as number) ** 2;
+(<number>a) ** b;
+(await p as number) ** 2;
+typeof (a as any);
+!(a satisfies boolean);
+(a as any).b();
+(a + b as number).toFixed();
+(<any>(a || b)).c;
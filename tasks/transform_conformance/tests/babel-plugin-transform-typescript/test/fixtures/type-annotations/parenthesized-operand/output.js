@@ -0,0 +1,8 @@
+(-x) ** 2;
+a ** b;
+(await p) ** 2;
+typeof a;
+!a;
+a.b();
+(a + b).toFixed();
+(a || b).c;